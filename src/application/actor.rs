@@ -0,0 +1,35 @@
+//! Identification of the person or agent performing changes.
+
+use std::env;
+
+/// Environment variable naming the acting user or agent.
+pub const ACTOR_ENV: &str = "CLICKY_USER";
+
+/// Name used when no actor can be determined.
+pub const UNKNOWN_ACTOR: &str = "unknown";
+
+/// Returns the name of the acting user or agent.
+///
+/// Uses `CLICKY_USER` if set, so agents can identify themselves, and
+/// falls back to the login name from `USER` or `USERNAME`.
+pub fn current_actor() -> String {
+    resolve_actor([
+        env::var(ACTOR_ENV).ok(),
+        env::var("USER").ok(),
+        env::var("USERNAME").ok(),
+    ])
+}
+
+/// Picks the first non-blank candidate name.
+fn resolve_actor<const N: usize>(candidates: [Option<String>; N]) -> String {
+    candidates
+        .into_iter()
+        .flatten()
+        .map(|name| name.trim().to_string())
+        .find(|name| !name.is_empty())
+        .unwrap_or_else(|| UNKNOWN_ACTOR.to_string())
+}
+
+#[cfg(test)]
+#[path = "./actor_tests.rs"]
+mod actor_tests;
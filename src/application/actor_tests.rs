@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+fn test_resolve_actor_prefers_first_candidate() {
+    let actor = resolve_actor([Some("agent-7".to_string()), Some("alice".to_string())]);
+
+    assert_eq!(actor, "agent-7");
+}
+
+#[test]
+fn test_resolve_actor_skips_blank_candidates() {
+    let actor = resolve_actor([None, Some("  ".to_string()), Some(" alice ".to_string())]);
+
+    assert_eq!(actor, "alice");
+}
+
+#[test]
+fn test_resolve_actor_falls_back_to_unknown() {
+    let actor = resolve_actor([None, None]);
+
+    assert_eq!(actor, UNKNOWN_ACTOR);
+}
@@ -1,16 +1,32 @@
+use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use crate::domain::Board;
-use crate::infrastructure::storage::{BoardStorage, JsonBoardRepository, StorageError};
+use crate::domain::{merge_boards, Board, BoardDiff, CardFilter, Issue, Journal, MergeOutcome};
+use crate::infrastructure::config::{BoardConfig, StorageBackend};
+use crate::infrastructure::git::{self, GitError, MergeDriverInstallation};
+use crate::infrastructure::lock::{lock_timeout_from_env, BoardLock};
+use crate::infrastructure::snapshot::{self, Snapshot};
+use crate::infrastructure::storage::{
+    BoardStorage, ConfiguredBoardRepository, JsonBoardRepository, MigrationReport,
+    StorageConversion, StorageError,
+};
 use crate::infrastructure::BoardRepository;
 
 /// Service for board-related operations.
 ///
 /// This service provides high-level operations for creating, loading,
 /// and managing kanban boards. It abstracts the storage details from
-/// the CLI interface.
+/// the CLI interface: boards are kept in whatever [`BoardRepository`]
+/// the service was created with.
+///
+/// Services that modify a board hold the lock from [`BoardService::lock`]
+/// across their whole load-modify-save cycle. Saving also checks the
+/// board's revision, so changes based on an outdated copy are rejected.
 pub struct BoardService {
-    repository: JsonBoardRepository,
+    repository: Box<dyn BoardRepository>,
+    lock_timeout: Duration,
+    expected_revision: Option<u64>,
 }
 
 /// Errors that can occur during board operations.
@@ -19,22 +35,72 @@ pub struct BoardService {
 pub enum BoardServiceError {
     #[error("Storage error: {0}")]
     Storage(#[from] StorageError),
+    #[error("Git error: {0}")]
+    Git(#[from] GitError),
     #[error("Board already initialized in this directory")]
     AlreadyInitialized,
     #[error("No board found. Run 'clicky init' to create one.")]
     BoardNotFound,
     #[error("Invalid board name: {0}")]
     InvalidName(String),
+    #[error("Board has changed: expected revision {expected}, but it is at revision {actual}. Reload and try again.")]
+    Conflict { expected: u64, actual: u64 },
+    #[error("Board is inconsistent: {issue} ({count} issue(s) in total). Run 'clicky check --repair' to fix it.")]
+    Inconsistent { issue: String, count: usize },
+}
+
+impl BoardServiceError {
+    /// Returns a stable, machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            BoardServiceError::Storage(e) => e.code(),
+            BoardServiceError::Git(e) => e.code(),
+            BoardServiceError::AlreadyInitialized => "already_initialized",
+            BoardServiceError::BoardNotFound => "board_not_found",
+            BoardServiceError::InvalidName(_) => "invalid_board_name",
+            BoardServiceError::Conflict { .. } => "conflict",
+            BoardServiceError::Inconsistent { .. } => "inconsistent_board",
+        }
+    }
 }
 
 impl BoardService {
-    /// Creates a new board service.
+    /// Creates a new board service storing each board in the backend
+    /// selected by its config.
+    ///
+    /// The lock timeout is read from `CLICKY_LOCK_TIMEOUT`.
     pub fn new() -> Self {
+        Self::with_repository(Box::new(ConfiguredBoardRepository::new()))
+    }
+
+    /// Creates a new board service backed by the given repository.
+    pub fn with_repository(repository: Box<dyn BoardRepository>) -> Self {
         Self {
-            repository: JsonBoardRepository::new(),
+            repository,
+            lock_timeout: lock_timeout_from_env(),
+            expected_revision: None,
         }
     }
 
+    /// Creates a new board service that waits at most `lock_timeout`
+    /// for the board lock.
+    #[allow(dead_code)]
+    pub fn with_lock_timeout(lock_timeout: Duration) -> Self {
+        Self {
+            lock_timeout,
+            ..Self::new()
+        }
+    }
+
+    /// Only saves a board while the stored board is at `revision`.
+    ///
+    /// Without an expected revision, saves require the stored board to still
+    /// be at the revision the saved board was loaded at.
+    pub fn if_revision(mut self, revision: Option<u64>) -> Self {
+        self.expected_revision = revision;
+        self
+    }
+
     /// Initializes a new board in the specified directory.
     ///
     /// # Arguments
@@ -43,14 +109,32 @@ impl BoardService {
     ///
     /// # Errors
     /// Returns `BoardServiceError::AlreadyInitialized` if a board already exists.
+    #[allow(dead_code)]
     pub fn initialize(
         &self,
         base_path: &Path,
         name: Option<String>,
     ) -> Result<Board, BoardServiceError> {
-        let board_path = BoardStorage::board_path(base_path);
+        self.initialize_with_storage(base_path, name, StorageBackend::default())
+    }
+
+    /// Initializes a new board kept in the given storage backend.
+    ///
+    /// Backends other than the default are recorded in the board config.
+    /// Boards in directory storage get random card IDs, which do not collide
+    /// when cards are created on different branches.
+    ///
+    /// # Errors
+    /// Returns `BoardServiceError::AlreadyInitialized` if a board already exists.
+    pub fn initialize_with_storage(
+        &self,
+        base_path: &Path,
+        name: Option<String>,
+        storage: StorageBackend,
+    ) -> Result<Board, BoardServiceError> {
+        let _lock = self.repository.lock(base_path, self.lock_timeout)?;
 
-        if self.repository.exists(&board_path) {
+        if self.repository.exists(base_path) {
             return Err(BoardServiceError::AlreadyInitialized);
         }
 
@@ -63,25 +147,103 @@ impl BoardService {
         });
 
         let board_id = Self::sanitize_id(&board_name);
-        let board = Board::new(board_id, board_name);
+        let mut board = Board::new(board_id, board_name);
+        board.card_id_scheme = storage.card_id_scheme();
 
-        self.repository.save(&board, &board_path)?;
+        if storage != StorageBackend::default() {
+            BoardConfig {
+                storage,
+                ..Default::default()
+            }
+            .save(base_path)?;
+        }
+        self.repository.save(&board, base_path)?;
 
         Ok(board)
     }
 
-    /// Loads the board from the specified directory.
+    /// Loads the board from the specified directory for reading.
+    ///
+    /// The board is returned even if it fails the structural checks, so it
+    /// can be inspected; callers that show it report the issues
+    /// [`BoardService::check_consistency`] finds in their own way.
     ///
     /// # Arguments
     /// * `base_path` - Directory containing the board
     pub fn load(&self, base_path: &Path) -> Result<Board, BoardServiceError> {
-        let board_path = BoardStorage::board_path(base_path);
+        self.load_unchecked(base_path)
+    }
+
+    /// Loads the board from the specified directory to change it.
+    ///
+    /// # Errors
+    /// Returns `BoardServiceError::Inconsistent` if the board fails the
+    /// structural checks, which [`BoardService::repair`] fixes.
+    pub fn load_for_change(&self, base_path: &Path) -> Result<Board, BoardServiceError> {
+        let board = self.load_unchecked(base_path)?;
+        Self::check_consistency(&board)?;
+        Ok(board)
+    }
+
+    /// Loads the board without checking its consistency.
+    pub fn load_unchecked(&self, base_path: &Path) -> Result<Board, BoardServiceError> {
+        if !self.repository.exists(base_path) {
+            return Err(BoardServiceError::BoardNotFound);
+        }
+
+        Ok(self.repository.load(base_path)?)
+    }
+
+    /// Runs the cheap structural checks on a loaded board.
+    ///
+    /// # Errors
+    /// Returns `BoardServiceError::Inconsistent` with the first issue found.
+    pub fn check_consistency(board: &Board) -> Result<(), BoardServiceError> {
+        let issues = board.check_structure();
+        match issues.first() {
+            Some(issue) => Err(BoardServiceError::Inconsistent {
+                issue: issue.to_string(),
+                count: issues.len(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks the board in the specified directory for inconsistencies.
+    pub fn check(&self, base_path: &Path) -> Result<Vec<Issue>, BoardServiceError> {
+        Ok(self.load_unchecked(base_path)?.check())
+    }
+
+    /// Fixes the inconsistencies of the board in the specified directory.
+    ///
+    /// Returns the repaired issues; the board is only saved if there were
+    /// any.
+    pub fn repair(&self, base_path: &Path) -> Result<Vec<Issue>, BoardServiceError> {
+        let _lock = self.lock(base_path)?;
+        let original = self.load_unchecked(base_path)?;
+        let mut board = original.clone();
+        let issues = board.repair();
+        if !issues.is_empty() {
+            self.snapshot(&original, base_path)?;
+            self.save(&mut board, base_path)?;
+        }
+        Ok(issues)
+    }
 
-        if !self.repository.exists(&board_path) {
+    /// Loads the board with only the cards matching `filter`.
+    ///
+    /// The result is meant for listing cards; it may lack the activity log
+    /// and must not be saved.
+    pub fn load_matching(
+        &self,
+        base_path: &Path,
+        filter: &CardFilter,
+    ) -> Result<Board, BoardServiceError> {
+        if !self.repository.exists(base_path) {
             return Err(BoardServiceError::BoardNotFound);
         }
 
-        Ok(self.repository.load(&board_path)?)
+        Ok(self.repository.load_matching(base_path, filter)?)
     }
 
     /// Finds and loads a board by searching upward from a path.
@@ -89,37 +251,196 @@ impl BoardService {
     /// Searches from `start_path` up the directory tree for a `.clicky` directory.
     #[allow(dead_code)]
     pub fn find_and_load(&self, start_path: &Path) -> Result<(Board, PathBuf), BoardServiceError> {
-        match BoardStorage::find_board_path(start_path) {
-            Some(board_path) => {
-                let board = self.repository.load(&board_path)?;
-                let base_path = board_path
-                    .parent()
-                    .and_then(|p| p.parent())
-                    .unwrap_or(start_path)
-                    .to_path_buf();
+        match BoardStorage::find_base_path(start_path) {
+            Some(base_path) => {
+                let board = self.load(&base_path)?;
                 Ok((board, base_path))
             }
             None => Err(BoardServiceError::BoardNotFound),
         }
     }
 
-    /// Saves a board to the specified directory.
-    pub fn save(&self, board: &Board, base_path: &Path) -> Result<(), BoardServiceError> {
-        let board_path = BoardStorage::board_path(base_path);
-        Ok(self.repository.save(board, &board_path)?)
+    /// Locks the board in the specified directory against concurrent changes.
+    ///
+    /// The lock is released when the returned guard is dropped. Storage
+    /// that is not shared between processes needs no lock and returns `None`.
+    ///
+    /// # Errors
+    /// Returns `BoardServiceError::BoardNotFound` if there is no board, or a
+    /// storage error if the lock is not acquired within the lock timeout.
+    pub fn lock(&self, base_path: &Path) -> Result<Option<BoardLock>, BoardServiceError> {
+        if !self.exists(base_path) {
+            return Err(BoardServiceError::BoardNotFound);
+        }
+
+        Ok(self.repository.lock(base_path, self.lock_timeout)?)
+    }
+
+    /// Saves a board to the specified directory and advances its revision.
+    ///
+    /// # Errors
+    /// Returns `BoardServiceError::Conflict` if the stored board has moved on
+    /// from the revision the change was based on.
+    pub fn save(&self, board: &mut Board, base_path: &Path) -> Result<(), BoardServiceError> {
+        if self.repository.exists(base_path) {
+            let stored = self.repository.stored_revision(base_path)?;
+            let expected = self.expected_revision.unwrap_or(board.revision);
+            if stored != expected {
+                return Err(BoardServiceError::Conflict {
+                    expected,
+                    actual: stored,
+                });
+            }
+            board.revision = stored + 1;
+        }
+
+        Ok(self.repository.save(board, base_path)?)
+    }
+
+    /// Takes a snapshot of a board before a destructive change to it.
+    ///
+    /// Ordinary saves only take snapshots now and then; see
+    /// [`BoardConfig::snapshot_retention`].
+    pub fn snapshot(&self, board: &Board, base_path: &Path) -> Result<(), BoardServiceError> {
+        Ok(self.repository.snapshot(board, base_path)?)
+    }
+
+    /// Loads the journal of undoable card operations.
+    pub fn journal(&self, base_path: &Path) -> Result<Journal, BoardServiceError> {
+        Ok(self.repository.load_journal(base_path)?)
+    }
+
+    /// Saves the journal of undoable card operations.
+    ///
+    /// The caller must hold the board lock.
+    pub fn save_journal(
+        &self,
+        journal: &Journal,
+        base_path: &Path,
+    ) -> Result<(), BoardServiceError> {
+        Ok(self.repository.save_journal(journal, base_path)?)
+    }
+
+    /// Upgrades the stored board to the current schema version.
+    ///
+    /// The original file is backed up and a snapshot of the board is taken
+    /// before it is rewritten. With `dry_run`, only reports the pending
+    /// steps.
+    pub fn migrate(
+        &self,
+        base_path: &Path,
+        dry_run: bool,
+    ) -> Result<MigrationReport, BoardServiceError> {
+        let _lock = self.lock(base_path)?;
+        let pending = self.repository.migrate(base_path, true)?;
+        if dry_run || pending.steps.is_empty() {
+            return Ok(pending);
+        }
+
+        self.snapshot(&self.load_unchecked(base_path)?, base_path)?;
+        Ok(self.repository.migrate(base_path, false)?)
+    }
+
+    /// Moves the board to another storage backend.
+    ///
+    /// The board is verified in its new backend before the old copy is
+    /// removed. Conversion works on the board files directly, whatever
+    /// repository the service was created with.
+    pub fn convert(
+        &self,
+        base_path: &Path,
+        target: StorageBackend,
+    ) -> Result<StorageConversion, BoardServiceError> {
+        let _lock = self.lock(base_path)?;
+        Ok(ConfiguredBoardRepository::new().convert(base_path, target)?)
+    }
+
+    /// Lists the snapshots of the board, newest first.
+    pub fn snapshots(&self, base_path: &Path) -> Result<Vec<Snapshot>, BoardServiceError> {
+        if !self.repository.exists(base_path) {
+            return Err(BoardServiceError::BoardNotFound);
+        }
+        Ok(snapshot::list(base_path)?)
+    }
+
+    /// Replaces the board with a snapshot.
+    ///
+    /// A snapshot of the replaced board is taken first and the restored
+    /// board is saved as a new revision, so a restore can itself be undone.
+    /// The card counter is not rewound, so IDs of cards created after the
+    /// snapshot are not handed out again.
+    pub fn restore_snapshot(
+        &self,
+        base_path: &Path,
+        name: &str,
+    ) -> Result<(Snapshot, Board), BoardServiceError> {
+        let _lock = self.lock(base_path)?;
+        let current = self.load_unchecked(base_path)?;
+        let snapshot = snapshot::find(base_path, name)?;
+
+        let mut board = snapshot.load()?;
+        self.snapshot(&current, base_path)?;
+        board.revision = current.revision;
+        board.next_card_number = board.next_card_number.max(current.next_card_number);
+        self.save(&mut board, base_path)?;
+        Ok((snapshot, board))
+    }
+
+    /// Compares a snapshot with the current board.
+    pub fn diff_snapshot(
+        &self,
+        base_path: &Path,
+        name: &str,
+    ) -> Result<(Snapshot, BoardDiff), BoardServiceError> {
+        let current = self.load_unchecked(base_path)?;
+        let snapshot = snapshot::find(base_path, name)?;
+        let diff = BoardDiff::between(&snapshot.load()?, &current);
+        Ok((snapshot, diff))
+    }
+
+    /// Merges their version of a board file into ours, as a git merge
+    /// driver.
+    ///
+    /// The merged board is written to `ours`, also when the merge has
+    /// conflicts; conflicting fields keep our value. An empty or missing
+    /// `base` means the board was added on both sides.
+    pub fn merge_files(
+        &self,
+        base: &Path,
+        ours: &Path,
+        theirs: &Path,
+    ) -> Result<MergeOutcome, BoardServiceError> {
+        let repository = JsonBoardRepository::new();
+        let base = match fs::metadata(base) {
+            Ok(metadata) if metadata.len() > 0 => Some(repository.load_file(base)?),
+            _ => None,
+        };
+        let our_board = repository.load_file(ours)?;
+        let their_board = repository.load_file(theirs)?;
+
+        let outcome = merge_boards(base.as_ref(), &our_board, &their_board);
+        repository.save_file(&outcome.board, ours)?;
+        Ok(outcome)
+    }
+
+    /// Registers the merge driver for the board file in the git repository
+    /// containing `base_path`.
+    pub fn install_merge_driver(
+        &self,
+        base_path: &Path,
+    ) -> Result<MergeDriverInstallation, BoardServiceError> {
+        Ok(git::install_merge_driver(base_path)?)
     }
 
     /// Deletes the board from the specified directory.
     #[allow(dead_code)]
     pub fn delete(&self, base_path: &Path) -> Result<(), BoardServiceError> {
-        let board_path = BoardStorage::board_path(base_path);
-        Ok(self.repository.delete(&board_path)?)
+        Ok(self.repository.delete(base_path)?)
     }
 
     /// Checks if a board exists in the specified directory.
     pub fn exists(&self, base_path: &Path) -> bool {
-        let board_path = BoardStorage::board_path(base_path);
-        self.repository.exists(&board_path)
+        self.repository.exists(base_path)
     }
 
     /// Sanitizes a name to create a valid board ID.
@@ -1,4 +1,5 @@
 use super::*;
+use crate::domain::CardIdScheme;
 use tempfile::TempDir;
 
 #[test]
@@ -17,6 +18,7 @@ fn test_initialize_board() {
     assert_eq!(board.id, "my-project");
     assert_eq!(board.card_id_prefix, "MYP");
     assert!(service.exists(temp_dir.path()));
+    assert!(temp_dir.path().join(".clicky").join(".gitignore").exists());
 }
 
 #[test]
@@ -86,3 +88,334 @@ fn test_sanitize_id() {
     );
     assert_eq!(BoardService::sanitize_id("My@Project!"), "myproject");
 }
+
+#[test]
+fn test_lock_times_out_while_held() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::with_lock_timeout(Duration::from_millis(50));
+    service.initialize(temp_dir.path(), None).unwrap();
+    let _held = service.lock(temp_dir.path()).unwrap();
+
+    // Act
+    let result = service.lock(temp_dir.path());
+
+    // Assert
+    match result {
+        Err(e @ BoardServiceError::Storage(StorageError::LockTimeout { .. })) => {
+            assert_eq!(e.code(), "lock_timeout");
+        }
+        other => panic!("Expected a lock timeout, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_lock_without_board() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+
+    // Act
+    let result = service.lock(temp_dir.path());
+
+    // Assert
+    assert!(matches!(result, Err(BoardServiceError::BoardNotFound)));
+}
+
+#[test]
+fn test_save_advances_revision() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+    let mut board = service.initialize(temp_dir.path(), None).unwrap();
+
+    // Act
+    service.save(&mut board, temp_dir.path()).unwrap();
+    service.save(&mut board, temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(board.revision, 2);
+    assert_eq!(service.load(temp_dir.path()).unwrap().revision, 2);
+}
+
+#[test]
+fn test_save_stale_board_conflicts() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+    service.initialize(temp_dir.path(), None).unwrap();
+    let mut first = service.load(temp_dir.path()).unwrap();
+    let mut stale = service.load(temp_dir.path()).unwrap();
+    service.save(&mut first, temp_dir.path()).unwrap();
+
+    // Act
+    let result = service.save(&mut stale, temp_dir.path());
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(BoardServiceError::Conflict {
+            expected: 0,
+            actual: 1
+        })
+    ));
+    assert_eq!(service.load(temp_dir.path()).unwrap().revision, 1);
+}
+
+#[test]
+fn test_save_with_expected_revision() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+    let mut board = service.initialize(temp_dir.path(), None).unwrap();
+    service.save(&mut board, temp_dir.path()).unwrap();
+
+    // Act
+    let stale = BoardService::new()
+        .if_revision(Some(0))
+        .save(&mut board, temp_dir.path());
+    let current = BoardService::new()
+        .if_revision(Some(1))
+        .save(&mut board, temp_dir.path());
+
+    // Assert
+    assert!(matches!(stale, Err(BoardServiceError::Conflict { .. })));
+    assert!(current.is_ok());
+    assert_eq!(board.revision, 2);
+}
+
+#[test]
+fn test_convert_to_sqlite_keeps_board_usable() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+    let board = service.initialize(temp_dir.path(), None).unwrap();
+
+    // Act
+    let conversion = service
+        .convert(temp_dir.path(), StorageBackend::Sqlite)
+        .unwrap();
+    let mut loaded = service.load(temp_dir.path()).unwrap();
+    service.save(&mut loaded, temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(conversion.to, StorageBackend::Sqlite);
+    assert_eq!(loaded.id, board.id);
+    assert_eq!(service.load(temp_dir.path()).unwrap().revision, 1);
+    assert!(!BoardStorage::board_path(temp_dir.path()).exists());
+}
+
+#[test]
+fn test_initialize_with_directory_storage() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+
+    // Act
+    let board = service
+        .initialize_with_storage(temp_dir.path(), None, StorageBackend::Directory)
+        .unwrap();
+
+    // Assert
+    assert_eq!(board.card_id_scheme, CardIdScheme::Random);
+    assert!(BoardStorage::meta_path(temp_dir.path()).exists());
+    assert!(!BoardStorage::board_path(temp_dir.path()).exists());
+    assert_eq!(service.load(temp_dir.path()).unwrap(), board);
+    assert!(matches!(
+        service.initialize(temp_dir.path(), None),
+        Err(BoardServiceError::AlreadyInitialized)
+    ));
+}
+
+#[test]
+fn test_merge_files_writes_merged_board_to_ours() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+    let repository = JsonBoardRepository::new();
+    let base = Board::new("test".to_string(), "Test Board".to_string());
+    let mut ours = base.clone();
+    ours.create_card("Ours".to_string(), None, None, None);
+    let mut theirs = base.clone();
+    theirs.create_card("Theirs".to_string(), None, None, None);
+    let (base_path, ours_path, theirs_path) = (
+        temp_dir.path().join("base"),
+        temp_dir.path().join("ours"),
+        temp_dir.path().join("theirs"),
+    );
+    repository.save_file(&base, &base_path).unwrap();
+    repository.save_file(&ours, &ours_path).unwrap();
+    repository.save_file(&theirs, &theirs_path).unwrap();
+
+    // Act
+    let outcome = service
+        .merge_files(&base_path, &ours_path, &theirs_path)
+        .unwrap();
+
+    // Assert
+    let merged = repository.load_file(&ours_path).unwrap();
+    assert!(outcome.conflicts.is_empty());
+    assert_eq!(outcome.renumbered.len(), 1);
+    assert_eq!(merged, outcome.board);
+    assert_eq!(merged.cards.len(), 2);
+}
+
+#[test]
+fn test_merge_files_without_base() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+    let repository = JsonBoardRepository::new();
+    let ours = Board::new("test".to_string(), "Ours".to_string());
+    let theirs = Board::new("test".to_string(), "Theirs".to_string());
+    let (base_path, ours_path, theirs_path) = (
+        temp_dir.path().join("base"),
+        temp_dir.path().join("ours"),
+        temp_dir.path().join("theirs"),
+    );
+    fs::write(&base_path, "").unwrap();
+    repository.save_file(&ours, &ours_path).unwrap();
+    repository.save_file(&theirs, &theirs_path).unwrap();
+
+    // Act
+    let outcome = service
+        .merge_files(&base_path, &ours_path, &theirs_path)
+        .unwrap();
+
+    // Assert
+    assert!(outcome.conflicts.iter().any(|c| c.field == "name"));
+    assert_eq!(outcome.board.name, "Ours");
+}
+
+#[test]
+fn test_load_for_change_rejects_inconsistent_board() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+    let mut board = service.initialize(temp_dir.path(), None).unwrap();
+    board.create_card("Task".to_string(), None, None, None);
+    board.columns[0].cards.clear();
+    JsonBoardRepository::new()
+        .save(&board, temp_dir.path())
+        .unwrap();
+
+    // Act
+    let loaded = service.load_for_change(temp_dir.path());
+    let read = service.load(temp_dir.path()).unwrap();
+    let issues = service.check(temp_dir.path()).unwrap();
+
+    // Assert
+    assert!(matches!(
+        loaded,
+        Err(BoardServiceError::Inconsistent { count: 1, .. })
+    ));
+    assert_eq!(read.cards.len(), 1);
+    assert!(matches!(
+        BoardService::check_consistency(&read),
+        Err(BoardServiceError::Inconsistent { count: 1, .. })
+    ));
+    assert_eq!(issues.len(), 1);
+}
+
+#[test]
+fn test_repair_saves_fixed_board() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+    let mut board = service.initialize(temp_dir.path(), None).unwrap();
+    let card_id = board.create_card("Task".to_string(), None, None, None);
+    board.columns[0].cards.clear();
+    board.columns[1].cards.push(card_id.clone());
+    JsonBoardRepository::new()
+        .save(&board, temp_dir.path())
+        .unwrap();
+
+    // Act
+    let repaired = service.repair(temp_dir.path()).unwrap();
+    let loaded = service.load(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(repaired.len(), 2);
+    assert_eq!(loaded.columns[0].cards, vec![card_id]);
+    assert!(loaded.columns[1].cards.is_empty());
+    assert!(service.repair(temp_dir.path()).unwrap().is_empty());
+}
+
+#[test]
+fn test_save_takes_snapshots_at_the_configured_interval() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+    service.initialize(temp_dir.path(), None).unwrap();
+    let mut board = service.load(temp_dir.path()).unwrap();
+
+    // Act
+    service.save(&mut board, temp_dir.path()).unwrap();
+    let throttled = service.snapshots(temp_dir.path()).unwrap();
+    BoardConfig {
+        snapshot_interval_minutes: 0,
+        ..Default::default()
+    }
+    .save(temp_dir.path())
+    .unwrap();
+    service.save(&mut board, temp_dir.path()).unwrap();
+    let snapshots = service.snapshots(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(throttled.len(), 1);
+    let revisions: Vec<u64> = snapshots.iter().map(|s| s.revision).collect();
+    assert_eq!(revisions, vec![2, 0]);
+}
+
+#[test]
+fn test_repair_takes_snapshot_of_broken_board() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+    service.initialize(temp_dir.path(), None).unwrap();
+    let mut board = service.load(temp_dir.path()).unwrap();
+    board.columns[0].cards.push("TES-404".to_string());
+    service.save(&mut board, temp_dir.path()).unwrap();
+
+    // Act
+    service.repair(temp_dir.path()).unwrap();
+    let snapshots = service.snapshots(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(snapshots[0].revision, 1);
+    assert_eq!(
+        snapshots[0].load().unwrap().columns[0].cards,
+        vec!["TES-404"]
+    );
+}
+
+#[test]
+fn test_restore_snapshot_keeps_card_counter() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let service = BoardService::new();
+    service.initialize(temp_dir.path(), None).unwrap();
+    let initial = service.snapshots(temp_dir.path()).unwrap().remove(0);
+    let mut board = service.load(temp_dir.path()).unwrap();
+    board.create_card("Task".to_string(), None, None, None);
+    service.save(&mut board, temp_dir.path()).unwrap();
+
+    // Act
+    let (diff_snapshot, diff) = service
+        .diff_snapshot(temp_dir.path(), &initial.name)
+        .unwrap();
+    let (restored, board) = service
+        .restore_snapshot(temp_dir.path(), &initial.name)
+        .unwrap();
+
+    // Assert
+    assert_eq!(diff_snapshot, initial);
+    assert_eq!(diff.added.len(), 1);
+    assert_eq!(restored, initial);
+    assert!(board.cards.is_empty());
+    let replaced = service.snapshots(temp_dir.path()).unwrap().remove(0);
+    assert_eq!(replaced.load().unwrap().cards.len(), 1);
+    assert_eq!(board.revision, 2);
+    assert_eq!(board.next_card_number, 2);
+    assert_eq!(service.load(temp_dir.path()).unwrap(), board);
+}
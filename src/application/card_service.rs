@@ -1,16 +1,35 @@
+use std::collections::BTreeSet;
 use std::path::Path;
 
-use crate::domain::Board;
+use chrono::{Duration, NaiveDate, Utc};
+
+use crate::domain::{
+    Activity, ActivityFilter, ActivityKind, Board, Card, CardFilter, CardPosition, CardState,
+    ChildPolicy, FieldChange, Label, LinkError, Operation, ParentError, Priority, WipLimitExceeded,
+    WipPolicy,
+};
+use crate::infrastructure::config::BoardConfig;
 use crate::infrastructure::storage::StorageError;
 
+use super::actor::current_actor;
 use super::{BoardService, BoardServiceError};
 
+/// Actor recorded for changes clicky makes on its own, like archiving
+/// cards automatically and expiring claims.
+pub const SYSTEM_ACTOR: &str = "clicky";
+
 /// Service for card-related operations.
 ///
 /// Provides high-level operations for creating, updating, moving,
-/// and deleting cards within a board.
+/// and deleting cards within a board. Every change is recorded in the
+/// board's activity log under the service's actor, and in the journal
+/// that allows undoing it.
 pub struct CardService {
     board_service: BoardService,
+    actor: String,
+    /// Whether changes may exceed WIP limits the board enforces, start
+    /// blocked cards and touch cards claimed by others
+    force: bool,
 }
 
 /// Errors that can occur during card operations.
@@ -27,6 +46,143 @@ pub enum CardServiceError {
     ColumnNotFound(String),
     #[error("Invalid card data: {0}")]
     InvalidData(String),
+    #[error("{0}")]
+    InvalidLabel(String),
+    #[error("Invalid position: {0}")]
+    InvalidPosition(String),
+    #[error("Column {} is at its WIP limit of {}", .0.column_id, .0.limit)]
+    WipLimitExceeded(#[from] WipLimitExceeded),
+    #[error("{0}")]
+    Link(#[from] LinkError),
+    #[error("{blocker} does not block {blocked}")]
+    NotLinked { blocker: String, blocked: String },
+    #[error("Card {card_id} is blocked by {}", .blockers.join(", "))]
+    Blocked {
+        card_id: String,
+        blockers: Vec<String>,
+    },
+    #[error("{0}")]
+    Parent(#[from] ParentError),
+    #[error("Card {card_id} has child cards: {}", .children.join(", "))]
+    HasChildren {
+        card_id: String,
+        children: Vec<String>,
+    },
+    #[error("Card {card_id} has no checklist item {item}")]
+    ChecklistItemNotFound { card_id: String, item: usize },
+    #[error("Card {card_id} has {open} open checklist item(s)")]
+    ChecklistIncomplete { card_id: String, open: usize },
+    #[error("Card is already archived: {0}")]
+    AlreadyArchived(String),
+    #[error("Card is not archived: {0}")]
+    NotArchived(String),
+    #[error("Card {card_id} is claimed by {claimant}")]
+    ClaimedByOther { card_id: String, claimant: String },
+    #[error("Card is not claimed: {0}")]
+    NotClaimed(String),
+    #[error("No card is ready to be picked up")]
+    NoCardAvailable,
+    #[error("Nothing to undo")]
+    NothingToUndo,
+    #[error("Nothing to redo")]
+    NothingToRedo,
+    #[error("{0}")]
+    UndoConflict(String),
+}
+
+impl CardServiceError {
+    /// Returns a stable, machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            CardServiceError::BoardService(e) => e.code(),
+            CardServiceError::Storage(e) => e.code(),
+            CardServiceError::CardNotFound(_) => "card_not_found",
+            CardServiceError::ColumnNotFound(_) => "column_not_found",
+            CardServiceError::InvalidData(_) => "invalid_card_data",
+            CardServiceError::InvalidLabel(_) => "invalid_label",
+            CardServiceError::InvalidPosition(_) => "invalid_position",
+            CardServiceError::WipLimitExceeded(_) => "wip_limit_exceeded",
+            CardServiceError::Link(LinkError::Cycle { .. }) => "dependency_cycle",
+            CardServiceError::Link(_) => "invalid_link",
+            CardServiceError::NotLinked { .. } => "not_linked",
+            CardServiceError::Blocked { .. } => "card_blocked",
+            CardServiceError::Parent(ParentError::Cycle { .. }) => "parent_cycle",
+            CardServiceError::Parent(_) => "invalid_parent",
+            CardServiceError::HasChildren { .. } => "card_has_children",
+            CardServiceError::ChecklistItemNotFound { .. } => "checklist_item_not_found",
+            CardServiceError::ChecklistIncomplete { .. } => "checklist_incomplete",
+            CardServiceError::AlreadyArchived(_) => "already_archived",
+            CardServiceError::NotArchived(_) => "not_archived",
+            CardServiceError::ClaimedByOther { .. } => "card_claimed",
+            CardServiceError::NotClaimed(_) => "not_claimed",
+            CardServiceError::NoCardAvailable => "no_card_available",
+            CardServiceError::NothingToUndo => "nothing_to_undo",
+            CardServiceError::NothingToRedo => "nothing_to_redo",
+            CardServiceError::UndoConflict(_) => "undo_conflict",
+        }
+    }
+}
+
+/// Details of a card to create.
+#[derive(Debug, Default)]
+pub struct NewCard {
+    pub title: String,
+    pub description: Option<String>,
+    pub assignee: Option<String>,
+    /// Target column (defaults to the first column)
+    pub column_id: Option<String>,
+    pub priority: Option<Priority>,
+    pub labels: Vec<String>,
+    pub due: Option<NaiveDate>,
+    /// Card the new card is part of, e.g. its epic
+    pub parent: Option<String>,
+}
+
+impl NewCard {
+    /// Creates card details with only a title set.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Changes to apply to a card.
+///
+/// Fields left as `None` are not modified. For optional card fields,
+/// `Some(None)` clears the value.
+#[derive(Debug, Default)]
+pub struct CardUpdate {
+    pub title: Option<String>,
+    pub description: Option<Option<String>>,
+    pub assignee: Option<Option<String>>,
+    pub priority: Option<Option<Priority>>,
+    pub due: Option<Option<NaiveDate>>,
+    /// Replaces all labels of the card
+    pub labels: Option<Vec<String>>,
+    /// Labels to add, applied after `labels`
+    pub add_labels: Vec<String>,
+    /// Labels to remove, applied after `add_labels`
+    pub remove_labels: Vec<String>,
+    pub parent: Option<Option<String>>,
+}
+
+/// A change to the checklist of a card.
+///
+/// Items are numbered from 1, in the order `clicky show` lists them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChecklistEdit {
+    /// Appends an open item with the given text
+    Add(String),
+    /// Marks an item as done
+    Check(usize),
+    /// Marks an item as not done
+    Uncheck(usize),
+    /// Moves an item to a new number, or to the end if the number is past it
+    Move { item: usize, to: usize },
+    /// Removes an item
+    Remove(usize),
 }
 
 /// Information about a created card.
@@ -35,42 +191,131 @@ pub struct CreatedCardInfo {
     pub board: Board,
 }
 
+/// A card picked by [`CardService::next`] and the resulting board.
+pub struct PickedCard {
+    pub card_id: String,
+    /// Whether the card was claimed
+    pub claimed: bool,
+    pub board: Board,
+}
+
+/// Operations undone or redone, in the order they were applied, and the
+/// resulting board.
+pub struct ReplayedOperations {
+    pub operations: Vec<Operation>,
+    pub board: Board,
+}
+
 impl CardService {
-    /// Creates a new card service.
+    /// Creates a new card service acting as the current user.
+    ///
+    /// See [`current_actor`] for how the user is determined.
     pub fn new() -> Self {
+        Self::with_actor(current_actor())
+    }
+
+    /// Creates a new card service that records changes under `actor`.
+    pub fn with_actor(actor: impl Into<String>) -> Self {
+        Self::with_board_service(BoardService::new(), actor)
+    }
+
+    /// Creates a card service working through `board_service`, e.g. one
+    /// backed by an in-memory repository, that records changes under `actor`.
+    pub fn with_board_service(board_service: BoardService, actor: impl Into<String>) -> Self {
         Self {
-            board_service: BoardService::new(),
+            board_service,
+            actor: actor.into(),
+            force: false,
         }
     }
 
+    /// Only applies changes while the board is at `revision`.
+    ///
+    /// See [`BoardService::if_revision`].
+    pub fn if_revision(mut self, revision: Option<u64>) -> Self {
+        self.board_service = self.board_service.if_revision(revision);
+        self
+    }
+
+    /// Lets cards be created in and moved to columns at their WIP limit
+    /// even if the board blocks it, lets blocked cards be moved out of the
+    /// first column, lets cards with open checklist items be moved to the
+    /// done column, and lets cards claimed by others be changed, archived
+    /// or deleted.
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
     /// Creates a new card in the board.
     ///
+    /// The card goes before the first card of a lower priority in its
+    /// column, so columns stay sorted by priority until ranked by hand.
+    ///
     /// # Arguments
     /// * `base_path` - Path to the board directory
-    /// * `title` - Card title
-    /// * `description` - Optional description
-    /// * `assignee` - Optional assignee name
-    /// * `column_id` - Optional target column (defaults to "todo")
+    /// * `new_card` - Details of the card to create
+    ///
+    /// # Errors
+    /// Returns `CardServiceError::CardNotFound` if the parent does not
+    /// exist.
     pub fn create(
         &self,
         base_path: &Path,
-        title: String,
-        description: Option<String>,
-        assignee: Option<String>,
-        column_id: Option<String>,
+        new_card: NewCard,
     ) -> Result<CreatedCardInfo, CardServiceError> {
-        let mut board = self.board_service.load(base_path)?;
+        let labels = Self::normalize_labels(&new_card.labels)?;
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load(base_path)?;
 
         // Validate column if specified
-        if let Some(ref col_id) = column_id {
+        if let Some(ref col_id) = new_card.column_id {
             if !board.columns.iter().any(|c| c.id == *col_id) {
                 return Err(CardServiceError::ColumnNotFound(col_id.clone()));
             }
         }
+        if let Some(parent) = &new_card.parent {
+            if board.get_card(parent).is_none() {
+                return Err(CardServiceError::CardNotFound(parent.clone()));
+            }
+        }
 
-        let card_id = board.create_card(title, description, assignee, column_id);
+        let card_id = board.create_card(
+            new_card.title,
+            new_card.description,
+            new_card.assignee,
+            new_card.column_id,
+        );
+        for label in &labels {
+            board.register_label(label);
+        }
+        if let Some(card) = board.get_card_mut(&card_id) {
+            card.priority = new_card.priority;
+            card.labels = labels;
+            card.due = new_card.due;
+            card.parent = new_card.parent;
+        }
+        if let Some(column_id) = board.get_card(&card_id).map(|c| c.column_id.clone()) {
+            let position = board.priority_position(&card_id, &column_id);
+            board.rank_card(&card_id, &position);
+        }
+        if let Some(card) = board.get_card(&card_id) {
+            self.enforce_wip_limit(&board, &card.column_id)?;
+            let kind = ActivityKind::Created {
+                title: card.title.clone(),
+                column_id: card.column_id.clone(),
+            };
+            self.record(&mut board, &card_id, kind);
+        }
 
-        self.board_service.save(&board, base_path)?;
+        self.save(&mut board, base_path)?;
+        self.journal(
+            base_path,
+            &board,
+            &card_id,
+            None,
+            format!("create {}", card_id),
+        )?;
 
         Ok(CreatedCardInfo { card_id, board })
     }
@@ -81,31 +326,134 @@ impl CardService {
     /// * `base_path` - Path to the board directory
     /// * `card_id` - ID of the card to move
     /// * `column_id` - Target column ID
+    #[allow(dead_code)]
     pub fn move_to(
         &self,
         base_path: &Path,
         card_id: &str,
         column_id: &str,
     ) -> Result<Board, CardServiceError> {
-        let mut board = self.board_service.load(base_path)?;
+        self.move_to_position(base_path, card_id, column_id, None)
+    }
+
+    /// Moves a card to a column, optionally at a position within it.
+    ///
+    /// Without a position the card goes before the first card of a lower
+    /// priority in the column, or keeps its place if it is already in it.
+    ///
+    /// Moving a card to the done column releases its claim.
+    ///
+    /// # Errors
+    /// Unless the service is forced, returns `CardServiceError::Blocked` if
+    /// the card is moved past the first column while one of its blockers is
+    /// not done, `CardServiceError::ChecklistIncomplete` if the card is
+    /// moved to the done column with open checklist items while the board's
+    /// config requires them to be done, and
+    /// `CardServiceError::ClaimedByOther` if someone else holds a claim on
+    /// the card.
+    pub fn move_to_position(
+        &self,
+        base_path: &Path,
+        card_id: &str,
+        column_id: &str,
+        position: Option<&CardPosition>,
+    ) -> Result<Board, CardServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load(base_path)?;
 
         // Validate card exists
-        if board.get_card(card_id).is_none() {
-            return Err(CardServiceError::CardNotFound(card_id.to_string()));
-        }
+        let card = board
+            .get_card(card_id)
+            .ok_or_else(|| CardServiceError::CardNotFound(card_id.to_string()))?;
+        self.enforce_claim(card)?;
+        let from = card.column_id.clone();
 
         // Validate column exists
         if !board.columns.iter().any(|c| c.id == column_id) {
             return Err(CardServiceError::ColumnNotFound(column_id.to_string()));
         }
 
+        if from != column_id {
+            self.enforce_ready(&board, card_id, column_id)?;
+            self.enforce_checklist(&board, base_path, card_id, column_id)?;
+        }
+
         // Move card
-        let success = board.move_card(card_id, column_id);
+        let before = CardState::of(&board, card_id);
+        let success = match position {
+            Some(position) => {
+                Self::check_position(&board, card_id, column_id, position)?;
+                board.move_card_to(card_id, column_id, position)
+            }
+            None => board.move_card(card_id, column_id),
+        };
         if !success {
             return Err(CardServiceError::CardNotFound(card_id.to_string()));
         }
 
-        self.board_service.save(&board, base_path)?;
+        let moved = from != column_id;
+        if moved {
+            self.enforce_wip_limit(&board, column_id)?;
+            let kind = ActivityKind::Moved {
+                from,
+                to: column_id.to_string(),
+            };
+            self.record(&mut board, card_id, kind);
+            if board.is_done_column(column_id) && board.release_claim(card_id) {
+                self.record(
+                    &mut board,
+                    card_id,
+                    ActivityKind::Released { expired: false },
+                );
+            }
+        }
+
+        self.save(&mut board, base_path)?;
+        let reordered =
+            Self::position_of(&before) != Self::position_of(&CardState::of(&board, card_id));
+        if moved || reordered {
+            let description = format!("move {} to {}", card_id, column_id);
+            self.journal(base_path, &board, card_id, before, description)?;
+        }
+
+        Ok(board)
+    }
+
+    /// Moves a card to a position within its column.
+    ///
+    /// Ranking is undoable but not recorded in the activity log, since the
+    /// card itself does not change.
+    pub fn rank(
+        &self,
+        base_path: &Path,
+        card_id: &str,
+        position: &CardPosition,
+    ) -> Result<Board, CardServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load(base_path)?;
+
+        let column_id = board
+            .get_card(card_id)
+            .map(|c| c.column_id.clone())
+            .ok_or_else(|| CardServiceError::CardNotFound(card_id.to_string()))?;
+        Self::check_position(&board, card_id, &column_id, position)?;
+
+        let before = CardState::of(&board, card_id);
+        if !board.rank_card(card_id, position) {
+            return Err(CardServiceError::CardNotFound(card_id.to_string()));
+        }
+        if Self::position_of(&before) == Self::position_of(&CardState::of(&board, card_id)) {
+            return Ok(board);
+        }
+
+        self.save(&mut board, base_path)?;
+        self.journal(
+            base_path,
+            &board,
+            card_id,
+            before,
+            format!("rank {}", card_id),
+        )?;
 
         Ok(board)
     }
@@ -115,36 +463,145 @@ impl CardService {
     /// # Arguments
     /// * `base_path` - Path to the board directory
     /// * `card_id` - ID of the card to update
-    /// * `title` - Optional new title
-    /// * `description` - Optional new description (None to clear)
-    /// * `assignee` - Optional new assignee (None to clear)
+    /// * `changes` - Fields to change
+    ///
+    /// # Errors
+    /// Returns `CardServiceError::CardNotFound` if the new parent does not
+    /// exist, `CardServiceError::Parent` if the card would end up below
+    /// itself, and, unless the service is forced,
+    /// `CardServiceError::ClaimedByOther` if someone else holds a claim on
+    /// the card.
     pub fn update(
         &self,
         base_path: &Path,
         card_id: &str,
-        title: Option<String>,
-        description: Option<Option<String>>,
-        assignee: Option<Option<String>>,
+        changes: CardUpdate,
     ) -> Result<Board, CardServiceError> {
-        let mut board = self.board_service.load(base_path)?;
+        let labels = changes
+            .labels
+            .as_deref()
+            .map(Self::normalize_labels)
+            .transpose()?;
+        let add_labels = Self::normalize_labels(&changes.add_labels)?;
+        let remove_labels = Self::normalize_labels(&changes.remove_labels)?;
+
+        let _lock = self.board_service.lock(base_path)?;
+
+        let mut board = self.load(base_path)?;
+        let before_state = CardState::of(&board, card_id);
+
+        for label in labels.iter().flatten().chain(&add_labels) {
+            board.register_label(label);
+        }
 
         let card = board
             .get_card_mut(card_id)
             .ok_or_else(|| CardServiceError::CardNotFound(card_id.to_string()))?;
+        self.enforce_claim(card)?;
+        let before = card.clone();
 
-        if let Some(new_title) = title {
+        if let Some(new_title) = changes.title {
             card.set_title(new_title);
         }
 
-        if let Some(new_description) = description {
+        if let Some(new_description) = changes.description {
             card.set_description(new_description);
         }
 
-        if let Some(new_assignee) = assignee {
+        if let Some(new_assignee) = changes.assignee {
             card.set_assignee(new_assignee);
         }
 
-        self.board_service.save(&board, base_path)?;
+        if let Some(new_priority) = changes.priority {
+            card.set_priority(new_priority);
+        }
+
+        if let Some(new_due) = changes.due {
+            card.set_due(new_due);
+        }
+
+        if let Some(new_labels) = labels {
+            card.set_labels(new_labels);
+        }
+
+        for label in add_labels {
+            card.add_label(label);
+        }
+
+        for label in &remove_labels {
+            card.remove_label(label);
+        }
+
+        if let Some(parent) = &changes.parent {
+            if let Some(parent) = parent.as_deref() {
+                if board.get_card(parent).is_none() {
+                    return Err(CardServiceError::CardNotFound(parent.to_string()));
+                }
+            }
+            board.set_parent(card_id, parent.as_deref())?;
+        }
+
+        let card = board
+            .get_card(card_id)
+            .ok_or_else(|| CardServiceError::CardNotFound(card_id.to_string()))?;
+        let field_changes = FieldChange::between(&before, card);
+        let updated = !field_changes.is_empty();
+        if updated {
+            let kind = ActivityKind::Updated {
+                changes: field_changes,
+            };
+            self.record(&mut board, card_id, kind);
+        }
+
+        self.save(&mut board, base_path)?;
+        if updated {
+            let description = format!("update {}", card_id);
+            self.journal(base_path, &board, card_id, before_state, description)?;
+        }
+
+        Ok(board)
+    }
+
+    /// Adds a comment to a card.
+    ///
+    /// # Arguments
+    /// * `base_path` - Path to the board directory
+    /// * `card_id` - ID of the card to comment on
+    /// * `author` - Name of the person or agent writing the comment
+    /// * `body` - Comment text
+    pub fn add_comment(
+        &self,
+        base_path: &Path,
+        card_id: &str,
+        author: &str,
+        body: &str,
+    ) -> Result<Board, CardServiceError> {
+        let body = body.trim();
+        if body.is_empty() {
+            return Err(CardServiceError::InvalidData(
+                "Comment cannot be empty".to_string(),
+            ));
+        }
+
+        let _lock = self.board_service.lock(base_path)?;
+
+        let mut board = self.load(base_path)?;
+        let before = CardState::of(&board, card_id);
+
+        let card = board
+            .get_card_mut(card_id)
+            .ok_or_else(|| CardServiceError::CardNotFound(card_id.to_string()))?;
+        self.enforce_claim(card)?;
+        card.add_comment(author.to_string(), body.to_string());
+        board.record_activity(Activity::new(
+            card_id.to_string(),
+            author.to_string(),
+            ActivityKind::Commented,
+        ));
+
+        self.save(&mut board, base_path)?;
+        let description = format!("comment on {}", card_id);
+        self.journal(base_path, &board, card_id, before, description)?;
 
         Ok(board)
     }
@@ -154,19 +611,423 @@ impl CardService {
     /// # Arguments
     /// * `base_path` - Path to the board directory
     /// * `card_id` - ID of the card to delete
+    ///
+    /// # Errors
+    /// Returns `CardServiceError::HasChildren` if the card has children; see
+    /// [`CardService::delete_with_children`].
+    #[allow(dead_code)]
     pub fn delete(&self, base_path: &Path, card_id: &str) -> Result<Board, CardServiceError> {
-        let mut board = self.board_service.load(base_path)?;
+        self.delete_with_children(base_path, card_id, None)
+    }
 
-        let deleted = board.delete_card(card_id);
-        if !deleted {
+    /// Deletes a card, dealing with its children according to `policy`.
+    ///
+    /// The deletion and the changes to the children are undone in one step.
+    ///
+    /// # Errors
+    /// Returns `CardServiceError::HasChildren` if the card has children and
+    /// no policy is given, and `CardServiceError::ClaimedByOther` if someone
+    /// else holds a claim on a card that would be deleted.
+    pub fn delete_with_children(
+        &self,
+        base_path: &Path,
+        card_id: &str,
+        policy: Option<ChildPolicy>,
+    ) -> Result<Board, CardServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load(base_path)?;
+
+        if board.get_card(card_id).is_none() {
             return Err(CardServiceError::CardNotFound(card_id.to_string()));
         }
+        let children: Vec<String> = board
+            .children(card_id)
+            .iter()
+            .map(|c| c.id.clone())
+            .collect();
+        let policy = match policy {
+            Some(policy) => policy,
+            None if children.is_empty() => ChildPolicy::Orphan,
+            None => {
+                return Err(CardServiceError::HasChildren {
+                    card_id: card_id.to_string(),
+                    children,
+                })
+            }
+        };
+
+        let mut touched = vec![card_id.to_string()];
+        if policy == ChildPolicy::Cascade {
+            touched.extend(board.descendants(card_id).iter().map(|c| c.id.clone()));
+        } else {
+            touched.extend(children);
+        }
+        // Cards blocked by a deleted card lose the link, which undo restores.
+        let deleting: Vec<String> = match policy {
+            ChildPolicy::Cascade => touched.clone(),
+            _ => vec![card_id.to_string()],
+        };
+        for card in deleting.iter().filter_map(|id| board.get_card(id)) {
+            self.enforce_claim(card)?;
+        }
+        let dependents: Vec<String> = board
+            .cards
+            .iter()
+            .filter(|c| !touched.contains(&c.id))
+            .filter(|c| deleting.iter().any(|id| c.blocked_by.contains(id)))
+            .map(|c| c.id.clone())
+            .collect();
+        touched.extend(dependents);
+        let before: Vec<Card> = touched
+            .iter()
+            .filter_map(|id| board.get_card(id).cloned())
+            .collect();
+        let states: Vec<(String, Option<CardState>)> = touched
+            .iter()
+            .map(|id| (id.clone(), CardState::of(&board, id)))
+            .collect();
+
+        self.board_service.snapshot(&board, base_path)?;
+        let deleted = board.delete_card_with_children(card_id, policy);
+        for card in before {
+            if deleted.contains(&card.id) {
+                let kind = ActivityKind::Deleted { title: card.title };
+                self.record(&mut board, &card.id, kind);
+            } else if let Some(after) = board.get_card(&card.id) {
+                let kind = ActivityKind::Updated {
+                    changes: FieldChange::between(&card, after),
+                };
+                self.record(&mut board, &card.id, kind);
+            }
+        }
+
+        self.save(&mut board, base_path)?;
+        let description = format!("delete {}", card_id);
+        self.journal_cards(base_path, &board, states, description)?;
+
+        Ok(board)
+    }
+
+    /// Archives a card, hiding it from the board but keeping it and its
+    /// history in storage.
+    pub fn archive(&self, base_path: &Path, card_id: &str) -> Result<Board, CardServiceError> {
+        self.set_archived(base_path, card_id, true)
+    }
+
+    /// Returns an archived card to its column.
+    pub fn unarchive(&self, base_path: &Path, card_id: &str) -> Result<Board, CardServiceError> {
+        self.set_archived(base_path, card_id, false)
+    }
+
+    /// Picks the card to work on next, see [`Board::next_card`].
+    ///
+    /// Cards are picked from `filter.column` if set, otherwise from the
+    /// `next_columns` of the board's config, or the first column. With a
+    /// claim TTL, the card is claimed for this service's actor and moved to
+    /// the config's `claim_column` or the in-progress column while the board
+    /// stays locked, so two agents never claim the same card.
+    ///
+    /// # Errors
+    /// Returns `CardServiceError::NoCardAvailable` if no card qualifies.
+    pub fn next(
+        &self,
+        base_path: &Path,
+        filter: &CardFilter,
+        claim: Option<Duration>,
+    ) -> Result<PickedCard, CardServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load(base_path)?;
+        let config = BoardConfig::load(base_path)?;
+
+        let columns = match &filter.column {
+            Some(column_id) => vec![column_id.clone()],
+            None if !config.next_columns.is_empty() => config.next_columns.clone(),
+            None => board.columns.iter().take(1).map(|c| c.id.clone()).collect(),
+        };
+        let target = match &config.claim_column {
+            Some(column_id) => Some(column_id.clone()),
+            None => board.in_progress_column().map(|c| c.id.clone()),
+        };
+        for column_id in columns.iter().chain(&target) {
+            if !board.columns.iter().any(|c| c.id == *column_id) {
+                return Err(CardServiceError::ColumnNotFound(column_id.clone()));
+            }
+        }
+
+        let now = Utc::now();
+        let card = board
+            .next_card(&columns, filter, &self.actor, now)
+            .cloned()
+            .ok_or(CardServiceError::NoCardAvailable)?;
+        let Some(ttl) = claim else {
+            return Ok(PickedCard {
+                card_id: card.id,
+                claimed: false,
+                board,
+            });
+        };
+
+        self.enforce_claim(&card)?;
+        let before = CardState::of(&board, &card.id);
+        board.claim_card(&card.id, &self.actor, ttl, now);
+        if let Some(target) = target.filter(|t| *t != card.column_id) {
+            board.move_card(&card.id, &target);
+            self.enforce_wip_limit(&board, &target)?;
+        }
+        let kind = ActivityKind::Claimed {
+            expires_at: now + ttl,
+        };
+        Self::record_changes(&mut board, &self.actor, &card, kind);
+
+        self.save(&mut board, base_path)?;
+        let description = format!("claim {}", card.id);
+        self.journal(base_path, &board, &card.id, before, description)?;
+
+        Ok(PickedCard {
+            card_id: card.id,
+            claimed: true,
+            board,
+        })
+    }
+
+    /// Claims a card for this service's actor for `ttl`, assigning it to
+    /// them.
+    ///
+    /// The claim has to be renewed with [`CardService::heartbeat`] before
+    /// it expires; expired claims are released, see [`CardService::release`].
+    /// Claiming a card again renews the claim with the new TTL.
+    ///
+    /// # Errors
+    /// Unless the service is forced, returns
+    /// `CardServiceError::ClaimedByOther` if someone else holds a claim on
+    /// the card.
+    pub fn claim(
+        &self,
+        base_path: &Path,
+        card_id: &str,
+        ttl: Duration,
+    ) -> Result<Board, CardServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load(base_path)?;
+
+        let card = board
+            .get_card(card_id)
+            .cloned()
+            .ok_or_else(|| CardServiceError::CardNotFound(card_id.to_string()))?;
+        self.enforce_claim(&card)?;
+
+        let before = CardState::of(&board, card_id);
+        let now = Utc::now();
+        board.claim_card(card_id, &self.actor, ttl, now);
+        let kind = ActivityKind::Claimed {
+            expires_at: now + ttl,
+        };
+        Self::record_changes(&mut board, &self.actor, &card, kind);
+
+        self.save(&mut board, base_path)?;
+        let description = format!("claim {}", card_id);
+        self.journal(base_path, &board, card_id, before, description)?;
+
+        Ok(board)
+    }
+
+    /// Extends the claim on a card by its TTL.
+    ///
+    /// Heartbeats are neither recorded in the activity log nor undoable.
+    ///
+    /// # Errors
+    /// Returns `CardServiceError::NotClaimed` if the card has no claim, and,
+    /// unless the service is forced, `CardServiceError::ClaimedByOther` if
+    /// someone else holds it.
+    pub fn heartbeat(&self, base_path: &Path, card_id: &str) -> Result<Board, CardServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load(base_path)?;
+
+        let card = board
+            .get_card(card_id)
+            .ok_or_else(|| CardServiceError::CardNotFound(card_id.to_string()))?;
+        if card.claim.is_none() {
+            return Err(CardServiceError::NotClaimed(card_id.to_string()));
+        }
+        self.enforce_claim(card)?;
 
-        self.board_service.save(&board, base_path)?;
+        board.renew_claim(card_id, Utc::now());
+        self.save(&mut board, base_path)?;
 
         Ok(board)
     }
 
+    /// Releases the claim on a card.
+    ///
+    /// Unless the card is done, it returns to the column and assignee it
+    /// had before it was claimed, as when the claim expires.
+    ///
+    /// # Errors
+    /// Returns `CardServiceError::NotClaimed` if the card has no claim, and,
+    /// unless the service is forced, `CardServiceError::ClaimedByOther` if
+    /// someone else holds it.
+    pub fn release(&self, base_path: &Path, card_id: &str) -> Result<Board, CardServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load(base_path)?;
+
+        let card = board
+            .get_card(card_id)
+            .cloned()
+            .ok_or_else(|| CardServiceError::CardNotFound(card_id.to_string()))?;
+        if card.claim.is_none() {
+            return Err(CardServiceError::NotClaimed(card_id.to_string()));
+        }
+        self.enforce_claim(&card)?;
+
+        let before = CardState::of(&board, card_id);
+        board.release_claim(card_id);
+        if let Some(released) = board.get_card(card_id) {
+            if released.column_id != card.column_id {
+                self.enforce_wip_limit(&board, &released.column_id)?;
+            }
+        }
+        let kind = ActivityKind::Released { expired: false };
+        Self::record_changes(&mut board, &self.actor, &card, kind);
+
+        self.save(&mut board, base_path)?;
+        let description = format!("release {}", card_id);
+        self.journal(base_path, &board, card_id, before, description)?;
+
+        Ok(board)
+    }
+
+    /// Changes the checklist of a card.
+    ///
+    /// Checklist changes are recorded as updates of the card. Checking an
+    /// item that is already done, or the like, changes nothing.
+    ///
+    /// # Errors
+    /// Returns `CardServiceError::ChecklistItemNotFound` if the card has no
+    /// item with the given number, `CardServiceError::InvalidData` if an
+    /// added item has no text, and, unless the service is forced,
+    /// `CardServiceError::ClaimedByOther` if someone else holds a claim on
+    /// the card.
+    pub fn edit_checklist(
+        &self,
+        base_path: &Path,
+        card_id: &str,
+        edit: &ChecklistEdit,
+    ) -> Result<Board, CardServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load(base_path)?;
+        let before_state = CardState::of(&board, card_id);
+
+        let card = board
+            .get_card_mut(card_id)
+            .ok_or_else(|| CardServiceError::CardNotFound(card_id.to_string()))?;
+        self.enforce_claim(card)?;
+        let before = card.clone();
+
+        let index = |item: &usize| item.checked_sub(1);
+        let (item, found) = match edit {
+            ChecklistEdit::Add(text) => {
+                let text = text.trim();
+                if text.is_empty() {
+                    return Err(CardServiceError::InvalidData(
+                        "Checklist item cannot be empty".to_string(),
+                    ));
+                }
+                (card.add_checklist_item(text.to_string()) + 1, true)
+            }
+            ChecklistEdit::Check(item) | ChecklistEdit::Uncheck(item) => {
+                let done = matches!(edit, ChecklistEdit::Check(_));
+                let found = index(item).is_some_and(|i| card.set_checklist_item_done(i, done));
+                (*item, found)
+            }
+            ChecklistEdit::Move { item, to } => {
+                let to = index(to).unwrap_or(0);
+                let found = index(item).is_some_and(|i| card.move_checklist_item(i, to));
+                (*item, found)
+            }
+            ChecklistEdit::Remove(item) => {
+                let found = index(item).is_some_and(|i| card.remove_checklist_item(i).is_some());
+                (*item, found)
+            }
+        };
+        if !found {
+            return Err(CardServiceError::ChecklistItemNotFound {
+                card_id: card_id.to_string(),
+                item,
+            });
+        }
+
+        let changes = FieldChange::between(&before, card);
+        if changes.is_empty() {
+            return Ok(board);
+        }
+        self.record(&mut board, card_id, ActivityKind::Updated { changes });
+
+        self.save(&mut board, base_path)?;
+        let description = format!("edit checklist of {}", card_id);
+        self.journal(base_path, &board, card_id, before_state, description)?;
+
+        Ok(board)
+    }
+
+    /// Makes `blocker` block `blocked`: `blocked` is not ready until
+    /// `blocker` is in the done column.
+    ///
+    /// Links are recorded as changes to the blocked card.
+    ///
+    /// # Errors
+    /// Returns `CardServiceError::Link` if the link would make a card block
+    /// itself, directly or through other cards.
+    pub fn link(
+        &self,
+        base_path: &Path,
+        blocker: &str,
+        blocked: &str,
+    ) -> Result<Board, CardServiceError> {
+        self.set_link(base_path, blocker, blocked, true)
+    }
+
+    /// Removes the link that makes `blocker` block `blocked`.
+    pub fn unlink(
+        &self,
+        base_path: &Path,
+        blocker: &str,
+        blocked: &str,
+    ) -> Result<Board, CardServiceError> {
+        self.set_link(base_path, blocker, blocked, false)
+    }
+
+    /// Reverses the latest `steps` card operations of this service's actor.
+    ///
+    /// Only the cards an operation touched are restored, so changes other
+    /// processes made to other cards in the meantime are kept. Fewer
+    /// operations are undone if the journal holds fewer.
+    ///
+    /// # Errors
+    /// Returns `CardServiceError::UndoConflict`, changing nothing, if a card
+    /// was changed since the operation that is to be undone.
+    pub fn undo(
+        &self,
+        base_path: &Path,
+        steps: usize,
+    ) -> Result<ReplayedOperations, CardServiceError> {
+        self.replay(base_path, steps, true)
+    }
+
+    /// Repeats the latest `steps` card operations this service's actor undid.
+    ///
+    /// Undone operations are discarded when the actor makes a new change.
+    ///
+    /// # Errors
+    /// Returns `CardServiceError::UndoConflict`, changing nothing, if a card
+    /// was changed since its operation was undone.
+    pub fn redo(
+        &self,
+        base_path: &Path,
+        steps: usize,
+    ) -> Result<ReplayedOperations, CardServiceError> {
+        self.replay(base_path, steps, false)
+    }
+
     /// Gets a card by ID.
     pub fn get(&self, base_path: &Path, card_id: &str) -> Result<Board, CardServiceError> {
         let board = self.board_service.load(base_path)?;
@@ -182,6 +1043,511 @@ impl CardService {
     pub fn list(&self, base_path: &Path) -> Result<Board, CardServiceError> {
         Ok(self.board_service.load(base_path)?)
     }
+
+    /// Lists the cards matching a filter.
+    ///
+    /// Returns the board with only the matching cards, letting the storage
+    /// backend select them. The board is a read-only view and must not be
+    /// saved.
+    pub fn list_matching(
+        &self,
+        base_path: &Path,
+        filter: &CardFilter,
+    ) -> Result<Board, CardServiceError> {
+        Ok(self.board_service.load_matching(base_path, filter)?)
+    }
+
+    /// Lists the cards matching a filter that are below `epic`, at any
+    /// depth.
+    ///
+    /// Returns the board with only those cards, like
+    /// [`CardService::list_matching`]. The whole board is loaded, since
+    /// cards between the epic and a matching card need not match.
+    pub fn list_under(
+        &self,
+        base_path: &Path,
+        epic: &str,
+        filter: &CardFilter,
+    ) -> Result<Board, CardServiceError> {
+        let mut board = self.board_service.load(base_path)?;
+        if board.get_card(epic).is_none() {
+            return Err(CardServiceError::CardNotFound(epic.to_string()));
+        }
+
+        let below: BTreeSet<String> = board
+            .descendants(epic)
+            .iter()
+            .map(|c| c.id.clone())
+            .collect();
+        board
+            .cards
+            .retain(|card| below.contains(&card.id) && filter.matches(card));
+        Ok(board)
+    }
+
+    /// Gets the recorded activity of a card, oldest first.
+    ///
+    /// The history of deleted cards remains available.
+    pub fn history(
+        &self,
+        base_path: &Path,
+        card_id: &str,
+    ) -> Result<Vec<Activity>, CardServiceError> {
+        let board = self.board_service.load(base_path)?;
+
+        let history: Vec<Activity> = board.card_history(card_id).into_iter().cloned().collect();
+        if history.is_empty() && board.get_card(card_id).is_none() {
+            return Err(CardServiceError::CardNotFound(card_id.to_string()));
+        }
+
+        Ok(history)
+    }
+
+    /// Gets the board-wide activity matching a filter, oldest first.
+    pub fn log(
+        &self,
+        base_path: &Path,
+        filter: &ActivityFilter,
+    ) -> Result<Vec<Activity>, CardServiceError> {
+        let board = self.board_service.load(base_path)?;
+
+        Ok(board
+            .activity
+            .iter()
+            .filter(|a| filter.matches(a))
+            .cloned()
+            .collect())
+    }
+
+    /// Records an activity by this service's actor.
+    fn record(&self, board: &mut Board, card_id: &str, kind: ActivityKind) {
+        board.record_activity(Activity::new(card_id.to_string(), self.actor.clone(), kind));
+    }
+
+    /// Records `kind` for a card, followed by the move and field changes
+    /// that came with it since the card was `before`.
+    fn record_changes(board: &mut Board, actor: &str, before: &Card, kind: ActivityKind) {
+        let Some(after) = board.get_card(&before.id).cloned() else {
+            return;
+        };
+        let mut kinds = vec![kind];
+        if before.column_id != after.column_id {
+            kinds.push(ActivityKind::Moved {
+                from: before.column_id.clone(),
+                to: after.column_id.clone(),
+            });
+        }
+        let changes = FieldChange::between(before, &after);
+        if !changes.is_empty() {
+            kinds.push(ActivityKind::Updated { changes });
+        }
+        for kind in kinds {
+            board.record_activity(Activity::new(before.id.clone(), actor.to_string(), kind));
+        }
+    }
+
+    /// Loads the board, releasing the claims that have expired.
+    ///
+    /// Expiry is recorded under [`SYSTEM_ACTOR`] and saved with the next
+    /// change, so it is never mistaken for that change.
+    fn load(&self, base_path: &Path) -> Result<Board, CardServiceError> {
+        let mut board = self.board_service.load_for_change(base_path)?;
+        for card in board.expire_claims(Utc::now()) {
+            let kind = ActivityKind::Released { expired: true };
+            Self::record_changes(&mut board, SYSTEM_ACTOR, &card, kind);
+        }
+        Ok(board)
+    }
+
+    /// Records a change to a card in the journal, so it can be undone.
+    ///
+    /// `board` is the saved board and `before` the card before the change.
+    fn journal(
+        &self,
+        base_path: &Path,
+        board: &Board,
+        card_id: &str,
+        before: Option<CardState>,
+        description: String,
+    ) -> Result<(), CardServiceError> {
+        let mut journal = self.board_service.journal(base_path)?;
+        journal.record(Operation::new(
+            description,
+            self.actor.clone(),
+            board,
+            card_id,
+            before,
+        ));
+        Ok(self.board_service.save_journal(&journal, base_path)?)
+    }
+
+    /// Records a change to several cards in the journal, so it can be
+    /// undone in one step.
+    ///
+    /// `board` is the saved board and `before` holds the cards before the
+    /// change, by ID.
+    fn journal_cards(
+        &self,
+        base_path: &Path,
+        board: &Board,
+        before: Vec<(String, Option<CardState>)>,
+        description: String,
+    ) -> Result<(), CardServiceError> {
+        let mut journal = self.board_service.journal(base_path)?;
+        journal.record(Operation::of_cards(
+            description,
+            self.actor.clone(),
+            board,
+            before,
+        ));
+        Ok(self.board_service.save_journal(&journal, base_path)?)
+    }
+
+    /// Archives or unarchives a card.
+    fn set_archived(
+        &self,
+        base_path: &Path,
+        card_id: &str,
+        archived: bool,
+    ) -> Result<Board, CardServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load(base_path)?;
+        let before = CardState::of(&board, card_id);
+
+        let card = board
+            .get_card_mut(card_id)
+            .ok_or_else(|| CardServiceError::CardNotFound(card_id.to_string()))?;
+        self.enforce_claim(card)?;
+        let (kind, description) = match (archived, card.is_archived()) {
+            (true, true) => return Err(CardServiceError::AlreadyArchived(card_id.to_string())),
+            (false, false) => return Err(CardServiceError::NotArchived(card_id.to_string())),
+            (true, false) => {
+                card.archive();
+                (ActivityKind::Archived, format!("archive {}", card_id))
+            }
+            (false, true) => {
+                card.unarchive();
+                (ActivityKind::Unarchived, format!("unarchive {}", card_id))
+            }
+        };
+        if !archived {
+            let column_id = card.column_id.clone();
+            self.enforce_wip_limit(&board, &column_id)?;
+        }
+        self.record(&mut board, card_id, kind);
+
+        self.save(&mut board, base_path)?;
+        self.journal(base_path, &board, card_id, before, description)?;
+
+        Ok(board)
+    }
+
+    /// Adds or removes the link that makes `blocker` block `blocked`.
+    ///
+    /// Adding a link that already exists changes nothing.
+    fn set_link(
+        &self,
+        base_path: &Path,
+        blocker: &str,
+        blocked: &str,
+        linked: bool,
+    ) -> Result<Board, CardServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load(base_path)?;
+
+        for card_id in [blocker, blocked] {
+            if board.get_card(card_id).is_none() {
+                return Err(CardServiceError::CardNotFound(card_id.to_string()));
+            }
+        }
+        if let Some(card) = board.get_card(blocked) {
+            self.enforce_claim(card)?;
+        }
+
+        let before = CardState::of(&board, blocked);
+        let changed = if linked {
+            board.add_blocker(blocked, blocker)?
+        } else if board.remove_blocker(blocked, blocker) {
+            true
+        } else {
+            return Err(CardServiceError::NotLinked {
+                blocker: blocker.to_string(),
+                blocked: blocked.to_string(),
+            });
+        };
+        if !changed {
+            return Ok(board);
+        }
+
+        if let (Some(before), Some(card)) = (&before, board.get_card(blocked)) {
+            let kind = ActivityKind::Updated {
+                changes: FieldChange::between(&before.card, card),
+            };
+            self.record(&mut board, blocked, kind);
+        }
+
+        self.save(&mut board, base_path)?;
+        let verb = if linked { "link" } else { "unlink" };
+        let description = format!("{} {} blocks {}", verb, blocker, blocked);
+        self.journal(base_path, &board, blocked, before, description)?;
+
+        Ok(board)
+    }
+
+    /// Saves the board, first archiving the cards that sat in the done
+    /// column longer than the board's config allows.
+    ///
+    /// Automatic archiving is recorded under [`SYSTEM_ACTOR`].
+    fn save(&self, board: &mut Board, base_path: &Path) -> Result<(), CardServiceError> {
+        let config = BoardConfig::load(base_path)?;
+        if let Some(days) = config.auto_archive_days {
+            let cutoff = Utc::now() - Duration::days(i64::from(days));
+            for card_id in board.archive_done_cards(cutoff) {
+                board.record_activity(Activity::new(
+                    card_id,
+                    SYSTEM_ACTOR.to_string(),
+                    ActivityKind::Archived,
+                ));
+            }
+        }
+
+        Ok(self.board_service.save(board, base_path)?)
+    }
+
+    /// Undoes or redoes up to `steps` operations and saves the board and
+    /// the journal.
+    fn replay(
+        &self,
+        base_path: &Path,
+        steps: usize,
+        undo: bool,
+    ) -> Result<ReplayedOperations, CardServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load(base_path)?;
+        let mut journal = self.board_service.journal(base_path)?;
+
+        let mut operations = Vec::new();
+        for _ in 0..steps {
+            let step = if undo {
+                journal.undo(&mut board, &self.actor)
+            } else {
+                journal.redo(&mut board, &self.actor)
+            };
+            match step.map_err(CardServiceError::UndoConflict)? {
+                Some(operation) => operations.push(operation),
+                None => break,
+            }
+        }
+        if operations.is_empty() {
+            return Err(if undo {
+                CardServiceError::NothingToUndo
+            } else {
+                CardServiceError::NothingToRedo
+            });
+        }
+
+        for operation in &operations {
+            let applied = if undo {
+                operation.inverse()
+            } else {
+                operation.clone()
+            };
+            self.record_operation(&mut board, &applied);
+        }
+        for column_id in Self::entered_columns(&operations, undo) {
+            self.enforce_wip_limit(&board, &column_id)?;
+        }
+
+        self.save(&mut board, base_path)?;
+        self.board_service.save_journal(&journal, base_path)?;
+
+        Ok(ReplayedOperations { operations, board })
+    }
+
+    /// Gets the columns that replaying `operations` adds shown cards to.
+    fn entered_columns(operations: &[Operation], undo: bool) -> BTreeSet<String> {
+        let shown = |state: &Option<CardState>| {
+            state
+                .as_ref()
+                .filter(|s| !s.card.is_archived())
+                .map(|s| s.card.column_id.clone())
+        };
+        operations
+            .iter()
+            .flat_map(|operation| &operation.changes)
+            .filter_map(|change| {
+                let (from, to) = if undo {
+                    (&change.after, &change.before)
+                } else {
+                    (&change.before, &change.after)
+                };
+                shown(to).filter(|column_id| shown(from).as_ref() != Some(column_id))
+            })
+            .collect()
+    }
+
+    /// Records the activity of applying an operation to the board.
+    fn record_operation(&self, board: &mut Board, operation: &Operation) {
+        for change in &operation.changes {
+            let mut kinds = Vec::new();
+            match (&change.before, &change.after) {
+                (None, Some(after)) => kinds.push(ActivityKind::Created {
+                    title: after.card.title.clone(),
+                    column_id: after.card.column_id.clone(),
+                }),
+                (Some(before), None) => kinds.push(ActivityKind::Deleted {
+                    title: before.card.title.clone(),
+                }),
+                (Some(before), Some(after)) => {
+                    if before.card.column_id != after.card.column_id {
+                        kinds.push(ActivityKind::Moved {
+                            from: before.card.column_id.clone(),
+                            to: after.card.column_id.clone(),
+                        });
+                    }
+                    let changes = FieldChange::between(&before.card, &after.card);
+                    if !changes.is_empty() {
+                        kinds.push(ActivityKind::Updated { changes });
+                    }
+                    match (before.card.is_archived(), after.card.is_archived()) {
+                        (false, true) => kinds.push(ActivityKind::Archived),
+                        (true, false) => kinds.push(ActivityKind::Unarchived),
+                        _ => {}
+                    }
+                }
+                (None, None) => {}
+            }
+            for kind in kinds {
+                self.record(board, &change.card_id, kind);
+            }
+        }
+    }
+
+    /// Rejects a change that took a column over its WIP limit if the board
+    /// blocks such changes and the service is not forced.
+    fn enforce_wip_limit(&self, board: &Board, column_id: &str) -> Result<(), CardServiceError> {
+        if self.force || board.wip_policy != WipPolicy::Block {
+            return Ok(());
+        }
+        Ok(board.check_wip_limit(column_id)?)
+    }
+
+    /// Rejects starting work on a card, by moving it to a column between
+    /// the first and the done column, while one of its blockers is not done,
+    /// unless the service is forced.
+    ///
+    /// Moving a blocked card to the done column is allowed, so cards whose
+    /// blockers were abandoned can still be closed.
+    fn enforce_ready(
+        &self,
+        board: &Board,
+        card_id: &str,
+        column_id: &str,
+    ) -> Result<(), CardServiceError> {
+        if self.force || board.is_backlog_column(column_id) || board.is_done_column(column_id) {
+            return Ok(());
+        }
+        let Some(card) = board.get_card(card_id) else {
+            return Ok(());
+        };
+        let blockers: Vec<String> = board
+            .unfinished_blockers(card)
+            .iter()
+            .map(|b| b.id.clone())
+            .collect();
+        if blockers.is_empty() {
+            return Ok(());
+        }
+        Err(CardServiceError::Blocked {
+            card_id: card_id.to_string(),
+            blockers,
+        })
+    }
+
+    /// Rejects moving a card with open checklist items to the done column
+    /// if the board's config requires them to be done, unless the service
+    /// is forced.
+    fn enforce_checklist(
+        &self,
+        board: &Board,
+        base_path: &Path,
+        card_id: &str,
+        column_id: &str,
+    ) -> Result<(), CardServiceError> {
+        if self.force || !board.is_done_column(column_id) {
+            return Ok(());
+        }
+        let open = board
+            .get_card(card_id)
+            .map_or(0, |card| card.open_checklist_items());
+        if open == 0 || !BoardConfig::load(base_path)?.require_checklist_done {
+            return Ok(());
+        }
+        Err(CardServiceError::ChecklistIncomplete {
+            card_id: card_id.to_string(),
+            open,
+        })
+    }
+
+    /// Rejects changing a card someone else holds a claim on, unless the
+    /// service is forced.
+    fn enforce_claim(&self, card: &Card) -> Result<(), CardServiceError> {
+        match card.active_claim(Utc::now()) {
+            Some(claim) if !self.force && claim.claimant != self.actor => {
+                Err(CardServiceError::ClaimedByOther {
+                    card_id: card.id.clone(),
+                    claimant: claim.claimant.clone(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the card to place a card before or after exists in the
+    /// target column and is not the card itself.
+    fn check_position(
+        board: &Board,
+        card_id: &str,
+        column_id: &str,
+        position: &CardPosition,
+    ) -> Result<(), CardServiceError> {
+        let (CardPosition::Before(other) | CardPosition::After(other)) = position else {
+            return Ok(());
+        };
+        if other == card_id {
+            return Err(CardServiceError::InvalidPosition(format!(
+                "cannot place {} relative to itself",
+                card_id
+            )));
+        }
+        let other_column = board
+            .get_card(other)
+            .map(|c| c.column_id.as_str())
+            .ok_or_else(|| CardServiceError::CardNotFound(other.to_string()))?;
+        if other_column != column_id {
+            return Err(CardServiceError::InvalidPosition(format!(
+                "{} is not in column {}",
+                other, column_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Gets the column and position of a card state, for telling whether
+    /// an operation reordered the card.
+    fn position_of(state: &Option<CardState>) -> Option<(&str, usize)> {
+        state
+            .as_ref()
+            .map(|s| (s.card.column_id.as_str(), s.position))
+    }
+
+    /// Validates label names and collects them into a sorted set.
+    fn normalize_labels(labels: &[String]) -> Result<BTreeSet<String>, CardServiceError> {
+        labels
+            .iter()
+            .map(|l| Label::normalize_name(l).map_err(CardServiceError::InvalidLabel))
+            .collect()
+    }
 }
 
 impl Default for CardService {
@@ -1,4 +1,7 @@
 use super::*;
+use crate::infrastructure::memory::InMemoryBoardRepository;
+use crate::infrastructure::storage::JsonBoardRepository;
+use crate::infrastructure::BoardRepository;
 use tempfile::TempDir;
 
 fn setup_test_board() -> (TempDir, CardService) {
@@ -20,10 +23,11 @@ fn test_create_card() {
     let result = service
         .create(
             temp_dir.path(),
-            "Test Task".to_string(),
-            Some("Description".to_string()),
-            Some("Alice".to_string()),
-            None,
+            NewCard {
+                description: Some("Description".to_string()),
+                assignee: Some("Alice".to_string()),
+                ..NewCard::new("Test Task")
+            },
         )
         .unwrap();
 
@@ -40,10 +44,10 @@ fn test_create_card_invalid_column() {
     // Act
     let result = service.create(
         temp_dir.path(),
-        "Test".to_string(),
-        None,
-        None,
-        Some("invalid".to_string()),
+        NewCard {
+            column_id: Some("invalid".to_string()),
+            ..NewCard::new("Test")
+        },
     );
 
     // Assert
@@ -55,7 +59,7 @@ fn test_move_card() {
     // Arrange
     let (temp_dir, service) = setup_test_board();
     let created = service
-        .create(temp_dir.path(), "Task".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Task"))
         .unwrap();
 
     // Act
@@ -85,7 +89,7 @@ fn test_update_card() {
     // Arrange
     let (temp_dir, service) = setup_test_board();
     let created = service
-        .create(temp_dir.path(), "Old Title".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Old Title"))
         .unwrap();
 
     // Act
@@ -93,9 +97,13 @@ fn test_update_card() {
         .update(
             temp_dir.path(),
             &created.card_id,
-            Some("New Title".to_string()),
-            Some(Some("New Desc".to_string())),
-            Some(Some("Bob".to_string())),
+            CardUpdate {
+                title: Some("New Title".to_string()),
+                description: Some(Some("New Desc".to_string())),
+                assignee: Some(Some("Bob".to_string())),
+                priority: Some(Some(Priority::High)),
+                ..Default::default()
+            },
         )
         .unwrap();
 
@@ -104,6 +112,7 @@ fn test_update_card() {
     assert_eq!(card.title, "New Title");
     assert_eq!(card.description, Some("New Desc".to_string()));
     assert_eq!(card.assignee, Some("Bob".to_string()));
+    assert_eq!(card.priority, Some(Priority::High));
 }
 
 #[test]
@@ -111,7 +120,7 @@ fn test_delete_card() {
     // Arrange
     let (temp_dir, service) = setup_test_board();
     let created = service
-        .create(temp_dir.path(), "Task".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Task"))
         .unwrap();
 
     // Act
@@ -121,3 +130,1851 @@ fn test_delete_card() {
     assert!(board.get_card(&created.card_id).is_none());
     assert_eq!(board.cards.len(), 0);
 }
+
+#[test]
+fn test_delete_card_takes_snapshot() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+
+    // Act
+    service.delete(temp_dir.path(), &created.card_id).unwrap();
+
+    // Assert
+    let snapshots = BoardService::new().snapshots(temp_dir.path()).unwrap();
+    let before_delete = snapshots[0].load().unwrap();
+    assert_eq!(snapshots[0].revision, 1);
+    assert!(before_delete.get_card(&created.card_id).is_some());
+}
+
+#[test]
+fn test_create_card_with_priority() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let result = service
+        .create(
+            temp_dir.path(),
+            NewCard {
+                priority: Some(Priority::Critical),
+                ..NewCard::new("Urgent")
+            },
+        )
+        .unwrap();
+
+    // Assert
+    let card = result.board.get_card(&result.card_id).unwrap();
+    assert_eq!(card.priority, Some(Priority::Critical));
+}
+
+#[test]
+fn test_create_and_move_order_cards_by_priority() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let create = |title: &str, priority: Option<Priority>| {
+        service
+            .create(
+                temp_dir.path(),
+                NewCard {
+                    priority,
+                    ..NewCard::new(title)
+                },
+            )
+            .unwrap()
+            .card_id
+    };
+    let plain = create("Plain", None);
+    let low = create("Low", Some(Priority::Low));
+    let critical = create("Critical", Some(Priority::Critical));
+    service.move_to(temp_dir.path(), &critical, "done").unwrap();
+    let high = create("High", Some(Priority::High));
+
+    // Act
+    let board = service.move_to(temp_dir.path(), &critical, "todo").unwrap();
+
+    // Assert
+    assert_eq!(board.columns[0].cards, vec![critical, high, low, plain]);
+}
+
+#[test]
+fn test_update_clears_priority() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(
+            temp_dir.path(),
+            NewCard {
+                priority: Some(Priority::Low),
+                ..NewCard::new("Task")
+            },
+        )
+        .unwrap();
+
+    // Act
+    let board = service
+        .update(
+            temp_dir.path(),
+            &created.card_id,
+            CardUpdate {
+                priority: Some(None),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    // Assert
+    let card = board.get_card(&created.card_id).unwrap();
+    assert!(card.priority.is_none());
+    assert_eq!(card.title, "Task");
+}
+
+#[test]
+fn test_create_card_with_labels_registers_them() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let result = service
+        .create(
+            temp_dir.path(),
+            NewCard {
+                labels: vec!["bug".to_string(), " frontend ".to_string()],
+                ..NewCard::new("Broken button")
+            },
+        )
+        .unwrap();
+
+    // Assert
+    let card = result.board.get_card(&result.card_id).unwrap();
+    assert!(card.has_label("bug"));
+    assert!(card.has_label("frontend"));
+    assert!(result.board.get_label("frontend").is_some());
+}
+
+#[test]
+fn test_create_card_rejects_invalid_label() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let result = service.create(
+        temp_dir.path(),
+        NewCard {
+            labels: vec!["two words".to_string()],
+            ..NewCard::new("Task")
+        },
+    );
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::InvalidLabel(_))));
+}
+
+#[test]
+fn test_update_adds_and_removes_labels() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(
+            temp_dir.path(),
+            NewCard {
+                labels: vec!["bug".to_string(), "backend".to_string()],
+                ..NewCard::new("Task")
+            },
+        )
+        .unwrap();
+
+    // Act
+    let board = service
+        .update(
+            temp_dir.path(),
+            &created.card_id,
+            CardUpdate {
+                add_labels: vec!["agent-ok".to_string()],
+                remove_labels: vec!["backend".to_string()],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    // Assert
+    let card = board.get_card(&created.card_id).unwrap();
+    let labels: Vec<&str> = card.labels.iter().map(String::as_str).collect();
+    assert_eq!(labels, vec!["agent-ok", "bug"]);
+}
+
+#[test]
+fn test_update_replaces_labels() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(
+            temp_dir.path(),
+            NewCard {
+                labels: vec!["bug".to_string()],
+                ..NewCard::new("Task")
+            },
+        )
+        .unwrap();
+
+    // Act
+    let board = service
+        .update(
+            temp_dir.path(),
+            &created.card_id,
+            CardUpdate {
+                labels: Some(vec!["docs".to_string()]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    // Assert
+    let card = board.get_card(&created.card_id).unwrap();
+    assert!(card.has_label("docs"));
+    assert!(!card.has_label("bug"));
+}
+
+#[test]
+fn test_create_and_clear_due_date() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let due = NaiveDate::from_ymd_opt(2024, 6, 30);
+    let created = service
+        .create(
+            temp_dir.path(),
+            NewCard {
+                due,
+                ..NewCard::new("Release")
+            },
+        )
+        .unwrap();
+    assert_eq!(created.board.get_card(&created.card_id).unwrap().due, due);
+
+    // Act
+    let board = service
+        .update(
+            temp_dir.path(),
+            &created.card_id,
+            CardUpdate {
+                due: Some(None),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    // Assert
+    assert!(board.get_card(&created.card_id).unwrap().due.is_none());
+}
+
+#[test]
+fn test_add_comment() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+
+    // Act
+    let board = service
+        .add_comment(
+            temp_dir.path(),
+            &created.card_id,
+            "agent-7",
+            "  Started work ",
+        )
+        .unwrap();
+
+    // Assert
+    let card = board.get_card(&created.card_id).unwrap();
+    assert_eq!(card.comments.len(), 1);
+    assert_eq!(card.comments[0].author, "agent-7");
+    assert_eq!(card.comments[0].body, "Started work");
+}
+
+#[test]
+fn test_add_empty_comment() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+
+    // Act
+    let result = service.add_comment(temp_dir.path(), &created.card_id, "alice", "   ");
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::InvalidData(_))));
+}
+
+#[test]
+fn test_history_records_mutations() {
+    // Arrange
+    let (temp_dir, _) = setup_test_board();
+    let service = CardService::with_actor("agent-7");
+    let created = service
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+    let card_id = created.card_id;
+
+    // Act
+    service
+        .move_to(temp_dir.path(), &card_id, "in_progress")
+        .unwrap();
+    service
+        .update(
+            temp_dir.path(),
+            &card_id,
+            CardUpdate {
+                title: Some("Renamed".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    service.delete(temp_dir.path(), &card_id).unwrap();
+    let history = service.history(temp_dir.path(), &card_id).unwrap();
+
+    // Assert
+    assert_eq!(history.len(), 4);
+    assert!(history.iter().all(|a| a.actor == "agent-7"));
+    assert!(matches!(history[0].kind, ActivityKind::Created { .. }));
+    assert_eq!(
+        history[1].kind,
+        ActivityKind::Moved {
+            from: "todo".to_string(),
+            to: "in_progress".to_string(),
+        }
+    );
+    match &history[2].kind {
+        ActivityKind::Updated { changes } => {
+            assert_eq!(changes.len(), 1);
+            assert_eq!(changes[0].field, "title");
+            assert_eq!(changes[0].before.as_deref(), Some("Task"));
+            assert_eq!(changes[0].after.as_deref(), Some("Renamed"));
+        }
+        other => panic!("Expected an update, got {:?}", other),
+    }
+    assert!(matches!(history[3].kind, ActivityKind::Deleted { .. }));
+}
+
+#[test]
+fn test_history_skips_noop_changes() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+
+    // Act
+    service
+        .move_to(temp_dir.path(), &created.card_id, "todo")
+        .unwrap();
+    service
+        .update(
+            temp_dir.path(),
+            &created.card_id,
+            CardUpdate {
+                title: Some("Task".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    let history = service.history(temp_dir.path(), &created.card_id).unwrap();
+
+    // Assert
+    assert_eq!(history.len(), 1);
+}
+
+#[test]
+fn test_history_card_not_found() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let result = service.history(temp_dir.path(), "TES-999");
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::CardNotFound(_))));
+}
+
+#[test]
+fn test_log_filters_by_actor() {
+    // Arrange
+    let (temp_dir, _) = setup_test_board();
+    let alice = CardService::with_actor("alice");
+    let bob = CardService::with_actor("bob");
+    let created = alice.create(temp_dir.path(), NewCard::new("Task")).unwrap();
+    bob.move_to(temp_dir.path(), &created.card_id, "done")
+        .unwrap();
+    bob.add_comment(temp_dir.path(), &created.card_id, "carol", "Shipped")
+        .unwrap();
+
+    let filter = ActivityFilter {
+        actor: Some("bob".to_string()),
+        ..Default::default()
+    };
+
+    // Act
+    let all = alice
+        .log(temp_dir.path(), &ActivityFilter::default())
+        .unwrap();
+    let by_bob = alice.log(temp_dir.path(), &filter).unwrap();
+
+    // Assert
+    assert_eq!(all.len(), 3);
+    assert_eq!(all[2].actor, "carol");
+    assert_eq!(by_bob.len(), 1);
+    assert!(matches!(by_bob[0].kind, ActivityKind::Moved { .. }));
+}
+
+#[test]
+fn test_concurrent_creates_keep_every_card() {
+    // Arrange
+    let (temp_dir, _) = setup_test_board();
+    let base_path = temp_dir.path().to_path_buf();
+
+    // Act
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let base_path = base_path.clone();
+            std::thread::spawn(move || {
+                CardService::new()
+                    .create(&base_path, NewCard::new(format!("Task {}", i)))
+                    .unwrap()
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    // Assert
+    let board = BoardService::new().load(&base_path).unwrap();
+    assert_eq!(board.cards.len(), 8);
+    assert_eq!(board.next_card_number, 9);
+}
+
+#[test]
+fn test_move_card_if_revision() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+    let revision = created.board.revision;
+
+    // Act
+    let current = CardService::new().if_revision(Some(revision)).move_to(
+        temp_dir.path(),
+        &created.card_id,
+        "in_progress",
+    );
+    let stale = CardService::new().if_revision(Some(revision)).move_to(
+        temp_dir.path(),
+        &created.card_id,
+        "done",
+    );
+
+    // Assert
+    assert_eq!(current.unwrap().revision, revision + 1);
+    assert!(matches!(
+        stale,
+        Err(CardServiceError::BoardService(
+            BoardServiceError::Conflict { .. }
+        ))
+    ));
+    let board = BoardService::new().load(temp_dir.path()).unwrap();
+    assert_eq!(
+        board.get_card(&created.card_id).unwrap().column_id,
+        "in_progress"
+    );
+}
+
+#[test]
+fn test_services_share_in_memory_repository() {
+    // Arrange
+    let repository = InMemoryBoardRepository::new();
+    let board_service = || BoardService::with_repository(Box::new(repository.clone()));
+    let base_path = Path::new("/embedded/board");
+    board_service()
+        .initialize(base_path, Some("Embedded".to_string()))
+        .unwrap();
+    let service = CardService::with_board_service(board_service(), "embedder");
+
+    // Act
+    let created = service.create(base_path, NewCard::new("Task")).unwrap();
+    service
+        .move_to(base_path, &created.card_id, "done")
+        .unwrap();
+
+    // Assert
+    let board = board_service().load(base_path).unwrap();
+    assert_eq!(board.get_card(&created.card_id).unwrap().column_id, "done");
+    assert_eq!(board.revision, 2);
+    let undone = service.undo(base_path, 1).unwrap();
+    assert_eq!(
+        undone.board.get_card(&created.card_id).unwrap().column_id,
+        "todo"
+    );
+    assert!(!base_path.exists());
+}
+
+#[test]
+fn test_undo_delete_restores_card() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+    service
+        .add_comment(temp_dir.path(), &created.card_id, "alice", "Keep me")
+        .unwrap();
+    service.delete(temp_dir.path(), &created.card_id).unwrap();
+
+    // Act
+    let undone = service.undo(temp_dir.path(), 1).unwrap();
+
+    // Assert
+    assert_eq!(undone.operations.len(), 1);
+    assert_eq!(undone.operations[0].description, "delete TES-001");
+    let card = undone.board.get_card(&created.card_id).unwrap();
+    assert_eq!(card.comments[0].body, "Keep me");
+    assert_eq!(undone.board.columns[0].cards, vec!["TES-001"]);
+    let history = service.history(temp_dir.path(), &created.card_id).unwrap();
+    assert!(matches!(
+        history.last().unwrap().kind,
+        ActivityKind::Created { .. }
+    ));
+}
+
+#[test]
+fn test_undo_and_redo_several_steps() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+    service
+        .move_to(temp_dir.path(), &created.card_id, "in_progress")
+        .unwrap();
+    service
+        .move_to(temp_dir.path(), &created.card_id, "done")
+        .unwrap();
+
+    // Act
+    let undone = service.undo(temp_dir.path(), 5).unwrap();
+
+    // Assert
+    let descriptions: Vec<&str> = undone
+        .operations
+        .iter()
+        .map(|o| o.description.as_str())
+        .collect();
+    assert_eq!(
+        descriptions,
+        vec![
+            "move TES-001 to done",
+            "move TES-001 to in_progress",
+            "create TES-001"
+        ]
+    );
+    assert!(undone.board.cards.is_empty());
+
+    // Act
+    let redone = service.redo(temp_dir.path(), 2).unwrap();
+
+    // Assert
+    assert_eq!(redone.operations.len(), 2);
+    assert_eq!(
+        redone.board.get_card(&created.card_id).unwrap().column_id,
+        "in_progress"
+    );
+}
+
+#[test]
+fn test_undo_keeps_concurrent_changes_to_other_cards() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let first = service
+        .create(temp_dir.path(), NewCard::new("First"))
+        .unwrap();
+    let second = service
+        .create(temp_dir.path(), NewCard::new("Second"))
+        .unwrap();
+    service
+        .move_to(temp_dir.path(), &first.card_id, "done")
+        .unwrap();
+    let other = CardService::with_actor("agent-7");
+    other
+        .move_to(temp_dir.path(), &second.card_id, "in_progress")
+        .unwrap();
+
+    // Act
+    service.undo(temp_dir.path(), 1).unwrap();
+
+    // Assert
+    let board = service.list(temp_dir.path()).unwrap();
+    assert_eq!(board.get_card(&first.card_id).unwrap().column_id, "todo");
+    assert_eq!(
+        board.get_card(&second.card_id).unwrap().column_id,
+        "in_progress"
+    );
+}
+
+#[test]
+fn test_undo_rejects_card_changed_elsewhere() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+    service
+        .move_to(temp_dir.path(), &created.card_id, "done")
+        .unwrap();
+    let board = service.list(temp_dir.path()).unwrap();
+    let mut changed = board.clone();
+    changed
+        .get_card_mut(&created.card_id)
+        .unwrap()
+        .set_title("Renamed".to_string());
+    BoardService::new()
+        .save(&mut changed, temp_dir.path())
+        .unwrap();
+
+    // Act
+    let result = service.undo(temp_dir.path(), 1);
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::UndoConflict(_))));
+    let board = service.list(temp_dir.path()).unwrap();
+    assert_eq!(board.get_card(&created.card_id).unwrap().column_id, "done");
+}
+
+#[test]
+fn test_undo_create_of_card_referenced_by_others_conflicts() {
+    // Arrange
+    let (temp_dir, _) = setup_test_board();
+    let x = CardService::with_actor("x");
+    let y = CardService::with_actor("y");
+    let epic = x.create(temp_dir.path(), NewCard::new("Epic")).unwrap();
+    let child = y
+        .create(
+            temp_dir.path(),
+            NewCard {
+                parent: Some(epic.card_id.clone()),
+                ..NewCard::new("Child")
+            },
+        )
+        .unwrap();
+    y.link(temp_dir.path(), &epic.card_id, &child.card_id)
+        .unwrap();
+
+    // Act
+    let result = x.undo(temp_dir.path(), 1);
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::UndoConflict(_))));
+    let board = x.list(temp_dir.path()).unwrap();
+    assert!(board.get_card(&epic.card_id).is_some());
+    assert!(board.check().is_empty());
+}
+
+#[test]
+fn test_new_change_discards_redo() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+    service
+        .move_to(temp_dir.path(), &created.card_id, "done")
+        .unwrap();
+    service.undo(temp_dir.path(), 1).unwrap();
+
+    // Act
+    service
+        .add_comment(temp_dir.path(), &created.card_id, "alice", "Later")
+        .unwrap();
+    let result = service.redo(temp_dir.path(), 1);
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::NothingToRedo)));
+}
+
+#[test]
+fn test_undo_with_empty_journal() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let result = service.undo(temp_dir.path(), 1);
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::NothingToUndo)));
+}
+
+#[test]
+fn test_archive_and_unarchive_card() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+
+    // Act
+    let board = service.archive(temp_dir.path(), &created.card_id).unwrap();
+
+    // Assert
+    assert!(board.get_card(&created.card_id).unwrap().is_archived());
+    assert!(board.get_cards_in_column("todo").is_empty());
+    let history = service.history(temp_dir.path(), &created.card_id).unwrap();
+    assert_eq!(history.last().unwrap().kind, ActivityKind::Archived);
+
+    // Act
+    let board = service
+        .unarchive(temp_dir.path(), &created.card_id)
+        .unwrap();
+
+    // Assert
+    assert!(!board.get_card(&created.card_id).unwrap().is_archived());
+    assert_eq!(board.get_cards_in_column("todo").len(), 1);
+}
+
+#[test]
+fn test_archive_twice_fails() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = service
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+    service.archive(temp_dir.path(), &created.card_id).unwrap();
+
+    // Act
+    let archived = service.archive(temp_dir.path(), &created.card_id);
+    service
+        .unarchive(temp_dir.path(), &created.card_id)
+        .unwrap();
+    let unarchived = service.unarchive(temp_dir.path(), &created.card_id);
+
+    // Assert
+    assert!(matches!(
+        archived,
+        Err(CardServiceError::AlreadyArchived(_))
+    ));
+    assert!(matches!(unarchived, Err(CardServiceError::NotArchived(_))));
+}
+
+#[test]
+fn test_auto_archive_done_cards() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let config = BoardConfig {
+        auto_archive_days: Some(0),
+        ..Default::default()
+    };
+    config.save(temp_dir.path()).unwrap();
+    let done = service
+        .create(temp_dir.path(), NewCard::new("Done"))
+        .unwrap();
+    service
+        .move_to(temp_dir.path(), &done.card_id, "done")
+        .unwrap();
+
+    // Act
+    let open = service
+        .create(temp_dir.path(), NewCard::new("Open"))
+        .unwrap();
+
+    // Assert
+    assert!(open.board.get_card(&done.card_id).unwrap().is_archived());
+    assert!(!open.board.get_card(&open.card_id).unwrap().is_archived());
+    let history = service.history(temp_dir.path(), &done.card_id).unwrap();
+    let archived = history.last().unwrap();
+    assert_eq!(archived.kind, ActivityKind::Archived);
+    assert_eq!(archived.actor, SYSTEM_ACTOR);
+}
+
+#[test]
+fn test_rank_card_and_undo() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    for title in ["A", "B", "C"] {
+        service
+            .create(temp_dir.path(), NewCard::new(title))
+            .unwrap();
+    }
+
+    // Act
+    let board = service
+        .rank(
+            temp_dir.path(),
+            "TES-003",
+            &CardPosition::Before("TES-001".to_string()),
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        board.columns[0].cards,
+        vec!["TES-003", "TES-001", "TES-002"]
+    );
+    let undone = service.undo(temp_dir.path(), 1).unwrap();
+    assert_eq!(undone.operations[0].description, "rank TES-003");
+    assert_eq!(
+        undone.board.columns[0].cards,
+        vec!["TES-001", "TES-002", "TES-003"]
+    );
+}
+
+#[test]
+fn test_rank_relative_to_card_in_other_column_fails() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+    service.move_to(temp_dir.path(), "TES-002", "done").unwrap();
+
+    // Act
+    let other_column = service.rank(
+        temp_dir.path(),
+        "TES-001",
+        &CardPosition::After("TES-002".to_string()),
+    );
+    let itself = service.rank(
+        temp_dir.path(),
+        "TES-001",
+        &CardPosition::Before("TES-001".to_string()),
+    );
+
+    // Assert
+    assert!(matches!(
+        other_column,
+        Err(CardServiceError::InvalidPosition(_))
+    ));
+    assert!(matches!(itself, Err(CardServiceError::InvalidPosition(_))));
+}
+
+#[test]
+fn test_move_card_to_position() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    for title in ["A", "B", "C"] {
+        service
+            .create(temp_dir.path(), NewCard::new(title))
+            .unwrap();
+    }
+    service.move_to(temp_dir.path(), "TES-001", "done").unwrap();
+    service.move_to(temp_dir.path(), "TES-002", "done").unwrap();
+
+    // Act
+    let board = service
+        .move_to_position(
+            temp_dir.path(),
+            "TES-003",
+            "done",
+            Some(&CardPosition::Index(1)),
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        board.columns[2].cards,
+        vec!["TES-001", "TES-003", "TES-002"]
+    );
+    let history = service.history(temp_dir.path(), "TES-003").unwrap();
+    assert!(matches!(
+        history.last().unwrap().kind,
+        ActivityKind::Moved { .. }
+    ));
+}
+
+/// Limits the in-progress column to one card, with the given policy.
+fn limit_in_progress(temp_dir: &TempDir, policy: WipPolicy) {
+    let board_service = BoardService::new();
+    let mut board = board_service.load(temp_dir.path()).unwrap();
+    board.set_wip_limit("in_progress", Some(1));
+    board.wip_policy = policy;
+    board_service.save(&mut board, temp_dir.path()).unwrap();
+}
+
+#[test]
+fn test_move_over_blocking_wip_limit_fails() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+    limit_in_progress(&temp_dir, WipPolicy::Block);
+    service
+        .move_to(temp_dir.path(), "TES-001", "in_progress")
+        .unwrap();
+
+    // Act
+    let result = service.move_to(temp_dir.path(), "TES-002", "in_progress");
+
+    // Assert
+    match result {
+        Err(CardServiceError::WipLimitExceeded(exceeded)) => {
+            assert_eq!(exceeded.column_id, "in_progress");
+            assert_eq!(exceeded.limit, 1);
+        }
+        other => panic!("expected WipLimitExceeded, got {:?}", other.map(|_| ())),
+    }
+    let board = BoardService::new().load(temp_dir.path()).unwrap();
+    assert_eq!(board.get_card("TES-002").unwrap().column_id, "todo");
+}
+
+#[test]
+fn test_forced_move_exceeds_blocking_wip_limit() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+    limit_in_progress(&temp_dir, WipPolicy::Block);
+    service
+        .move_to(temp_dir.path(), "TES-001", "in_progress")
+        .unwrap();
+
+    // Act
+    let board = service
+        .force(true)
+        .move_to(temp_dir.path(), "TES-002", "in_progress")
+        .unwrap();
+
+    // Assert
+    assert_eq!(board.get_cards_in_column("in_progress").len(), 2);
+    assert!(board.check_wip_limit("in_progress").is_err());
+}
+
+#[test]
+fn test_warning_wip_limit_allows_moves_and_creation() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    limit_in_progress(&temp_dir, WipPolicy::Warn);
+    service
+        .move_to(temp_dir.path(), "TES-001", "in_progress")
+        .unwrap();
+
+    // Act
+    let created = service
+        .create(
+            temp_dir.path(),
+            NewCard {
+                column_id: Some("in_progress".to_string()),
+                ..NewCard::new("B")
+            },
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(created.board.get_cards_in_column("in_progress").len(), 2);
+}
+
+#[test]
+fn test_create_in_column_at_blocking_wip_limit_fails() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service
+        .create(
+            temp_dir.path(),
+            NewCard {
+                column_id: Some("in_progress".to_string()),
+                ..NewCard::new("A")
+            },
+        )
+        .unwrap();
+    limit_in_progress(&temp_dir, WipPolicy::Block);
+
+    // Act
+    let result = service.create(
+        temp_dir.path(),
+        NewCard {
+            column_id: Some("in_progress".to_string()),
+            ..NewCard::new("B")
+        },
+    );
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::WipLimitExceeded(_))));
+}
+
+#[test]
+fn test_unarchive_into_column_at_blocking_wip_limit_fails() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let in_progress = || NewCard {
+        column_id: Some("in_progress".to_string()),
+        ..NewCard::new("Task")
+    };
+    let archived = service.create(temp_dir.path(), in_progress()).unwrap();
+    service.archive(temp_dir.path(), &archived.card_id).unwrap();
+    service.create(temp_dir.path(), in_progress()).unwrap();
+    limit_in_progress(&temp_dir, WipPolicy::Block);
+
+    // Act
+    let result = service.unarchive(temp_dir.path(), &archived.card_id);
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::WipLimitExceeded(_))));
+    let board = service.list(temp_dir.path()).unwrap();
+    assert!(board.get_card(&archived.card_id).unwrap().is_archived());
+    let forced = service
+        .force(true)
+        .unarchive(temp_dir.path(), &archived.card_id)
+        .unwrap();
+    assert_eq!(forced.get_cards_in_column("in_progress").len(), 2);
+}
+
+#[test]
+fn test_undo_over_blocking_wip_limit_fails() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+    limit_in_progress(&temp_dir, WipPolicy::Block);
+    let x = CardService::with_actor("x");
+    let y = CardService::with_actor("y");
+    x.move_to(temp_dir.path(), "TES-001", "in_progress")
+        .unwrap();
+    x.move_to(temp_dir.path(), "TES-001", "done").unwrap();
+    y.move_to(temp_dir.path(), "TES-002", "in_progress")
+        .unwrap();
+
+    // Act
+    let result = x.undo(temp_dir.path(), 1);
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::WipLimitExceeded(_))));
+    let board = x.list(temp_dir.path()).unwrap();
+    assert_eq!(board.get_card("TES-001").unwrap().column_id, "done");
+    x.force(true).undo(temp_dir.path(), 1).unwrap();
+}
+
+#[test]
+fn test_release_to_column_at_blocking_wip_limit_fails() {
+    // Arrange
+    let (temp_dir, _) = setup_test_board();
+    let service = CardService::with_actor("agent-7");
+    let in_progress = || NewCard {
+        column_id: Some("in_progress".to_string()),
+        ..NewCard::new("Task")
+    };
+    let claimed = service.create(temp_dir.path(), in_progress()).unwrap();
+    limit_in_progress(&temp_dir, WipPolicy::Block);
+    service
+        .claim(temp_dir.path(), &claimed.card_id, Duration::minutes(30))
+        .unwrap();
+    service
+        .move_to(temp_dir.path(), &claimed.card_id, "todo")
+        .unwrap();
+    service.create(temp_dir.path(), in_progress()).unwrap();
+
+    // Act
+    let result = service.release(temp_dir.path(), &claimed.card_id);
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::WipLimitExceeded(_))));
+    let board = service.list(temp_dir.path()).unwrap();
+    assert!(board.get_card(&claimed.card_id).unwrap().claim.is_some());
+}
+
+#[test]
+fn test_link_records_blocker_and_can_be_undone() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+
+    // Act
+    let board = service.link(temp_dir.path(), "TES-001", "TES-002").unwrap();
+
+    // Assert
+    let card = board.get_card("TES-002").unwrap();
+    assert!(card.blocked_by.contains("TES-001"));
+    assert!(!board.is_ready(card));
+    let history = board.card_history("TES-002");
+    assert!(matches!(
+        &history.last().unwrap().kind,
+        ActivityKind::Updated { changes } if changes[0].field == "blocked_by"
+    ));
+
+    // Act
+    let replayed = service.undo(temp_dir.path(), 1).unwrap();
+
+    // Assert
+    assert_eq!(
+        replayed.operations[0].description,
+        "link TES-001 blocks TES-002"
+    );
+    assert!(replayed
+        .board
+        .get_card("TES-002")
+        .unwrap()
+        .blocked_by
+        .is_empty());
+}
+
+#[test]
+fn test_link_rejects_cycle() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+    service.link(temp_dir.path(), "TES-001", "TES-002").unwrap();
+
+    // Act
+    let result = service.link(temp_dir.path(), "TES-002", "TES-001");
+
+    // Assert
+    let err = result.unwrap_err();
+    assert_eq!(err.code(), "dependency_cycle");
+    assert!(matches!(
+        err,
+        CardServiceError::Link(LinkError::Cycle { .. })
+    ));
+}
+
+#[test]
+fn test_link_unknown_card_fails() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+
+    // Act
+    let result = service.link(temp_dir.path(), "TES-999", "TES-001");
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::CardNotFound(id)) if id == "TES-999"));
+}
+
+#[test]
+fn test_unlink_missing_link_fails() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+    service.link(temp_dir.path(), "TES-001", "TES-002").unwrap();
+    service
+        .unlink(temp_dir.path(), "TES-001", "TES-002")
+        .unwrap();
+
+    // Act
+    let result = service.unlink(temp_dir.path(), "TES-001", "TES-002");
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::NotLinked { .. })));
+}
+
+#[test]
+fn test_move_blocked_card_into_progress_fails() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+    service.link(temp_dir.path(), "TES-001", "TES-002").unwrap();
+
+    // Act
+    let result = service.move_to(temp_dir.path(), "TES-002", "in_progress");
+
+    // Assert
+    match result {
+        Err(CardServiceError::Blocked { card_id, blockers }) => {
+            assert_eq!(card_id, "TES-002");
+            assert_eq!(blockers, vec!["TES-001"]);
+        }
+        other => panic!("expected Blocked, got {:?}", other.map(|_| ())),
+    }
+    let board = BoardService::new().load(temp_dir.path()).unwrap();
+    assert_eq!(board.get_card("TES-002").unwrap().column_id, "todo");
+}
+
+#[test]
+fn test_move_blocked_card_succeeds_when_forced_or_blockers_done() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("C")).unwrap();
+    service.link(temp_dir.path(), "TES-001", "TES-002").unwrap();
+    service.link(temp_dir.path(), "TES-001", "TES-003").unwrap();
+
+    // Act
+    let forced = CardService::new()
+        .force(true)
+        .move_to(temp_dir.path(), "TES-002", "in_progress")
+        .unwrap();
+    service.move_to(temp_dir.path(), "TES-001", "done").unwrap();
+    let ready = service
+        .move_to(temp_dir.path(), "TES-003", "in_progress")
+        .unwrap();
+
+    // Assert
+    assert_eq!(forced.get_card("TES-002").unwrap().column_id, "in_progress");
+    assert_eq!(ready.get_card("TES-003").unwrap().column_id, "in_progress");
+}
+
+#[test]
+fn test_move_blocked_card_to_done_succeeds() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+    service.link(temp_dir.path(), "TES-001", "TES-002").unwrap();
+
+    // Act
+    let board = service.move_to(temp_dir.path(), "TES-002", "done").unwrap();
+
+    // Assert
+    assert_eq!(board.get_card("TES-002").unwrap().column_id, "done");
+}
+
+#[test]
+fn test_delete_blocker_removes_link_until_undone() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+    service.link(temp_dir.path(), "TES-001", "TES-002").unwrap();
+
+    // Act
+    let board = service.delete(temp_dir.path(), "TES-001").unwrap();
+
+    // Assert
+    assert!(board.get_card("TES-002").unwrap().blocked_by.is_empty());
+
+    // Act
+    let replayed = service.undo(temp_dir.path(), 1).unwrap();
+
+    // Assert
+    let card = replayed.board.get_card("TES-002").unwrap();
+    assert!(card.blocked_by.contains("TES-001"));
+}
+
+#[test]
+fn test_next_picks_without_changing_the_board() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service
+        .create(
+            temp_dir.path(),
+            NewCard {
+                priority: Some(Priority::High),
+                ..NewCard::new("B")
+            },
+        )
+        .unwrap();
+    let revision = BoardService::new().load(temp_dir.path()).unwrap().revision;
+
+    // Act
+    let picked = service
+        .next(temp_dir.path(), &CardFilter::default(), None)
+        .unwrap();
+
+    // Assert
+    assert_eq!(picked.card_id, "TES-002");
+    assert!(!picked.claimed);
+    let board = BoardService::new().load(temp_dir.path()).unwrap();
+    assert_eq!(board.revision, revision);
+}
+
+#[test]
+fn test_next_claim_assigns_and_moves_card() {
+    // Arrange
+    let (temp_dir, _) = setup_test_board();
+    let service = CardService::with_actor("agent-7");
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+
+    // Act
+    let picked = service
+        .next(
+            temp_dir.path(),
+            &CardFilter::default(),
+            Some(Duration::minutes(30)),
+        )
+        .unwrap();
+
+    // Assert
+    let card = picked.board.get_card("TES-001").unwrap();
+    assert!(picked.claimed);
+    assert_eq!(card.assignee.as_deref(), Some("agent-7"));
+    assert_eq!(card.column_id, "in_progress");
+    assert_eq!(card.claim.as_ref().unwrap().previous_column, "todo");
+    assert!(matches!(
+        service.next(
+            temp_dir.path(),
+            &CardFilter::default(),
+            Some(Duration::minutes(30))
+        ),
+        Err(CardServiceError::NoCardAvailable)
+    ));
+
+    // Act
+    let replayed = service.undo(temp_dir.path(), 1).unwrap();
+
+    // Assert
+    assert_eq!(replayed.operations[0].description, "claim TES-001");
+    let card = replayed.board.get_card("TES-001").unwrap();
+    assert_eq!(card.assignee, None);
+    assert_eq!(card.column_id, "todo");
+}
+
+#[test]
+fn test_next_skips_cards_claimed_by_others() {
+    // Arrange
+    let (temp_dir, _) = setup_test_board();
+    let alice = CardService::with_actor("alice");
+    alice.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    alice
+        .claim(temp_dir.path(), "TES-001", Duration::minutes(30))
+        .unwrap();
+    let filter = CardFilter {
+        assignee: Some("alice".to_string()),
+        column: Some("todo".to_string()),
+        ..Default::default()
+    };
+
+    // Act
+    let taken =
+        CardService::with_actor("bob").next(temp_dir.path(), &filter, Some(Duration::minutes(30)));
+    let own = alice.next(temp_dir.path(), &filter, None).unwrap();
+
+    // Assert
+    assert!(matches!(taken, Err(CardServiceError::NoCardAvailable)));
+    assert_eq!(own.card_id, "TES-001");
+    let board = alice.get(temp_dir.path(), "TES-001").unwrap();
+    let claim = board.get_card("TES-001").unwrap().claim.clone().unwrap();
+    assert_eq!(claim.claimant, "alice");
+}
+
+#[test]
+fn test_next_uses_configured_columns() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service
+        .create(
+            temp_dir.path(),
+            NewCard {
+                column_id: Some("in_progress".to_string()),
+                ..NewCard::new("B")
+            },
+        )
+        .unwrap();
+    BoardConfig {
+        next_columns: vec!["in_progress".to_string()],
+        claim_column: Some("done".to_string()),
+        ..Default::default()
+    }
+    .save(temp_dir.path())
+    .unwrap();
+
+    // Act
+    let picked = service
+        .next(
+            temp_dir.path(),
+            &CardFilter::default(),
+            Some(Duration::minutes(30)),
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(picked.card_id, "TES-002");
+    assert_eq!(picked.board.get_card("TES-002").unwrap().column_id, "done");
+}
+
+#[test]
+fn test_next_with_unknown_column_fails() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let filter = CardFilter {
+        column: Some("nowhere".to_string()),
+        ..Default::default()
+    };
+
+    // Act
+    let result = service.next(temp_dir.path(), &filter, None);
+
+    // Assert
+    assert!(matches!(result, Err(CardServiceError::ColumnNotFound(c)) if c == "nowhere"));
+}
+
+/// Claims a card for `claimant` that expired a minute ago.
+fn claim_expired(temp_dir: &TempDir, card_id: &str, claimant: &str) {
+    let board_service = BoardService::new();
+    let mut board = board_service.load(temp_dir.path()).unwrap();
+    let claimed_at = Utc::now() - Duration::minutes(31);
+    board.claim_card(card_id, claimant, Duration::minutes(30), claimed_at);
+    board.move_card(card_id, "in_progress");
+    board_service.save(&mut board, temp_dir.path()).unwrap();
+}
+
+#[test]
+fn test_claim_heartbeat_and_release() {
+    // Arrange
+    let (temp_dir, _) = setup_test_board();
+    let service = CardService::with_actor("agent-7");
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+
+    // Act
+    let board = service
+        .claim(temp_dir.path(), "TES-001", Duration::minutes(30))
+        .unwrap();
+
+    // Assert
+    let claim = board.get_card("TES-001").unwrap().claim.clone().unwrap();
+    assert_eq!(claim.claimant, "agent-7");
+    assert_eq!(claim.ttl_seconds, 1800);
+
+    // Act
+    let board = service.heartbeat(temp_dir.path(), "TES-001").unwrap();
+
+    // Assert
+    let renewed = board.get_card("TES-001").unwrap().claim.clone().unwrap();
+    assert!(renewed.expires_at >= claim.expires_at);
+
+    // Act
+    let board = service.release(temp_dir.path(), "TES-001").unwrap();
+
+    // Assert
+    let card = board.get_card("TES-001").unwrap();
+    assert_eq!(card.claim, None);
+    assert_eq!(card.assignee, None);
+    assert!(matches!(
+        board.card_history("TES-001").last().unwrap().kind,
+        ActivityKind::Updated { .. }
+    ));
+    assert!(matches!(
+        service.heartbeat(temp_dir.path(), "TES-001"),
+        Err(CardServiceError::NotClaimed(_))
+    ));
+}
+
+#[test]
+fn test_card_claimed_by_other_cannot_be_changed_unless_forced() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    CardService::with_actor("agent-7")
+        .claim(temp_dir.path(), "TES-001", Duration::minutes(30))
+        .unwrap();
+    let other = CardService::with_actor("agent-8");
+
+    // Act
+    let moved = other.move_to(temp_dir.path(), "TES-001", "done");
+    let updated = other.update(
+        temp_dir.path(),
+        "TES-001",
+        CardUpdate {
+            title: Some("Mine".to_string()),
+            ..Default::default()
+        },
+    );
+    let claimed = other.claim(temp_dir.path(), "TES-001", Duration::minutes(30));
+
+    // Assert
+    for result in [moved, updated, claimed] {
+        match result {
+            Err(CardServiceError::ClaimedByOther { claimant, .. }) => {
+                assert_eq!(claimant, "agent-7")
+            }
+            other => panic!("expected ClaimedByOther, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    // Act
+    let board = other
+        .force(true)
+        .move_to(temp_dir.path(), "TES-001", "done")
+        .unwrap();
+
+    // Assert
+    let card = board.get_card("TES-001").unwrap();
+    assert_eq!(card.column_id, "done");
+    assert_eq!(card.claim, None);
+}
+
+#[test]
+fn test_card_claimed_by_other_cannot_be_deleted_or_archived_unless_forced() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+    CardService::with_actor("agent-7")
+        .claim(temp_dir.path(), "TES-001", Duration::minutes(30))
+        .unwrap();
+    let other = CardService::with_actor("agent-8");
+
+    // Act
+    let deleted = other.delete(temp_dir.path(), "TES-001");
+    let archived = other.archive(temp_dir.path(), "TES-001");
+    let linked = other.link(temp_dir.path(), "TES-002", "TES-001");
+    let commented = other.add_comment(temp_dir.path(), "TES-001", "agent-8", "Mine now");
+
+    // Assert
+    for result in [deleted, archived, linked, commented] {
+        assert!(matches!(
+            result,
+            Err(CardServiceError::ClaimedByOther { .. })
+        ));
+    }
+
+    // Act
+    let other = other.force(true);
+    other.archive(temp_dir.path(), "TES-001").unwrap();
+    let board = other.delete(temp_dir.path(), "TES-001").unwrap();
+
+    // Assert
+    assert!(board.get_card("TES-001").is_none());
+}
+
+#[test]
+fn test_expired_claim_returns_card_on_next_change() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service.create(temp_dir.path(), NewCard::new("B")).unwrap();
+    claim_expired(&temp_dir, "TES-001", "agent-7");
+
+    // Act
+    let board = CardService::with_actor("agent-8")
+        .update(
+            temp_dir.path(),
+            "TES-001",
+            CardUpdate {
+                title: Some("Taken over".to_string()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    // Assert
+    let card = board.get_card("TES-001").unwrap();
+    assert_eq!(card.claim, None);
+    assert_eq!(card.column_id, "todo");
+    assert_eq!(card.assignee, None);
+    assert_eq!(card.title, "Taken over");
+    let expiry = board
+        .card_history("TES-001")
+        .into_iter()
+        .find(|a| a.kind == ActivityKind::Released { expired: true })
+        .unwrap();
+    assert_eq!(expiry.actor, SYSTEM_ACTOR);
+}
+
+#[test]
+fn test_edit_checklist_records_changes_and_can_be_undone() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    for text in ["Write tests", "Update docs"] {
+        service
+            .edit_checklist(
+                temp_dir.path(),
+                "TES-001",
+                &ChecklistEdit::Add(text.to_string()),
+            )
+            .unwrap();
+    }
+
+    // Act
+    service
+        .edit_checklist(temp_dir.path(), "TES-001", &ChecklistEdit::Check(2))
+        .unwrap();
+    let board = service
+        .edit_checklist(
+            temp_dir.path(),
+            "TES-001",
+            &ChecklistEdit::Move { item: 2, to: 1 },
+        )
+        .unwrap();
+
+    // Assert
+    let card = board.get_card("TES-001").unwrap();
+    assert_eq!(card.checklist[0].to_string(), "[x] Update docs");
+    assert_eq!(card.checklist_progress(), Some((1, 2)));
+    let history = board.card_history("TES-001");
+    assert!(matches!(
+        &history.last().unwrap().kind,
+        ActivityKind::Updated { changes } if changes[0].field == "checklist"
+    ));
+
+    // Act
+    let replayed = service.undo(temp_dir.path(), 1).unwrap();
+
+    // Assert
+    assert_eq!(
+        replayed.operations[0].description,
+        "edit checklist of TES-001"
+    );
+    let card = replayed.board.get_card("TES-001").unwrap();
+    assert_eq!(card.checklist[0].to_string(), "[ ] Write tests");
+}
+
+#[test]
+fn test_edit_checklist_rejects_missing_item_and_empty_text() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+
+    // Act
+    let missing = service.edit_checklist(temp_dir.path(), "TES-001", &ChecklistEdit::Remove(1));
+    let zero = service.edit_checklist(temp_dir.path(), "TES-001", &ChecklistEdit::Check(0));
+    let empty = service.edit_checklist(
+        temp_dir.path(),
+        "TES-001",
+        &ChecklistEdit::Add("  ".to_string()),
+    );
+
+    // Assert
+    let err = missing.unwrap_err();
+    assert_eq!(err.code(), "checklist_item_not_found");
+    assert_eq!(err.to_string(), "Card TES-001 has no checklist item 1");
+    assert!(matches!(
+        zero,
+        Err(CardServiceError::ChecklistItemNotFound { item: 0, .. })
+    ));
+    assert!(matches!(empty, Err(CardServiceError::InvalidData(_))));
+}
+
+#[test]
+fn test_move_to_done_requires_checklist_when_configured() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    service
+        .edit_checklist(
+            temp_dir.path(),
+            "TES-001",
+            &ChecklistEdit::Add("Write tests".to_string()),
+        )
+        .unwrap();
+    let unconfigured = service.move_to(temp_dir.path(), "TES-001", "done");
+    service.move_to(temp_dir.path(), "TES-001", "todo").unwrap();
+    BoardConfig {
+        require_checklist_done: true,
+        ..Default::default()
+    }
+    .save(temp_dir.path())
+    .unwrap();
+
+    // Act
+    let blocked = service.move_to(temp_dir.path(), "TES-001", "done");
+    let forced = CardService::new()
+        .force(true)
+        .move_to(temp_dir.path(), "TES-001", "done");
+
+    // Assert
+    assert!(unconfigured.is_ok());
+    let err = blocked.unwrap_err();
+    assert_eq!(err.code(), "checklist_incomplete");
+    assert!(matches!(
+        err,
+        CardServiceError::ChecklistIncomplete { open: 1, .. }
+    ));
+    assert_eq!(
+        forced.unwrap().get_card("TES-001").unwrap().column_id,
+        "done"
+    );
+}
+
+/// Creates an epic TES-001 with stories TES-002 and TES-003, and a task
+/// TES-004 below TES-002.
+fn create_epic(temp_dir: &TempDir, service: &CardService) {
+    service
+        .create(temp_dir.path(), NewCard::new("Epic"))
+        .unwrap();
+    for (title, parent) in [("A", "TES-001"), ("B", "TES-001"), ("C", "TES-002")] {
+        service
+            .create(
+                temp_dir.path(),
+                NewCard {
+                    parent: Some(parent.to_string()),
+                    ..NewCard::new(title)
+                },
+            )
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_create_with_parent() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    create_epic(&temp_dir, &service);
+
+    // Act
+    let missing = service.create(
+        temp_dir.path(),
+        NewCard {
+            parent: Some("TES-999".to_string()),
+            ..NewCard::new("Orphan")
+        },
+    );
+
+    // Assert
+    let board = service.list(temp_dir.path()).unwrap();
+    assert_eq!(
+        board.get_card("TES-004").unwrap().parent.as_deref(),
+        Some("TES-002")
+    );
+    assert_eq!(board.descendants("TES-001").len(), 3);
+    assert!(matches!(missing, Err(CardServiceError::CardNotFound(id)) if id == "TES-999"));
+}
+
+#[test]
+fn test_update_parent_rejects_cycle() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    create_epic(&temp_dir, &service);
+
+    // Act
+    let cycle = service.update(
+        temp_dir.path(),
+        "TES-001",
+        CardUpdate {
+            parent: Some(Some("TES-004".to_string())),
+            ..Default::default()
+        },
+    );
+    let board = service
+        .update(
+            temp_dir.path(),
+            "TES-004",
+            CardUpdate {
+                parent: Some(None),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    // Assert
+    let err = cycle.unwrap_err();
+    assert_eq!(err.code(), "parent_cycle");
+    assert_eq!(board.get_card("TES-004").unwrap().parent, None);
+    assert!(matches!(
+        &board.card_history("TES-004").last().unwrap().kind,
+        ActivityKind::Updated { changes } if changes[0].field == "parent"
+    ));
+}
+
+#[test]
+fn test_delete_parent_requires_policy() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    create_epic(&temp_dir, &service);
+
+    // Act
+    let result = service.delete(temp_dir.path(), "TES-002");
+
+    // Assert
+    let err = result.unwrap_err();
+    assert_eq!(err.code(), "card_has_children");
+    assert!(matches!(
+        err,
+        CardServiceError::HasChildren { children, .. } if children == vec!["TES-004"]
+    ));
+    assert!(service.get(temp_dir.path(), "TES-002").is_ok());
+}
+
+#[test]
+fn test_delete_with_reparent_moves_children_up() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    create_epic(&temp_dir, &service);
+
+    // Act
+    let board = service
+        .delete_with_children(temp_dir.path(), "TES-002", Some(ChildPolicy::Reparent))
+        .unwrap();
+
+    // Assert
+    assert!(board.get_card("TES-002").is_none());
+    assert_eq!(
+        board.get_card("TES-004").unwrap().parent.as_deref(),
+        Some("TES-001")
+    );
+}
+
+#[test]
+fn test_cascade_delete_is_undone_in_one_step() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    create_epic(&temp_dir, &service);
+    let original = service.list(temp_dir.path()).unwrap();
+
+    // Act
+    let board = service
+        .delete_with_children(temp_dir.path(), "TES-001", Some(ChildPolicy::Cascade))
+        .unwrap();
+
+    // Assert
+    assert!(board.cards.is_empty());
+    assert!(board.card_history("TES-004").iter().any(|a| matches!(
+        &a.kind,
+        ActivityKind::Deleted { title } if title == "C"
+    )));
+
+    // Act
+    let replayed = service.undo(temp_dir.path(), 1).unwrap();
+
+    // Assert
+    assert_eq!(replayed.operations[0].description, "delete TES-001");
+    assert_eq!(replayed.board.cards.len(), 4);
+    assert_eq!(replayed.board.columns[0].cards, original.columns[0].cards);
+}
+
+#[test]
+fn test_list_under_epic() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    create_epic(&temp_dir, &service);
+    service
+        .create(temp_dir.path(), NewCard::new("Other"))
+        .unwrap();
+    service
+        .move_to(temp_dir.path(), "TES-002", "in_progress")
+        .unwrap();
+    let in_todo = CardFilter {
+        column: Some("todo".to_string()),
+        ..Default::default()
+    };
+
+    // Act
+    let board = service
+        .list_under(temp_dir.path(), "TES-001", &in_todo)
+        .unwrap();
+    let missing = service.list_under(temp_dir.path(), "TES-999", &in_todo);
+
+    // Assert
+    let ids: Vec<&str> = board.cards.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, vec!["TES-003", "TES-004"]);
+    assert!(matches!(missing, Err(CardServiceError::CardNotFound(_))));
+}
+
+#[test]
+fn test_inconsistent_board_can_be_listed_but_not_changed() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    service.create(temp_dir.path(), NewCard::new("A")).unwrap();
+    let mut board = service.list(temp_dir.path()).unwrap();
+    board.next_card_number = 1;
+    JsonBoardRepository::new()
+        .save(&board, temp_dir.path())
+        .unwrap();
+
+    // Act
+    let listed = service.list(temp_dir.path());
+    let created = service.create(temp_dir.path(), NewCard::new("B"));
+
+    // Assert
+    assert_eq!(listed.unwrap().cards.len(), 1);
+    assert!(matches!(
+        created,
+        Err(CardServiceError::BoardService(
+            BoardServiceError::Inconsistent { .. }
+        ))
+    ));
+}
@@ -0,0 +1,330 @@
+use std::path::Path;
+
+use chrono::Utc;
+
+use crate::domain::{Board, WipLimitExceeded, WipPolicy};
+
+use super::{BoardService, BoardServiceError};
+
+/// Service for column-related operations.
+///
+/// Provides validated operations for adding, removing, renaming,
+/// and reordering the columns of a board, and for setting their WIP limits.
+pub struct ColumnService {
+    board_service: BoardService,
+    /// Whether cards may be moved over a WIP limit the board enforces
+    force: bool,
+}
+
+/// Errors that can occur during column operations.
+#[derive(Debug, thiserror::Error)]
+pub enum ColumnServiceError {
+    #[error("Board service error: {0}")]
+    BoardService(#[from] BoardServiceError),
+    #[error("Column not found: {0}")]
+    ColumnNotFound(String),
+    #[error("Column already exists: {0}")]
+    ColumnAlreadyExists(String),
+    #[error("Invalid column ID '{0}': use lowercase letters, digits, '-' or '_'")]
+    InvalidId(String),
+    #[error("Column name cannot be empty")]
+    EmptyName,
+    #[error("Cannot remove the last column of a board")]
+    LastColumn,
+    #[error("Column '{column}' contains {count} card(s); choose a destination with --move-to")]
+    ColumnNotEmpty { column: String, count: usize },
+    #[error("Invalid destination column: {0}")]
+    InvalidDestination(String),
+    #[error("Column {} is at its WIP limit of {}", .0.column_id, .0.limit)]
+    WipLimitExceeded(#[from] WipLimitExceeded),
+}
+
+impl ColumnServiceError {
+    /// Returns a stable, machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ColumnServiceError::BoardService(e) => e.code(),
+            ColumnServiceError::ColumnNotFound(_) => "column_not_found",
+            ColumnServiceError::ColumnAlreadyExists(_) => "column_already_exists",
+            ColumnServiceError::InvalidId(_) => "invalid_column_id",
+            ColumnServiceError::EmptyName => "invalid_column_name",
+            ColumnServiceError::LastColumn => "last_column",
+            ColumnServiceError::ColumnNotEmpty { .. } => "column_not_empty",
+            ColumnServiceError::InvalidDestination(_) => "invalid_destination",
+            ColumnServiceError::WipLimitExceeded(_) => "wip_limit_exceeded",
+        }
+    }
+}
+
+impl ColumnService {
+    /// Creates a new column service.
+    pub fn new() -> Self {
+        Self::with_board_service(BoardService::new())
+    }
+
+    /// Creates a column service working through `board_service`.
+    pub fn with_board_service(board_service: BoardService) -> Self {
+        Self {
+            board_service,
+            force: false,
+        }
+    }
+
+    /// Allows moving cards over a WIP limit the board enforces.
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Only applies changes while the board is at `revision`.
+    ///
+    /// See [`BoardService::if_revision`].
+    pub fn if_revision(mut self, revision: Option<u64>) -> Self {
+        self.board_service = self.board_service.if_revision(revision);
+        self
+    }
+
+    /// Adds a new column to the board.
+    ///
+    /// Without a position, the column goes before the done column: the last
+    /// column is the one whose cards count as finished, so it only changes
+    /// when asked for.
+    ///
+    /// # Arguments
+    /// * `base_path` - Path to the board directory
+    /// * `id` - Column identifier (e.g., "review")
+    /// * `name` - Optional display name (derived from the ID if omitted)
+    /// * `position` - Optional position (0 = leftmost, defaults to before
+    ///   the done column)
+    pub fn add(
+        &self,
+        base_path: &Path,
+        id: String,
+        name: Option<String>,
+        position: Option<usize>,
+    ) -> Result<Board, ColumnServiceError> {
+        Self::validate_id(&id)?;
+
+        let name = match name {
+            Some(name) => Self::validate_name(name)?,
+            None => Self::default_name(&id),
+        };
+
+        let _lock = self.board_service.lock(base_path)?;
+
+        let mut board = self.board_service.load_for_change(base_path)?;
+
+        if board.columns.iter().any(|c| c.id == id) {
+            return Err(ColumnServiceError::ColumnAlreadyExists(id));
+        }
+
+        let last = board.columns.len();
+        board.add_column(id.clone(), name, last as u32);
+        board.move_column(&id, position.unwrap_or(last.saturating_sub(1)));
+
+        self.board_service.save(&mut board, base_path)?;
+
+        Ok(board)
+    }
+
+    /// Removes a column from the board.
+    ///
+    /// # Arguments
+    /// * `base_path` - Path to the board directory
+    /// * `id` - Column to remove
+    /// * `move_to` - Destination for the column's cards (required if it has any)
+    ///
+    /// Returns the board and the number of cards moved to `move_to`,
+    /// including archived cards.
+    ///
+    /// # Errors
+    /// Unless the service is forced, returns
+    /// `ColumnServiceError::WipLimitExceeded` if the moved cards take
+    /// `move_to` over a WIP limit the board enforces.
+    pub fn remove(
+        &self,
+        base_path: &Path,
+        id: &str,
+        move_to: Option<&str>,
+    ) -> Result<(Board, usize), ColumnServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.board_service.load_for_change(base_path)?;
+
+        if !board.columns.iter().any(|c| c.id == id) {
+            return Err(ColumnServiceError::ColumnNotFound(id.to_string()));
+        }
+
+        if board.columns.len() <= 1 {
+            return Err(ColumnServiceError::LastColumn);
+        }
+
+        // Archived cards keep their column, so they have to move as well.
+        let count = board.cards.iter().filter(|c| c.column_id == id).count();
+
+        if let Some(target) = move_to {
+            if target == id || !board.columns.iter().any(|c| c.id == target) {
+                return Err(ColumnServiceError::InvalidDestination(target.to_string()));
+            }
+        } else if count > 0 {
+            return Err(ColumnServiceError::ColumnNotEmpty {
+                column: id.to_string(),
+                count,
+            });
+        }
+
+        if !board.remove_column(id, move_to) {
+            return Err(ColumnServiceError::ColumnNotFound(id.to_string()));
+        }
+        if let Some(target) = move_to {
+            if !self.force && board.wip_policy == WipPolicy::Block {
+                board.check_wip_limit(target)?;
+            }
+        }
+
+        self.board_service.save(&mut board, base_path)?;
+
+        Ok((board, count))
+    }
+
+    /// Renames a column.
+    pub fn rename(
+        &self,
+        base_path: &Path,
+        id: &str,
+        name: String,
+    ) -> Result<Board, ColumnServiceError> {
+        let name = Self::validate_name(name)?;
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.board_service.load_for_change(base_path)?;
+
+        if !board.rename_column(id, name) {
+            return Err(ColumnServiceError::ColumnNotFound(id.to_string()));
+        }
+
+        self.board_service.save(&mut board, base_path)?;
+
+        Ok(board)
+    }
+
+    /// Moves a column to a new position (0 = leftmost).
+    ///
+    /// Returns the board and the position the column ended up at, which is
+    /// the last one if `position` is past the end.
+    pub fn reorder(
+        &self,
+        base_path: &Path,
+        id: &str,
+        position: usize,
+    ) -> Result<(Board, usize), ColumnServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.board_service.load_for_change(base_path)?;
+
+        if !board.move_column(id, position) {
+            return Err(ColumnServiceError::ColumnNotFound(id.to_string()));
+        }
+        let applied = board
+            .columns
+            .iter()
+            .position(|c| c.id == id)
+            .unwrap_or(position);
+
+        self.board_service.save(&mut board, base_path)?;
+
+        Ok((board, applied))
+    }
+
+    /// Sets or clears the WIP limit of a column.
+    pub fn set_wip_limit(
+        &self,
+        base_path: &Path,
+        id: &str,
+        limit: Option<u32>,
+    ) -> Result<Board, ColumnServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.board_service.load_for_change(base_path)?;
+
+        if !board.set_wip_limit(id, limit) {
+            return Err(ColumnServiceError::ColumnNotFound(id.to_string()));
+        }
+
+        self.board_service.save(&mut board, base_path)?;
+
+        Ok(board)
+    }
+
+    /// Sets whether changes may take columns over their WIP limit.
+    pub fn set_wip_policy(
+        &self,
+        base_path: &Path,
+        policy: WipPolicy,
+    ) -> Result<Board, ColumnServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.board_service.load_for_change(base_path)?;
+
+        board.wip_policy = policy;
+        board.updated_at = Utc::now();
+        self.board_service.save(&mut board, base_path)?;
+
+        Ok(board)
+    }
+
+    /// Lists the columns of the board.
+    pub fn list(&self, base_path: &Path) -> Result<Board, ColumnServiceError> {
+        Ok(self.board_service.load(base_path)?)
+    }
+
+    /// Validates a column ID.
+    ///
+    /// IDs must be non-empty and contain only lowercase letters,
+    /// digits, hyphens, and underscores.
+    fn validate_id(id: &str) -> Result<(), ColumnServiceError> {
+        let valid = !id.is_empty()
+            && id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+
+        if valid {
+            Ok(())
+        } else {
+            Err(ColumnServiceError::InvalidId(id.to_string()))
+        }
+    }
+
+    /// Trims a column name and rejects empty names.
+    fn validate_name(name: String) -> Result<String, ColumnServiceError> {
+        let trimmed = name.trim();
+        if trimmed.is_empty() {
+            Err(ColumnServiceError::EmptyName)
+        } else {
+            Ok(trimmed.to_string())
+        }
+    }
+
+    /// Derives a display name from a column ID.
+    ///
+    /// Splits on hyphens and underscores and capitalizes each word,
+    /// so "in_review" becomes "In Review".
+    fn default_name(id: &str) -> String {
+        id.split(['_', '-'])
+            .filter(|w| !w.is_empty())
+            .map(|w| {
+                let mut chars = w.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+impl Default for ColumnService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+#[path = "./column_service_tests.rs"]
+mod column_service_tests;
@@ -0,0 +1,276 @@
+use super::*;
+use crate::application::{CardService, NewCard};
+use tempfile::TempDir;
+
+fn setup_test_board() -> (TempDir, ColumnService) {
+    let temp_dir = TempDir::new().unwrap();
+    let board_service = BoardService::new();
+    board_service
+        .initialize(temp_dir.path(), Some("Test".to_string()))
+        .unwrap();
+    (temp_dir, ColumnService::new())
+}
+
+fn column_ids(board: &Board) -> Vec<&str> {
+    board.columns.iter().map(|c| c.id.as_str()).collect()
+}
+
+#[test]
+fn test_add_column_at_position() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let board = service
+        .add(temp_dir.path(), "review".to_string(), None, Some(2))
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        column_ids(&board),
+        vec!["todo", "in_progress", "review", "done"]
+    );
+    assert_eq!(board.columns[2].name, "Review");
+}
+
+#[test]
+fn test_add_column_defaults_to_before_done() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let board = service
+        .add(
+            temp_dir.path(),
+            "blocked".to_string(),
+            Some("Blocked".to_string()),
+            None,
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        column_ids(&board),
+        vec!["todo", "in_progress", "blocked", "done"]
+    );
+    assert!(board.is_done_column("done"));
+}
+
+#[test]
+fn test_added_column_does_not_finish_blockers() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let cards = CardService::new();
+    let blocker = cards
+        .create(temp_dir.path(), NewCard::new("Blocker"))
+        .unwrap()
+        .card_id;
+    let blocked = cards
+        .create(temp_dir.path(), NewCard::new("Blocked"))
+        .unwrap()
+        .card_id;
+    cards.link(temp_dir.path(), &blocker, &blocked).unwrap();
+
+    // Act
+    service
+        .add(temp_dir.path(), "review".to_string(), None, None)
+        .unwrap();
+    let board = cards.move_to(temp_dir.path(), &blocker, "review").unwrap();
+
+    // Assert
+    let card = board.get_card(&blocked).unwrap();
+    assert!(!board.is_ready(card));
+    assert!(cards
+        .move_to(temp_dir.path(), &blocked, "in_progress")
+        .is_err());
+}
+
+#[test]
+fn test_add_duplicate_column() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let result = service.add(temp_dir.path(), "todo".to_string(), None, None);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(ColumnServiceError::ColumnAlreadyExists(_))
+    ));
+}
+
+#[test]
+fn test_add_column_invalid_id() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let result = service.add(temp_dir.path(), "In Review".to_string(), None, None);
+
+    // Assert
+    assert!(matches!(result, Err(ColumnServiceError::InvalidId(_))));
+}
+
+#[test]
+fn test_remove_column_with_cards_requires_destination() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    CardService::new()
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+
+    // Act
+    let result = service.remove(temp_dir.path(), "todo", None);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(ColumnServiceError::ColumnNotEmpty { count: 1, .. })
+    ));
+}
+
+#[test]
+fn test_remove_column_moves_cards_to_destination() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let created = CardService::new()
+        .create(temp_dir.path(), NewCard::new("Task"))
+        .unwrap();
+
+    // Act
+    let (board, moved) = service
+        .remove(temp_dir.path(), "todo", Some("in_progress"))
+        .unwrap();
+
+    // Assert
+    assert_eq!(moved, 1);
+    assert_eq!(column_ids(&board), vec!["in_progress", "done"]);
+    assert_eq!(
+        board.get_card(&created.card_id).unwrap().column_id,
+        "in_progress"
+    );
+}
+
+#[test]
+fn test_remove_column_over_blocking_wip_limit_fails() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let cards = CardService::new();
+    cards
+        .create(temp_dir.path(), NewCard::new("Waiting"))
+        .unwrap();
+    cards
+        .create(
+            temp_dir.path(),
+            NewCard {
+                column_id: Some("in_progress".to_string()),
+                ..NewCard::new("Started")
+            },
+        )
+        .unwrap();
+    service
+        .set_wip_limit(temp_dir.path(), "in_progress", Some(1))
+        .unwrap();
+    service
+        .set_wip_policy(temp_dir.path(), WipPolicy::Block)
+        .unwrap();
+
+    // Act
+    let result = service.remove(temp_dir.path(), "todo", Some("in_progress"));
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(ColumnServiceError::WipLimitExceeded(_))
+    ));
+    assert_eq!(
+        column_ids(&service.list(temp_dir.path()).unwrap()),
+        vec!["todo", "in_progress", "done"]
+    );
+    let (board, _) = service
+        .force(true)
+        .remove(temp_dir.path(), "todo", Some("in_progress"))
+        .unwrap();
+    assert_eq!(board.get_cards_in_column("in_progress").len(), 2);
+}
+
+#[test]
+fn test_remove_column_invalid_destination() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let result = service.remove(temp_dir.path(), "todo", Some("todo"));
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(ColumnServiceError::InvalidDestination(_))
+    ));
+}
+
+#[test]
+fn test_rename_column() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let board = service
+        .rename(temp_dir.path(), "todo", "Backlog".to_string())
+        .unwrap();
+
+    // Assert
+    assert_eq!(board.columns[0].name, "Backlog");
+    assert!(matches!(
+        service.rename(temp_dir.path(), "todo", "  ".to_string()),
+        Err(ColumnServiceError::EmptyName)
+    ));
+}
+
+#[test]
+fn test_reorder_column() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let (board, position) = service.reorder(temp_dir.path(), "todo", 10).unwrap();
+
+    // Assert
+    assert_eq!(position, 2);
+    assert_eq!(column_ids(&board), vec!["in_progress", "done", "todo"]);
+    assert!(matches!(
+        service.reorder(temp_dir.path(), "missing", 0),
+        Err(ColumnServiceError::ColumnNotFound(_))
+    ));
+}
+
+#[test]
+fn test_default_name() {
+    assert_eq!(ColumnService::default_name("review"), "Review");
+    assert_eq!(ColumnService::default_name("in_review"), "In Review");
+    assert_eq!(ColumnService::default_name("qa-ready"), "Qa Ready");
+}
+
+#[test]
+fn test_set_wip_limit_and_policy() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let board = service
+        .set_wip_limit(temp_dir.path(), "in_progress", Some(2))
+        .unwrap();
+    let board_with_policy = service
+        .set_wip_policy(temp_dir.path(), WipPolicy::Block)
+        .unwrap();
+
+    // Assert
+    assert_eq!(board.columns[1].wip_limit, Some(2));
+    assert_eq!(board_with_policy.columns[1].wip_limit, Some(2));
+    assert_eq!(board_with_policy.wip_policy, WipPolicy::Block);
+    assert!(matches!(
+        service.set_wip_limit(temp_dir.path(), "review", Some(2)),
+        Err(ColumnServiceError::ColumnNotFound(_))
+    ));
+}
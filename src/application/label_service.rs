@@ -0,0 +1,153 @@
+use std::path::Path;
+
+use crate::domain::{Board, Label};
+
+use super::{BoardService, BoardServiceError};
+
+/// Service for label-related operations.
+///
+/// Labels live on cards; this service manages them across the whole
+/// board and maintains the board's label registry.
+pub struct LabelService {
+    board_service: BoardService,
+}
+
+/// Errors that can occur during label operations.
+#[derive(Debug, thiserror::Error)]
+pub enum LabelServiceError {
+    #[error("Board service error: {0}")]
+    BoardService(#[from] BoardServiceError),
+    #[error("Label not found: {0}")]
+    LabelNotFound(String),
+    #[error("{0}")]
+    InvalidLabel(String),
+    #[error("{0}")]
+    InvalidColor(String),
+}
+
+impl LabelServiceError {
+    /// Returns a stable, machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            LabelServiceError::BoardService(e) => e.code(),
+            LabelServiceError::LabelNotFound(_) => "label_not_found",
+            LabelServiceError::InvalidLabel(_) => "invalid_label",
+            LabelServiceError::InvalidColor(_) => "invalid_color",
+        }
+    }
+}
+
+/// Result of an operation that changed the labels of several cards.
+pub struct LabelChangeInfo {
+    pub cards_affected: usize,
+    pub board: Board,
+}
+
+impl LabelService {
+    /// Creates a new label service.
+    pub fn new() -> Self {
+        Self::with_board_service(BoardService::new())
+    }
+
+    /// Creates a label service working through `board_service`.
+    pub fn with_board_service(board_service: BoardService) -> Self {
+        Self { board_service }
+    }
+
+    /// Only applies changes while the board is at `revision`.
+    ///
+    /// See [`BoardService::if_revision`].
+    pub fn if_revision(mut self, revision: Option<u64>) -> Self {
+        self.board_service = self.board_service.if_revision(revision);
+        self
+    }
+
+    /// Loads the board to list its labels.
+    pub fn list(&self, base_path: &Path) -> Result<Board, LabelServiceError> {
+        Ok(self.board_service.load(base_path)?)
+    }
+
+    /// Renames a label on every card.
+    ///
+    /// Renaming to an existing label merges the two.
+    pub fn rename(
+        &self,
+        base_path: &Path,
+        name: &str,
+        new_name: &str,
+    ) -> Result<LabelChangeInfo, LabelServiceError> {
+        let new_name = Label::normalize_name(new_name).map_err(LabelServiceError::InvalidLabel)?;
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load_with_label(base_path, name)?;
+
+        let cards_affected = board.rename_label(name, new_name);
+
+        self.board_service.save(&mut board, base_path)?;
+
+        Ok(LabelChangeInfo {
+            cards_affected,
+            board,
+        })
+    }
+
+    /// Removes a label from every card and from the registry.
+    pub fn delete(
+        &self,
+        base_path: &Path,
+        name: &str,
+    ) -> Result<LabelChangeInfo, LabelServiceError> {
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load_with_label(base_path, name)?;
+
+        let cards_affected = board.delete_label(name);
+
+        self.board_service.save(&mut board, base_path)?;
+
+        Ok(LabelChangeInfo {
+            cards_affected,
+            board,
+        })
+    }
+
+    /// Sets or clears the display color of a label.
+    pub fn set_color(
+        &self,
+        base_path: &Path,
+        name: &str,
+        color: Option<&str>,
+    ) -> Result<Board, LabelServiceError> {
+        let color = color
+            .map(Label::normalize_color)
+            .transpose()
+            .map_err(LabelServiceError::InvalidColor)?;
+        let _lock = self.board_service.lock(base_path)?;
+        let mut board = self.load_with_label(base_path, name)?;
+
+        board.set_label_color(name, color);
+
+        self.board_service.save(&mut board, base_path)?;
+
+        Ok(board)
+    }
+
+    /// Loads the board and checks that the label is known.
+    fn load_with_label(&self, base_path: &Path, name: &str) -> Result<Board, LabelServiceError> {
+        let board = self.board_service.load_for_change(base_path)?;
+
+        if !board.label_names().iter().any(|l| l == name) {
+            return Err(LabelServiceError::LabelNotFound(name.to_string()));
+        }
+
+        Ok(board)
+    }
+}
+
+impl Default for LabelService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+#[path = "./label_service_tests.rs"]
+mod label_service_tests;
@@ -0,0 +1,93 @@
+use super::*;
+use crate::application::{CardService, NewCard};
+use tempfile::TempDir;
+
+fn setup_test_board() -> (TempDir, LabelService) {
+    let temp_dir = TempDir::new().unwrap();
+    let board_service = BoardService::new();
+    board_service
+        .initialize(temp_dir.path(), Some("Test".to_string()))
+        .unwrap();
+    (temp_dir, LabelService::new())
+}
+
+fn create_labeled_card(temp_dir: &TempDir, labels: &[&str]) -> String {
+    CardService::new()
+        .create(
+            temp_dir.path(),
+            NewCard {
+                labels: labels.iter().map(|l| l.to_string()).collect(),
+                ..NewCard::new("Task")
+            },
+        )
+        .unwrap()
+        .card_id
+}
+
+#[test]
+fn test_rename_label() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    let card_id = create_labeled_card(&temp_dir, &["bug"]);
+    create_labeled_card(&temp_dir, &["frontend"]);
+
+    // Act
+    let result = service.rename(temp_dir.path(), "bug", "defect").unwrap();
+
+    // Assert
+    assert_eq!(result.cards_affected, 1);
+    assert!(result.board.get_card(&card_id).unwrap().has_label("defect"));
+    assert_eq!(
+        result.board.label_names(),
+        vec!["defect".to_string(), "frontend".to_string()]
+    );
+}
+
+#[test]
+fn test_rename_unknown_label() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+
+    // Act
+    let result = service.rename(temp_dir.path(), "missing", "other");
+
+    // Assert
+    assert!(matches!(result, Err(LabelServiceError::LabelNotFound(_))));
+}
+
+#[test]
+fn test_delete_label() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    create_labeled_card(&temp_dir, &["bug", "frontend"]);
+    create_labeled_card(&temp_dir, &["bug"]);
+
+    // Act
+    let result = service.delete(temp_dir.path(), "bug").unwrap();
+
+    // Assert
+    assert_eq!(result.cards_affected, 2);
+    assert_eq!(result.board.label_names(), vec!["frontend".to_string()]);
+}
+
+#[test]
+fn test_set_color() {
+    // Arrange
+    let (temp_dir, service) = setup_test_board();
+    create_labeled_card(&temp_dir, &["bug"]);
+
+    // Act
+    let board = service
+        .set_color(temp_dir.path(), "bug", Some("Red"))
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        board.get_label("bug").unwrap().color.as_deref(),
+        Some("red")
+    );
+    assert!(matches!(
+        service.set_color(temp_dir.path(), "bug", Some("purple")),
+        Err(LabelServiceError::InvalidColor(_))
+    ));
+}
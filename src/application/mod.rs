@@ -4,8 +4,13 @@
 //! the infrastructure layer (storage, I/O). It implements the use cases
 //! that fulfill user requirements.
 
+pub mod actor;
 pub mod board_service;
 pub mod card_service;
+pub mod column_service;
+pub mod label_service;
 
 pub use board_service::{BoardService, BoardServiceError};
-pub use card_service::CardService;
+pub use card_service::{CardService, CardUpdate, ChecklistEdit, NewCard};
+pub use column_service::ColumnService;
+pub use label_service::LabelService;
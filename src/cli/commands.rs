@@ -1,6 +1,14 @@
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
-use clap::{Parser, Subcommand};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+
+use super::output::OutputFormat;
+use crate::domain::{
+    parse_due_date, parse_duration, parse_since, CardPosition, ChildPolicy, Priority, WipPolicy,
+};
+use crate::infrastructure::config::StorageBackend;
 
 /// Clicky - A CLI kanban board for human-agent collaboration
 #[derive(Parser)]
@@ -12,6 +20,14 @@ pub struct Cli {
     #[arg(short, long, global = true)]
     pub path: Option<PathBuf>,
 
+    /// Output format (json and ndjson are versioned and stable for scripts)
+    #[arg(short, long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+
+    /// Only modify the board if it is still at this revision (see `clicky info`)
+    #[arg(long, global = true, value_name = "N")]
+    pub if_revision: Option<u64>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -25,6 +41,11 @@ pub enum Commands {
         #[arg(short, long)]
         name: Option<String>,
 
+        /// Where to keep the board: json (default), sqlite, or directory
+        /// (one file per card, for boards kept in git)
+        #[arg(long, value_name = "BACKEND", default_value_t)]
+        storage: StorageBackend,
+
         /// Use interactive mode
         #[arg(short, long)]
         interactive: bool,
@@ -44,10 +65,30 @@ pub enum Commands {
         #[arg(short, long)]
         assignee: Option<String>,
 
-        /// Column to place the card in (defaults to "todo")
+        /// Column to place the card in (defaults to the first column)
         #[arg(short, long)]
         column: Option<String>,
 
+        /// Priority (critical, high, medium, low)
+        #[arg(long)]
+        priority: Option<Priority>,
+
+        /// Label to attach (repeatable)
+        #[arg(short, long = "label", value_name = "LABEL")]
+        labels: Vec<String>,
+
+        /// Due date (YYYY-MM-DD, today, tomorrow, +3d, +2w, +1m or a weekday)
+        #[arg(long, value_parser = parse_due)]
+        due: Option<NaiveDate>,
+
+        /// Card the new card is part of, e.g. its epic
+        #[arg(long, value_name = "ID")]
+        parent: Option<String>,
+
+        /// Create the card even if its column is at a blocking WIP limit
+        #[arg(short, long)]
+        force: bool,
+
         /// Use interactive mode
         #[arg(short, long)]
         interactive: bool,
@@ -63,6 +104,14 @@ pub enum Commands {
         #[arg(required_unless_present = "interactive")]
         column: Option<String>,
 
+        #[command(flatten)]
+        position: CardPositionArgs,
+
+        /// Move the card even if the column is at a blocking WIP limit, the
+        /// card is blocked or someone else claimed it
+        #[arg(short, long)]
+        force: bool,
+
         /// Use interactive mode
         #[arg(short, long)]
         interactive: bool,
@@ -89,6 +138,38 @@ pub enum Commands {
         #[arg(short, long)]
         assignee: Option<String>,
 
+        /// Filter by priority
+        #[arg(long)]
+        priority: Option<Priority>,
+
+        /// Filter by label (repeatable; cards must have all given labels)
+        #[arg(short, long = "label", value_name = "LABEL")]
+        labels: Vec<String>,
+
+        /// Match cards having any of the given labels instead of all
+        #[arg(long, requires = "labels")]
+        any_label: bool,
+
+        /// Only show cards past their due date (excluding the done column)
+        #[arg(long)]
+        overdue: bool,
+
+        /// Only show cards due before a date (same formats as --due)
+        #[arg(long, value_parser = parse_due, value_name = "DATE")]
+        due_before: Option<NaiveDate>,
+
+        /// Show archived cards instead of the cards on the board
+        #[arg(long)]
+        archived: bool,
+
+        /// Only show cards below this card, e.g. the cards of an epic
+        #[arg(long, value_name = "ID")]
+        epic: Option<String>,
+
+        /// Show cards as a tree of parents and children instead of by column
+        #[arg(long)]
+        tree: bool,
+
         /// Use interactive mode
         #[arg(short, long)]
         interactive: bool,
@@ -120,6 +201,50 @@ pub enum Commands {
         #[arg(long, conflicts_with = "assignee")]
         clear_assignee: bool,
 
+        /// New priority (critical, high, medium, low)
+        #[arg(long)]
+        priority: Option<Priority>,
+
+        /// Clear the priority
+        #[arg(long, conflicts_with = "priority")]
+        clear_priority: bool,
+
+        /// Replace all labels (repeatable)
+        #[arg(short, long = "label", value_name = "LABEL")]
+        labels: Vec<String>,
+
+        /// Add a label (repeatable)
+        #[arg(long = "add-label", value_name = "LABEL")]
+        add_labels: Vec<String>,
+
+        /// Remove a label (repeatable)
+        #[arg(long = "remove-label", value_name = "LABEL")]
+        remove_labels: Vec<String>,
+
+        /// Remove all labels
+        #[arg(long, conflicts_with = "labels")]
+        clear_labels: bool,
+
+        /// New due date (YYYY-MM-DD, today, tomorrow, +3d, +2w, +1m or a weekday)
+        #[arg(long, value_parser = parse_due)]
+        due: Option<NaiveDate>,
+
+        /// Clear the due date
+        #[arg(long, conflicts_with = "due")]
+        clear_due: bool,
+
+        /// New parent card, e.g. an epic
+        #[arg(long, value_name = "ID")]
+        parent: Option<String>,
+
+        /// Make the card top-level
+        #[arg(long, conflicts_with = "parent")]
+        clear_parent: bool,
+
+        /// Update the card even if someone else claimed it
+        #[arg(short, long)]
+        force: bool,
+
         /// Use interactive mode
         #[arg(short, long)]
         interactive: bool,
@@ -135,15 +260,666 @@ pub enum Commands {
         #[arg(short, long)]
         force: bool,
 
+        /// What happens to the card's children: orphan, reparent or cascade
+        #[arg(long, value_name = "POLICY")]
+        children: Option<ChildPolicy>,
+
+        /// Delete the card even if someone else claimed it
+        #[arg(long)]
+        ignore_claim: bool,
+
         /// Use interactive mode
         #[arg(short, long)]
         interactive: bool,
     },
 
+    /// Change the position of a card within its column
+    #[command(group(
+        ArgGroup::new("rank_position")
+            .args(["top", "bottom", "before", "after", "position"])
+            .required(true)
+    ))]
+    Rank {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        #[command(flatten)]
+        position: CardPositionArgs,
+    },
+
+    /// Make a card block another card
+    Link {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        /// How the first card relates to the second
+        relation: LinkRelation,
+
+        /// ID of the other card
+        other_id: String,
+
+        /// Change the links of a card someone else holds a claim on
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Remove a link made with `link`
+    Unlink {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        /// How the first card relates to the second
+        relation: LinkRelation,
+
+        /// ID of the other card
+        other_id: String,
+
+        /// Change the links of a card someone else holds a claim on
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// List cards whose blockers are all done
+    Ready,
+
+    /// Pick the card to work on next: the most important ready, unassigned card
+    Next {
+        /// Pick from this column instead of the configured ones
+        #[arg(short, long)]
+        column: Option<String>,
+
+        /// Pick from the cards assigned to this person instead of unassigned cards
+        #[arg(short, long)]
+        assignee: Option<String>,
+
+        /// Only pick cards carrying this label (repeatable)
+        #[arg(short, long = "label", value_name = "LABEL")]
+        labels: Vec<String>,
+
+        /// Claim the card and move it to the in-progress column
+        #[arg(long)]
+        claim: bool,
+
+        /// How long the claim lasts without a heartbeat (e.g. 30m, 2h)
+        #[arg(long, value_parser = parse_ttl, default_value = "30m", requires = "claim")]
+        ttl: Duration,
+
+        /// Claim even if the in-progress column is at a blocking WIP limit
+        #[arg(short, long, requires = "claim")]
+        force: bool,
+    },
+
+    /// Claim a card for yourself for a limited time
+    Claim {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        /// How long the claim lasts without a heartbeat (e.g. 30m, 2h)
+        #[arg(long, value_parser = parse_ttl, default_value = "30m")]
+        ttl: Duration,
+
+        /// Take over a claim someone else holds
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Extend your claim on a card by its TTL
+    Heartbeat {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+    },
+
+    /// Give up a claim, returning the card to where it was claimed
+    Release {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        /// Release a claim someone else holds, or return the card to a
+        /// column at a blocking WIP limit
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Add, check, reorder and remove checklist items of a card
+    Checklist {
+        #[command(subcommand)]
+        command: ChecklistCommands,
+
+        /// Change a card someone else holds a claim on
+        #[arg(short, long, global = true)]
+        force: bool,
+    },
+
+    /// Archive a card, hiding it from the board but keeping its history
+    Archive {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        /// Archive the card even if someone else claimed it
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Return an archived card to the board
+    Unarchive {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        /// Unarchive the card even if its column is at a blocking WIP limit
+        /// or someone else claimed it
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Add a comment to a card
+    Comment {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        /// Comment text
+        text: String,
+
+        /// Author name (defaults to $CLICKY_USER, then the login name)
+        #[arg(long)]
+        author: Option<String>,
+
+        /// Comment even if someone else claimed the card
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Undo the latest card changes
+    Undo {
+        /// Number of changes to undo
+        #[arg(long, default_value = "1")]
+        steps: NonZeroUsize,
+
+        /// Undo even if it takes a column over a blocking WIP limit
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Redo the latest undone card changes
+    Redo {
+        /// Number of changes to redo
+        #[arg(long, default_value = "1")]
+        steps: NonZeroUsize,
+
+        /// Redo even if it takes a column over a blocking WIP limit
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Show the change history of a card
+    History {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+    },
+
+    /// Show changes made across the board
+    Log {
+        /// Only changes since this time (YYYY-MM-DD, RFC 3339, or 30m, 12h, 7d, 2w ago)
+        #[arg(long, value_parser = parse_since_now)]
+        since: Option<DateTime<Utc>>,
+
+        /// Only changes made by this actor
+        #[arg(long)]
+        actor: Option<String>,
+    },
+
     /// Show board information
     Info,
 
+    /// Check the board for inconsistencies
+    Check {
+        /// Fix the inconsistencies found
+        #[arg(long)]
+        repair: bool,
+    },
+
+    /// Upgrade the board file to the current schema version
+    Migrate {
+        /// Show the pending steps without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Manage how the board is stored
+    Storage {
+        #[command(subcommand)]
+        command: StorageCommands,
+    },
+
+    /// List, inspect and restore automatic snapshots of the board
+    Backup {
+        #[command(subcommand)]
+        command: BackupCommands,
+    },
+
+    /// Merge two versions of the board file (run by git as a merge driver)
+    #[command(name = "merge-driver")]
+    MergeDriver {
+        /// Common ancestor version (%O)
+        base: PathBuf,
+
+        /// Our version, replaced by the merged board (%A)
+        ours: PathBuf,
+
+        /// Their version (%B)
+        theirs: PathBuf,
+    },
+
+    /// Integrate the board with git
+    Git {
+        #[command(subcommand)]
+        command: GitCommands,
+    },
+
+    /// Manage board columns
+    Column {
+        #[command(subcommand)]
+        command: ColumnCommands,
+    },
+
+    /// List and manage labels across all cards
+    Labels {
+        /// Defaults to listing labels
+        #[command(subcommand)]
+        command: Option<LabelCommands>,
+    },
+
     /// Launch interactive TUI mode
     #[cfg(feature = "tui")]
     Tui,
 }
+
+/// Where to place a card within a column.
+#[derive(Args, Debug, Default)]
+#[group(multiple = false)]
+pub struct CardPositionArgs {
+    /// Place the card at the top of the column
+    #[arg(long)]
+    pub top: bool,
+
+    /// Place the card at the bottom of the column
+    #[arg(long)]
+    pub bottom: bool,
+
+    /// Place the card directly before another card
+    #[arg(long, value_name = "CARD_ID")]
+    pub before: Option<String>,
+
+    /// Place the card directly after another card
+    #[arg(long, value_name = "CARD_ID")]
+    pub after: Option<String>,
+
+    /// Place the card at a position (0 = top)
+    #[arg(long)]
+    pub position: Option<usize>,
+}
+
+impl CardPositionArgs {
+    /// Returns the requested position, if any.
+    pub fn to_position(&self) -> Option<CardPosition> {
+        if self.top {
+            Some(CardPosition::Top)
+        } else if self.bottom {
+            Some(CardPosition::Bottom)
+        } else if let Some(card_id) = &self.before {
+            Some(CardPosition::Before(card_id.clone()))
+        } else if let Some(card_id) = &self.after {
+            Some(CardPosition::After(card_id.clone()))
+        } else {
+            self.position.map(CardPosition::Index)
+        }
+    }
+}
+
+/// How the first card of `link` and `unlink` relates to the second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LinkRelation {
+    /// The first card must be done before the second can start
+    Blocks,
+    /// The second card must be done before the first can start
+    BlockedBy,
+}
+
+impl LinkRelation {
+    /// Orders two card IDs as (blocker, blocked).
+    pub fn order<'a>(self, card_id: &'a str, other_id: &'a str) -> (&'a str, &'a str) {
+        match self {
+            LinkRelation::Blocks => (card_id, other_id),
+            LinkRelation::BlockedBy => (other_id, card_id),
+        }
+    }
+}
+
+/// Parses a due date argument relative to the local date.
+fn parse_due(value: &str) -> Result<NaiveDate, String> {
+    parse_due_date(value, Local::now().date_naive())
+}
+
+/// Parses a `--ttl` argument, which must be positive.
+fn parse_ttl(value: &str) -> Result<Duration, String> {
+    match parse_duration(value)? {
+        ttl if ttl > Duration::zero() => Ok(ttl),
+        _ => Err(format!("Invalid TTL '{}': must be positive", value)),
+    }
+}
+
+/// Parses a `--since` argument relative to the current time.
+fn parse_since_now(value: &str) -> Result<DateTime<Utc>, String> {
+    parse_since(value, Utc::now())
+}
+
+impl Commands {
+    /// Returns the command name reported in machine-readable output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Commands::Init { .. } => "init",
+            Commands::Create { .. } => "create",
+            Commands::Move { .. } => "move",
+            Commands::Show { .. } => "show",
+            Commands::List { .. } => "list",
+            Commands::Update { .. } => "update",
+            Commands::Delete { .. } => "delete",
+            Commands::Rank { .. } => "rank",
+            Commands::Link { .. } => "link",
+            Commands::Unlink { .. } => "unlink",
+            Commands::Ready => "ready",
+            Commands::Next { .. } => "next",
+            Commands::Claim { .. } => "claim",
+            Commands::Heartbeat { .. } => "heartbeat",
+            Commands::Release { .. } => "release",
+            Commands::Checklist { command, .. } => command.name(),
+            Commands::Archive { .. } => "archive",
+            Commands::Unarchive { .. } => "unarchive",
+            Commands::Comment { .. } => "comment",
+            Commands::Undo { .. } => "undo",
+            Commands::Redo { .. } => "redo",
+            Commands::History { .. } => "history",
+            Commands::Log { .. } => "log",
+            Commands::Info => "info",
+            Commands::Check { .. } => "check",
+            Commands::Migrate { .. } => "migrate",
+            Commands::Storage { command } => command.name(),
+            Commands::Backup { command } => command.name(),
+            Commands::MergeDriver { .. } => "merge-driver",
+            Commands::Git { command } => command.name(),
+            Commands::Column { command } => command.name(),
+            Commands::Labels { command } => command
+                .as_ref()
+                .map_or(LabelCommands::List.name(), LabelCommands::name),
+            #[cfg(feature = "tui")]
+            Commands::Tui => "tui",
+        }
+    }
+}
+
+/// Column management subcommands
+#[derive(Subcommand)]
+pub enum ColumnCommands {
+    /// Add a new column
+    Add {
+        /// Column ID (e.g., "review")
+        id: String,
+
+        /// Display name (derived from the ID if omitted)
+        name: Option<String>,
+
+        /// Position of the new column (0 = leftmost, defaults to before the
+        /// done column, which is the last one)
+        #[arg(long)]
+        position: Option<usize>,
+    },
+
+    /// Remove a column
+    Remove {
+        /// Column ID
+        id: String,
+
+        /// Column that receives the removed column's cards
+        #[arg(short, long)]
+        move_to: Option<String>,
+
+        /// Move the cards even if that takes the column over a blocking WIP
+        /// limit
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Rename a column
+    Rename {
+        /// Column ID
+        id: String,
+
+        /// New display name
+        name: String,
+    },
+
+    /// Move a column to a new position
+    Reorder {
+        /// Column ID
+        id: String,
+
+        /// New position (0 = leftmost)
+        position: usize,
+    },
+
+    /// Set or clear the WIP limit of a column
+    Limit {
+        /// Column ID
+        id: String,
+
+        /// Most cards the column should hold
+        #[arg(
+            required_unless_present = "clear",
+            value_parser = clap::value_parser!(u32).range(1..)
+        )]
+        limit: Option<u32>,
+
+        /// Remove the limit
+        #[arg(long, conflicts_with = "limit")]
+        clear: bool,
+    },
+
+    /// Set whether moves may exceed WIP limits (warn or block)
+    Policy {
+        /// Policy: warn (default) or block
+        policy: WipPolicy,
+    },
+
+    /// List all columns
+    List,
+}
+
+impl ColumnCommands {
+    /// Returns the command name reported in machine-readable output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColumnCommands::Add { .. } => "column add",
+            ColumnCommands::Remove { .. } => "column remove",
+            ColumnCommands::Rename { .. } => "column rename",
+            ColumnCommands::Reorder { .. } => "column reorder",
+            ColumnCommands::Limit { .. } => "column limit",
+            ColumnCommands::Policy { .. } => "column policy",
+            ColumnCommands::List => "column list",
+        }
+    }
+}
+
+/// Checklist subcommands
+///
+/// Items are numbered from 1, in the order `clicky show` lists them.
+#[derive(Subcommand)]
+pub enum ChecklistCommands {
+    /// Append an item to the checklist
+    Add {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        /// Item text
+        text: String,
+    },
+
+    /// Mark an item as done
+    Check {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        /// Item number
+        item: NonZeroUsize,
+    },
+
+    /// Mark an item as not done
+    Uncheck {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        /// Item number
+        item: NonZeroUsize,
+    },
+
+    /// Move an item to a new position
+    Move {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        /// Item number
+        item: NonZeroUsize,
+
+        /// New item number (past the end moves the item to the end)
+        to: NonZeroUsize,
+    },
+
+    /// Remove an item
+    Remove {
+        /// Card ID (e.g., PRJ-001)
+        card_id: String,
+
+        /// Item number
+        item: NonZeroUsize,
+    },
+}
+
+impl ChecklistCommands {
+    /// Returns the command name reported in machine-readable output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChecklistCommands::Add { .. } => "checklist add",
+            ChecklistCommands::Check { .. } => "checklist check",
+            ChecklistCommands::Uncheck { .. } => "checklist uncheck",
+            ChecklistCommands::Move { .. } => "checklist move",
+            ChecklistCommands::Remove { .. } => "checklist remove",
+        }
+    }
+}
+
+/// Storage management subcommands
+#[derive(Subcommand)]
+pub enum StorageCommands {
+    /// Move the board to another storage backend, keeping all data
+    Convert {
+        /// Backend to convert to (json, sqlite or directory)
+        #[arg(long, value_name = "BACKEND")]
+        to: StorageBackend,
+    },
+}
+
+impl StorageCommands {
+    /// Returns the command name reported in machine-readable output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StorageCommands::Convert { .. } => "storage convert",
+        }
+    }
+}
+
+/// Snapshot subcommands
+#[derive(Subcommand)]
+pub enum BackupCommands {
+    /// List the snapshots, newest first
+    List,
+
+    /// Replace the board with a snapshot
+    Restore {
+        /// Snapshot name, or a unique prefix of it
+        snapshot: String,
+    },
+
+    /// Show how the current board differs from a snapshot
+    Diff {
+        /// Snapshot name, or a unique prefix of it
+        snapshot: String,
+    },
+}
+
+impl BackupCommands {
+    /// Returns the command name reported in machine-readable output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BackupCommands::List => "backup list",
+            BackupCommands::Restore { .. } => "backup restore",
+            BackupCommands::Diff { .. } => "backup diff",
+        }
+    }
+}
+
+/// Git integration subcommands
+#[derive(Subcommand)]
+pub enum GitCommands {
+    /// Let git merge the board file with `clicky merge-driver`
+    InstallMergeDriver,
+}
+
+impl GitCommands {
+    /// Returns the command name reported in machine-readable output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            GitCommands::InstallMergeDriver => "git install-merge-driver",
+        }
+    }
+}
+
+/// Label management subcommands
+#[derive(Subcommand)]
+pub enum LabelCommands {
+    /// List all labels with their colors and usage counts
+    List,
+
+    /// Rename a label on every card (merges into an existing label)
+    Rename {
+        /// Current label name
+        name: String,
+
+        /// New label name
+        new_name: String,
+    },
+
+    /// Remove a label from every card and from the registry
+    Delete {
+        /// Label name
+        name: String,
+    },
+
+    /// Set the display color of a label (omit the color to clear it)
+    Color {
+        /// Label name
+        name: String,
+
+        /// Color name (e.g., "red", "lightblue") or #rrggbb
+        color: Option<String>,
+    },
+}
+
+impl LabelCommands {
+    /// Returns the command name reported in machine-readable output.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LabelCommands::List => "labels list",
+            LabelCommands::Rename { .. } => "labels rename",
+            LabelCommands::Delete { .. } => "labels delete",
+            LabelCommands::Color { .. } => "labels color",
+        }
+    }
+}
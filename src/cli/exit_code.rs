@@ -0,0 +1,77 @@
+//! Process exit codes and their mapping from the error codes of the JSON
+//! output.
+
+#![allow(dead_code)]
+
+pub const SUCCESS: i32 = 0;
+pub const GENERAL_ERROR: i32 = 1;
+pub const BOARD_NOT_FOUND: i32 = 2;
+pub const INVALID_INPUT: i32 = 3;
+pub const LOCK_TIMEOUT: i32 = 4;
+pub const CONFLICT: i32 = 5;
+pub const MERGE_CONFLICT: i32 = 6;
+pub const USAGE_ERROR: i32 = 7;
+
+/// Maps an error code from the JSON output to a process exit code.
+///
+/// Codes that are not listed here exit with `GENERAL_ERROR`, like every
+/// failure did before error codes existed.
+pub fn from_error_code(code: &str) -> i32 {
+    match code {
+        "general_error"
+        | "io_error"
+        | "serialization_error"
+        | "database_error"
+        | "git_error"
+        | "inconsistent_board"
+        | "unsupported_schema_version" => GENERAL_ERROR,
+        "board_not_found" => BOARD_NOT_FOUND,
+        "already_archived"
+        | "already_initialized"
+        | "ambiguous_snapshot"
+        | "board_already_exists"
+        | "card_blocked"
+        | "card_claimed"
+        | "card_has_children"
+        | "card_not_found"
+        | "checklist_incomplete"
+        | "checklist_item_not_found"
+        | "column_already_exists"
+        | "column_not_empty"
+        | "column_not_found"
+        | "confirmation_required"
+        | "dependency_cycle"
+        | "invalid_board_data"
+        | "invalid_board_name"
+        | "invalid_card_data"
+        | "invalid_color"
+        | "invalid_column_id"
+        | "invalid_column_name"
+        | "invalid_destination"
+        | "invalid_label"
+        | "invalid_link"
+        | "invalid_parent"
+        | "invalid_position"
+        | "label_not_found"
+        | "last_column"
+        | "no_card_available"
+        | "not_archived"
+        | "not_claimed"
+        | "not_linked"
+        | "nothing_to_redo"
+        | "nothing_to_undo"
+        | "parent_cycle"
+        | "snapshot_not_found"
+        | "undo_conflict"
+        | "wip_limit_exceeded" => INVALID_INPUT,
+        "lock_timeout" => LOCK_TIMEOUT,
+        "conflict" => CONFLICT,
+        "merge_conflict" => MERGE_CONFLICT,
+        "usage_error" => USAGE_ERROR,
+        _ => GENERAL_ERROR,
+    }
+}
+
+#[cfg(test)]
+#[path = "./exit_code_tests.rs"]
+mod exit_code_tests;
@@ -0,0 +1,20 @@
+use super::*;
+
+#[test]
+fn test_mapped_error_codes() {
+    assert_eq!(from_error_code("board_not_found"), BOARD_NOT_FOUND);
+    assert_eq!(from_error_code("card_not_found"), INVALID_INPUT);
+    assert_eq!(from_error_code("wip_limit_exceeded"), INVALID_INPUT);
+    assert_eq!(from_error_code("lock_timeout"), LOCK_TIMEOUT);
+    assert_eq!(from_error_code("conflict"), CONFLICT);
+    assert_eq!(from_error_code("merge_conflict"), MERGE_CONFLICT);
+    assert_eq!(from_error_code("usage_error"), USAGE_ERROR);
+}
+
+#[test]
+fn test_unknown_error_codes_are_general_errors() {
+    assert_eq!(from_error_code("general_error"), GENERAL_ERROR);
+    assert_eq!(from_error_code("io_error"), GENERAL_ERROR);
+    assert_eq!(from_error_code("inconsistent_board"), GENERAL_ERROR);
+    assert_eq!(from_error_code("something_new"), GENERAL_ERROR);
+}
@@ -3,10 +3,12 @@
 use std::path::Path;
 
 use anyhow::Result;
+use chrono::Local;
 #[cfg(feature = "interactive")]
-use inquire::{required, Select, Text};
+use inquire::{required, validator::Validation, Select, Text};
 
-use crate::application::CardService;
+use crate::application::{CardService, NewCard};
+use crate::domain::{parse_due_date, Priority};
 
 /// Run interactive prompts for creating a new card.
 #[cfg(feature = "interactive")]
@@ -46,6 +48,45 @@ pub fn run_interactive_create(base_path: &Path) -> Result<()> {
         Some(assignee)
     };
 
+    // Select priority (optional)
+    let priority_options = vec!["none", "critical", "high", "medium", "low"];
+    let selected_priority = Select::new("Priority:", priority_options)
+        .with_starting_cursor(0)
+        .prompt()?;
+    let priority = selected_priority.parse::<Priority>().ok();
+
+    // Get labels (optional)
+    let labels = Text::new("Labels (optional, comma-separated):")
+        .with_placeholder("bug, frontend")
+        .prompt()?;
+
+    let labels: Vec<String> = labels
+        .split(',')
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+        .collect();
+
+    // Get due date (optional)
+    let today = Local::now().date_naive();
+    let due = Text::new("Due date (optional):")
+        .with_placeholder("2024-06-30, +3d, friday")
+        .with_validator(move |input: &str| {
+            if input.trim().is_empty() {
+                return Ok(Validation::Valid);
+            }
+            Ok(match parse_due_date(input, today) {
+                Ok(_) => Validation::Valid,
+                Err(e) => Validation::Invalid(e.into()),
+            })
+        })
+        .prompt()?;
+
+    let due = if due.trim().is_empty() {
+        None
+    } else {
+        Some(parse_due_date(&due, today).map_err(anyhow::Error::msg)?)
+    };
+
     // Select column
     let column_options: Vec<&str> = board.columns.iter().map(|c| c.id.as_str()).collect();
     let selected_column = Select::new("Column:", column_options)
@@ -55,10 +96,16 @@ pub fn run_interactive_create(base_path: &Path) -> Result<()> {
     // Create the card
     let result = service.create(
         base_path,
-        title,
-        description,
-        assignee,
-        Some(selected_column.to_string()),
+        NewCard {
+            title,
+            description,
+            assignee,
+            column_id: Some(selected_column.to_string()),
+            priority,
+            labels,
+            due,
+            parent: None,
+        },
     )?;
 
     println!("\n✓ Created card {}", result.card_id);
@@ -55,8 +55,8 @@ pub fn run_interactive_init(base_path: &Path) -> Result<()> {
             "Simple (To Do, Done)" => {
                 let mut board = service.initialize(base_path, Some(board_name))?;
                 // Remove in_progress column
-                board.remove_column("in_progress");
-                service.save(&board, base_path)?;
+                board.remove_column("in_progress", None);
+                service.save(&mut board, base_path)?;
                 board
             }
             "Development (Backlog, In Progress, Review, Done)" => {
@@ -64,9 +64,9 @@ pub fn run_interactive_init(base_path: &Path) -> Result<()> {
                 // Add backlog and review columns
                 board.add_column("backlog".to_string(), "Backlog".to_string(), 0);
                 board.add_column("review".to_string(), "Review".to_string(), 2);
-                board.remove_column("todo");
+                board.remove_column("todo", None);
                 board.columns.sort_by_key(|c| c.order);
-                service.save(&board, base_path)?;
+                service.save(&mut board, base_path)?;
                 board
             }
             "Custom" => {
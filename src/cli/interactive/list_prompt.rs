@@ -68,7 +68,8 @@ pub fn run_interactive_list(base_path: &Path) -> Result<()> {
 
     // Display results
     println!("\nBoard: {} ({})", board.name, board.id);
-    println!("Total cards: {}\n", board.cards.len());
+    let total = board.cards.iter().filter(|c| !c.is_archived()).count();
+    println!("Total cards: {}\n", total);
 
     for column in &board.columns {
         // Skip if column filter is specified and doesn't match
@@ -79,13 +80,12 @@ pub fn run_interactive_list(base_path: &Path) -> Result<()> {
         }
 
         let cards_in_column: Vec<_> = board
-            .cards
-            .iter()
+            .get_cards_in_column(&column.id)
+            .into_iter()
             .filter(|c| {
-                c.column_id == column.id
-                    && assignee_filter
-                        .as_ref()
-                        .is_none_or(|a| c.assignee.as_ref() == Some(a))
+                assignee_filter
+                    .as_ref()
+                    .is_none_or(|a| c.assignee.as_ref() == Some(a))
             })
             .collect();
 
@@ -101,7 +101,14 @@ pub fn run_interactive_list(base_path: &Path) -> Result<()> {
                     .as_ref()
                     .map(|a| format!(" [@{}]", a))
                     .unwrap_or_default();
-                println!("  {}: {}{}", card.id, card.title, assignee_str);
+                let priority_str = card
+                    .priority
+                    .map(|p| format!(" ({})", p))
+                    .unwrap_or_default();
+                println!(
+                    "  {}: {}{}{}",
+                    card.id, card.title, priority_str, assignee_str
+                );
             }
             println!();
         }
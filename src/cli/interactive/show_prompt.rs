@@ -60,6 +60,16 @@ pub fn run_interactive_show(base_path: &Path) -> Result<()> {
     if let Some(ref assignee) = card.assignee {
         println!("  Assignee:    {}", assignee);
     }
+    if let Some(priority) = card.priority {
+        println!("  Priority:    {}", priority);
+    }
+    if !card.labels.is_empty() {
+        let labels: Vec<&str> = card.labels.iter().map(String::as_str).collect();
+        println!("  Labels:      {}", labels.join(", "));
+    }
+    if let Some(due) = card.due {
+        println!("  Due:         {}", due);
+    }
     println!(
         "  Created:     {}",
         card.created_at.format("%Y-%m-%d %H:%M")
@@ -69,6 +79,18 @@ pub fn run_interactive_show(base_path: &Path) -> Result<()> {
         card.updated_at.format("%Y-%m-%d %H:%M")
     );
 
+    if !card.comments.is_empty() {
+        println!("\nComments ({}):", card.comments.len());
+        for comment in &card.comments {
+            println!(
+                "  [{}] {}: {}",
+                comment.created_at.format("%Y-%m-%d %H:%M"),
+                comment.author,
+                comment.body
+            );
+        }
+    }
+
     Ok(())
 }
 
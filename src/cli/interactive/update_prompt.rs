@@ -3,10 +3,12 @@
 use std::path::Path;
 
 use anyhow::Result;
+use chrono::{Local, NaiveDate};
 #[cfg(feature = "interactive")]
-use inquire::{Confirm, Select, Text};
+use inquire::{validator::Validation, Confirm, Select, Text};
 
-use crate::application::CardService;
+use crate::application::{CardService, CardUpdate};
+use crate::domain::{parse_due_date, Priority};
 
 /// Run interactive prompts for updating a card.
 #[cfg(feature = "interactive")]
@@ -50,6 +52,9 @@ pub fn run_interactive_update(base_path: &Path) -> Result<()> {
     let mut title: Option<String> = None;
     let mut description: Option<Option<String>> = None;
     let mut assignee: Option<Option<String>> = None;
+    let mut priority: Option<Option<Priority>> = None;
+    let mut labels: Option<Vec<String>> = None;
+    let mut due: Option<Option<NaiveDate>> = None;
 
     let update_title = Confirm::new("Update title?").with_default(false).prompt()?;
     if update_title {
@@ -111,14 +116,87 @@ pub fn run_interactive_update(base_path: &Path) -> Result<()> {
         }
     }
 
+    let update_priority = Confirm::new("Update priority?")
+        .with_default(false)
+        .prompt()?;
+    if update_priority {
+        let priority_options = vec!["none", "critical", "high", "medium", "low"];
+        let current = card.priority.map(|p| p.as_str()).unwrap_or("none");
+        let starting_cursor = priority_options
+            .iter()
+            .position(|p| *p == current)
+            .unwrap_or(0);
+        let selected = Select::new("New priority:", priority_options)
+            .with_starting_cursor(starting_cursor)
+            .prompt()?;
+        priority = Some(selected.parse::<Priority>().ok());
+    }
+
+    let update_labels = Confirm::new("Update labels?")
+        .with_default(false)
+        .prompt()?;
+    if update_labels {
+        let current: Vec<&str> = card.labels.iter().map(String::as_str).collect();
+        let new_labels = Text::new("Labels (comma-separated):")
+            .with_default(&current.join(", "))
+            .prompt()?;
+        labels = Some(
+            new_labels
+                .split(',')
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty())
+                .collect(),
+        );
+    }
+
+    let update_due = Confirm::new("Update due date?")
+        .with_default(false)
+        .prompt()?;
+    if update_due {
+        let today = Local::now().date_naive();
+        let current = card.due.map(|d| d.to_string()).unwrap_or_default();
+        let new_due = Text::new("Due date (empty to clear):")
+            .with_default(&current)
+            .with_validator(move |input: &str| {
+                if input.trim().is_empty() {
+                    return Ok(Validation::Valid);
+                }
+                Ok(match parse_due_date(input, today) {
+                    Ok(_) => Validation::Valid,
+                    Err(e) => Validation::Invalid(e.into()),
+                })
+            })
+            .prompt()?;
+        due = Some(if new_due.trim().is_empty() {
+            None
+        } else {
+            Some(parse_due_date(&new_due, today).map_err(anyhow::Error::msg)?)
+        });
+    }
+
     // Check if any changes were made
-    if title.is_none() && description.is_none() && assignee.is_none() {
+    if title.is_none()
+        && description.is_none()
+        && assignee.is_none()
+        && priority.is_none()
+        && labels.is_none()
+        && due.is_none()
+    {
         println!("No changes made.");
         return Ok(());
     }
 
     // Update the card
-    let updated_board = service.update(base_path, &card_id, title, description, assignee)?;
+    let changes = CardUpdate {
+        title,
+        description,
+        assignee,
+        priority,
+        due,
+        labels,
+        ..Default::default()
+    };
+    let updated_board = service.update(base_path, &card_id, changes)?;
 
     let updated_card = updated_board.get_card(&card_id).unwrap();
 
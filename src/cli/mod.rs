@@ -1,8 +1,13 @@
 //! CLI layer for parsing command-line arguments and handling user input.
 
 pub mod commands;
+pub mod exit_code;
 pub mod interactive;
+pub mod output;
 #[cfg(feature = "tui")]
 pub mod tui;
 
-pub use commands::{Cli, Commands};
+pub use commands::{
+    BackupCommands, CardPositionArgs, ChecklistCommands, Cli, ColumnCommands, Commands,
+    GitCommands, LabelCommands, StorageCommands,
+};
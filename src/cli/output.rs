@@ -0,0 +1,284 @@
+//! Machine-readable output for agents and scripts.
+//!
+//! Every command can report its result as a versioned JSON document
+//! (`--output json`) or as newline-delimited JSON (`--output ndjson`),
+//! where collections are streamed one item per line.
+
+use std::error::Error;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::application::board_service::BoardServiceError;
+use crate::application::card_service::CardServiceError;
+use crate::application::column_service::ColumnServiceError;
+use crate::application::label_service::LabelServiceError;
+use crate::domain::{Board, Column, WipPolicy};
+use crate::infrastructure::storage::StorageError;
+
+/// Version of the JSON output format.
+///
+/// Incremented whenever a field is removed or changes meaning.
+/// Adding fields is not considered a breaking change.
+pub const OUTPUT_VERSION: u32 = 1;
+
+/// Output format selected with `--output`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// A single JSON document
+    Json,
+    /// Newline-delimited JSON, one document per item
+    Ndjson,
+}
+
+impl OutputFormat {
+    /// Returns true for the human-readable format.
+    pub fn is_text(self) -> bool {
+        self == OutputFormat::Text
+    }
+
+    /// Finds the format asked for with `--output` in raw command-line
+    /// arguments, for errors raised before they could be parsed.
+    ///
+    /// Falls back to text if the option is missing or its value unknown.
+    pub fn requested<I: IntoIterator<Item = String>>(args: I) -> Self {
+        let mut args = args.into_iter().skip(1);
+        let mut format = Self::Text;
+        while let Some(arg) = args.next() {
+            let value = match arg.as_str() {
+                "--" => break,
+                "--output" | "-o" => args.next(),
+                _ => arg
+                    .strip_prefix("--output=")
+                    .or_else(|| arg.strip_prefix("-o"))
+                    .map(str::to_string),
+            };
+            if let Some(parsed) = value.and_then(|v| Self::from_str(&v, true).ok()) {
+                format = parsed;
+            }
+        }
+        format
+    }
+}
+
+/// Envelope wrapped around every JSON document.
+#[derive(Serialize)]
+struct Envelope<'a, T: Serialize> {
+    version: u32,
+    command: &'a str,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<&'a T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<ErrorBody<'a>>,
+}
+
+/// Error details reported in JSON output.
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    code: &'a str,
+    message: String,
+}
+
+/// Summary of a board as reported by `info` and `init`.
+#[derive(Serialize)]
+pub struct BoardSummary {
+    pub id: String,
+    pub name: String,
+    pub card_id_prefix: String,
+    pub revision: u64,
+    /// Cards on the board, leaving out archived cards
+    pub total_cards: usize,
+    pub archived_cards: usize,
+    pub wip_policy: WipPolicy,
+    pub columns: Vec<ColumnSummary>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Board> for BoardSummary {
+    fn from(board: &Board) -> Self {
+        let archived_cards = board.archived_cards().len();
+        Self {
+            id: board.id.clone(),
+            name: board.name.clone(),
+            card_id_prefix: board.card_id_prefix.clone(),
+            revision: board.revision,
+            total_cards: board.cards.len() - archived_cards,
+            archived_cards,
+            wip_policy: board.wip_policy,
+            columns: board
+                .columns
+                .iter()
+                .map(|c| ColumnSummary::new(board, c))
+                .collect(),
+            created_at: board.created_at,
+            updated_at: board.updated_at,
+        }
+    }
+}
+
+/// Summary of a column with its card count.
+#[derive(Serialize)]
+pub struct ColumnSummary {
+    pub id: String,
+    pub name: String,
+    pub order: u32,
+    pub card_count: usize,
+    pub wip_limit: Option<u32>,
+    pub over_wip_limit: bool,
+}
+
+impl ColumnSummary {
+    /// Builds a summary for a column of the given board.
+    pub fn new(board: &Board, column: &Column) -> Self {
+        Self {
+            id: column.id.clone(),
+            name: column.name.clone(),
+            order: column.order,
+            card_count: board.get_cards_in_column(&column.id).len(),
+            wip_limit: column.wip_limit,
+            over_wip_limit: board.check_wip_limit(&column.id).is_err(),
+        }
+    }
+}
+
+/// Summary of a label with its usage count.
+#[derive(Serialize)]
+pub struct LabelSummary {
+    pub name: String,
+    pub color: Option<String>,
+    pub card_count: usize,
+}
+
+impl LabelSummary {
+    /// Builds a summary for a label of the given board.
+    pub fn new(board: &Board, name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            color: board.get_label(name).and_then(|l| l.color.clone()),
+            card_count: board.count_cards_with_label(name),
+        }
+    }
+}
+
+/// Error raised by the CLI layer itself, carrying a stable error code.
+#[derive(Debug)]
+pub struct CliError {
+    code: &'static str,
+    message: String,
+}
+
+impl CliError {
+    /// Creates a new CLI error with the given code and message.
+    pub fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for CliError {}
+
+/// Prints a single result document.
+pub fn print_data<T: Serialize>(
+    format: OutputFormat,
+    command: &str,
+    data: &T,
+) -> Result<(), serde_json::Error> {
+    print_envelope(
+        format,
+        &Envelope {
+            version: OUTPUT_VERSION,
+            command,
+            ok: true,
+            data: Some(data),
+            error: None,
+        },
+    )
+}
+
+/// Prints a collection of results.
+///
+/// JSON output reports the items as an array; NDJSON output prints one
+/// document per item.
+pub fn print_items<T: Serialize>(
+    format: OutputFormat,
+    command: &str,
+    items: &[T],
+) -> Result<(), serde_json::Error> {
+    match format {
+        OutputFormat::Ndjson => {
+            for item in items {
+                print_data(format, command, item)?;
+            }
+            Ok(())
+        }
+        _ => print_data(format, command, &items),
+    }
+}
+
+/// Prints an error document to stdout.
+pub fn print_error(format: OutputFormat, command: &str, err: &(dyn Error + 'static)) {
+    let envelope: Envelope<'_, ()> = Envelope {
+        version: OUTPUT_VERSION,
+        command,
+        ok: false,
+        data: None,
+        error: Some(ErrorBody {
+            code: error_code(err),
+            message: err.to_string(),
+        }),
+    };
+
+    if print_envelope(format, &envelope).is_err() {
+        eprintln!("Error: {}", err);
+    }
+}
+
+/// Returns the stable error code for an error.
+pub fn error_code(err: &(dyn Error + 'static)) -> &'static str {
+    if let Some(e) = err.downcast_ref::<CliError>() {
+        e.code
+    } else if let Some(e) = err.downcast_ref::<CardServiceError>() {
+        e.code()
+    } else if let Some(e) = err.downcast_ref::<ColumnServiceError>() {
+        e.code()
+    } else if let Some(e) = err.downcast_ref::<LabelServiceError>() {
+        e.code()
+    } else if let Some(e) = err.downcast_ref::<BoardServiceError>() {
+        e.code()
+    } else if let Some(e) = err.downcast_ref::<StorageError>() {
+        e.code()
+    } else {
+        "general_error"
+    }
+}
+
+fn print_envelope<T: Serialize>(
+    format: OutputFormat,
+    envelope: &Envelope<'_, T>,
+) -> Result<(), serde_json::Error> {
+    let content = match format {
+        OutputFormat::Ndjson => serde_json::to_string(envelope)?,
+        _ => serde_json::to_string_pretty(envelope)?,
+    };
+    println!("{}", content);
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "./output_tests.rs"]
+mod output_tests;
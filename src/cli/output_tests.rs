@@ -0,0 +1,92 @@
+use super::*;
+
+#[test]
+fn test_error_code_for_service_errors() {
+    let card_error: Box<dyn Error> = CardServiceError::CardNotFound("PRJ-001".to_string()).into();
+    assert_eq!(error_code(card_error.as_ref()), "card_not_found");
+
+    let nested: Box<dyn Error> =
+        CardServiceError::BoardService(BoardServiceError::BoardNotFound).into();
+    assert_eq!(error_code(nested.as_ref()), "board_not_found");
+
+    let column_error: Box<dyn Error> = ColumnServiceError::LastColumn.into();
+    assert_eq!(error_code(column_error.as_ref()), "last_column");
+
+    let label_error: Box<dyn Error> = LabelServiceError::LabelNotFound("bug".to_string()).into();
+    assert_eq!(error_code(label_error.as_ref()), "label_not_found");
+}
+
+#[test]
+fn test_error_code_for_cli_and_plain_errors() {
+    let cli_error: Box<dyn Error> = CliError::new("confirmation_required", "need --force").into();
+    assert_eq!(error_code(cli_error.as_ref()), "confirmation_required");
+    assert_eq!(cli_error.to_string(), "need --force");
+
+    let plain: Box<dyn Error> = "something went wrong".into();
+    assert_eq!(error_code(plain.as_ref()), "general_error");
+}
+
+#[test]
+fn test_board_summary() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    board.create_card("Task".to_string(), None, None, None);
+
+    // Act
+    let summary = BoardSummary::from(&board);
+
+    // Assert
+    assert_eq!(summary.total_cards, 1);
+    assert_eq!(summary.columns.len(), 3);
+    assert_eq!(summary.columns[0].card_count, 1);
+    assert_eq!(summary.columns[1].card_count, 0);
+}
+
+#[test]
+fn test_envelope_serialization() {
+    let envelope = Envelope {
+        version: OUTPUT_VERSION,
+        command: "show",
+        ok: true,
+        data: Some(&serde_json::json!({ "id": "PRJ-001" })),
+        error: None,
+    };
+
+    let value = serde_json::to_value(&envelope).unwrap();
+
+    assert_eq!(value["version"], 1);
+    assert_eq!(value["command"], "show");
+    assert_eq!(value["ok"], true);
+    assert_eq!(value["data"]["id"], "PRJ-001");
+    assert!(value.get("error").is_none());
+}
+
+#[test]
+fn test_requested_format_from_raw_arguments() {
+    let args = |line: &str| {
+        line.split_whitespace()
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        OutputFormat::requested(args("clicky list --output json --bogus")),
+        OutputFormat::Json
+    );
+    assert_eq!(
+        OutputFormat::requested(args("clicky -ondjson frob")),
+        OutputFormat::Ndjson
+    );
+    assert_eq!(
+        OutputFormat::requested(args("clicky --output=json list")),
+        OutputFormat::Json
+    );
+    assert_eq!(
+        OutputFormat::requested(args("clicky list --output xml")),
+        OutputFormat::Text
+    );
+    assert_eq!(
+        OutputFormat::requested(args("clicky create -- -o json")),
+        OutputFormat::Text
+    );
+}
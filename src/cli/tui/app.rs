@@ -1,8 +1,10 @@
 //! TUI application state.
 
-use crate::application::{BoardService, CardService};
+use crate::application::actor::current_actor;
+use crate::application::card_service::CardServiceError;
+use crate::application::{BoardService, BoardServiceError, CardService, ChecklistEdit, NewCard};
 use crate::cli::tui::state::{AppState, CardFormData, Focus, FormField, InputMode};
-use crate::domain::Board;
+use crate::domain::{Board, CardPosition, Priority};
 use std::path::PathBuf;
 
 /// Main TUI application.
@@ -45,6 +47,14 @@ pub struct App {
     pub editing_card_id: Option<String>,
     /// Whether a card is selected (first Enter pressed) but not showing details
     pub card_selected: bool,
+    /// Scroll offset of the comment pane in the card detail view
+    pub comment_scroll: u16,
+    /// Text of the comment being written
+    pub comment_input: String,
+    /// Selected checklist item index in the card detail view
+    pub checklist_selected: usize,
+    /// Selected card index in the archive view
+    pub archive_selected: usize,
 }
 
 impl App {
@@ -68,6 +78,10 @@ impl App {
             form_data: CardFormData::default(),
             editing_card_id: None,
             card_selected: false,
+            comment_scroll: 0,
+            comment_input: String::new(),
+            checklist_selected: 0,
+            archive_selected: 0,
         }
     }
 
@@ -81,9 +95,12 @@ impl App {
     pub fn load_board(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let board_service = BoardService::new();
         if board_service.exists(&self.board_path) {
-            let board = board_service.load(&self.board_path)?;
+            // Warn instead of printing on stderr, which would garble the screen.
+            let board = board_service.load_unchecked(&self.board_path)?;
+            self.error_message = BoardService::check_consistency(&board)
+                .err()
+                .map(|e| e.to_string());
             self.board = Some(board);
-            self.error_message = None;
         } else {
             self.error_message =
                 Some("No board found. Run 'clicky init' to create one.".to_string());
@@ -94,11 +111,7 @@ impl App {
     pub fn selected_card_id(&self) -> Option<String> {
         if let (Some(board), Some(card_idx)) = (&self.board, self.selected_card) {
             let column = board.columns.get(self.selected_column)?;
-            let cards_in_column: Vec<_> = board
-                .cards
-                .iter()
-                .filter(|c| c.column_id == column.id)
-                .collect();
+            let cards_in_column: Vec<_> = board.get_cards_in_column(&column.id);
 
             if let Some(card) = cards_in_column.get(card_idx) {
                 return Some(card.id.clone());
@@ -110,11 +123,7 @@ impl App {
     fn update_selected_card_id_from_index(&mut self, card_idx: usize) {
         if let Some(board) = &self.board {
             if let Some(column) = board.columns.get(self.selected_column) {
-                let cards_in_column: Vec<_> = board
-                    .cards
-                    .iter()
-                    .filter(|c| c.column_id == column.id)
-                    .collect();
+                let cards_in_column: Vec<_> = board.get_cards_in_column(&column.id);
                 if let Some(card) = cards_in_column.get(card_idx) {
                     self.selected_card_id = Some(card.id.clone());
                 }
@@ -125,11 +134,7 @@ impl App {
     pub fn get_selected_card_index(&self) -> Option<usize> {
         if let (Some(board), Some(card_id)) = (&self.board, &self.selected_card_id) {
             if let Some(column) = board.columns.get(self.selected_column) {
-                let cards_in_column: Vec<_> = board
-                    .cards
-                    .iter()
-                    .filter(|c| c.column_id == column.id)
-                    .collect();
+                let cards_in_column: Vec<_> = board.get_cards_in_column(&column.id);
                 for (i, card) in cards_in_column.iter().enumerate() {
                     if card.id == *card_id {
                         return Some(i);
@@ -175,11 +180,7 @@ impl App {
         if self.focus == Focus::Columns {
             if let Some(board) = &self.board {
                 if let Some(column) = board.columns.get(self.selected_column) {
-                    let card_count = board
-                        .cards
-                        .iter()
-                        .filter(|c| c.column_id == column.id)
-                        .count();
+                    let card_count = board.get_cards_in_column(&column.id).len();
 
                     if card_count > 0 {
                         let new_idx = if let Some(current) = self.pre_selected_card {
@@ -203,11 +204,7 @@ impl App {
             }
         } else if let Some(board) = &self.board {
             if let Some(column) = board.columns.get(self.selected_column) {
-                let card_count = board
-                    .cards
-                    .iter()
-                    .filter(|c| c.column_id == column.id)
-                    .count();
+                let card_count = board.get_cards_in_column(&column.id).len();
                 if card_count > 0 {
                     let new_idx = card_count - 1;
                     self.selected_card = Some(new_idx);
@@ -222,11 +219,7 @@ impl App {
         if self.focus == Focus::Columns {
             if let Some(board) = &self.board {
                 if let Some(column) = board.columns.get(self.selected_column) {
-                    let card_count = board
-                        .cards
-                        .iter()
-                        .filter(|c| c.column_id == column.id)
-                        .count();
+                    let card_count = board.get_cards_in_column(&column.id).len();
 
                     if card_count > 0 {
                         let new_idx = if let Some(current) = self.pre_selected_card {
@@ -244,11 +237,7 @@ impl App {
             }
         } else if let Some(board) = &self.board {
             if let Some(column) = board.columns.get(self.selected_column) {
-                let card_count = board
-                    .cards
-                    .iter()
-                    .filter(|c| c.column_id == column.id)
-                    .count();
+                let card_count = board.get_cards_in_column(&column.id).len();
                 let current_idx = self.get_selected_card_index().unwrap_or(0);
 
                 if current_idx < card_count.saturating_sub(1) {
@@ -284,16 +273,111 @@ impl App {
             self.enter_cards();
         }
         self.state = AppState::CardDetail;
+        self.comment_scroll = 0;
+        self.checklist_selected = 0;
+    }
+
+    pub fn scroll_comments_down(&mut self) {
+        let max_scroll = self
+            .selected_card_id()
+            .and_then(|id| self.board.as_ref()?.get_card(&id).cloned())
+            .map(|card| {
+                card.comments
+                    .iter()
+                    .map(|c| c.body.lines().count() + 2)
+                    .sum::<usize>()
+            })
+            .unwrap_or(0);
+        if usize::from(self.comment_scroll) + 1 < max_scroll {
+            self.comment_scroll += 1;
+        }
+    }
+
+    pub fn scroll_comments_up(&mut self) {
+        self.comment_scroll = self.comment_scroll.saturating_sub(1);
+    }
+
+    /// Gets the number of checklist items of the selected card.
+    fn checklist_len(&self) -> usize {
+        self.selected_card_id()
+            .and_then(|id| {
+                self.board
+                    .as_ref()?
+                    .get_card(&id)
+                    .map(|c| c.checklist.len())
+            })
+            .unwrap_or(0)
+    }
+
+    pub fn select_next_checklist_item(&mut self) {
+        if self.checklist_selected + 1 < self.checklist_len() {
+            self.checklist_selected += 1;
+        }
+    }
+
+    pub fn select_previous_checklist_item(&mut self) {
+        self.checklist_selected = self.checklist_selected.saturating_sub(1);
+    }
+
+    /// Checks or unchecks the selected checklist item and reloads the board.
+    pub fn toggle_checklist_item(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let card_id = self.selected_card_id().ok_or("No card selected")?;
+        let done = self
+            .board
+            .as_ref()
+            .and_then(|b| b.get_card(&card_id))
+            .and_then(|c| c.checklist.get(self.checklist_selected))
+            .map(|item| item.done)
+            .ok_or("The card has no checklist")?;
+
+        let item = self.checklist_selected + 1;
+        let edit = if done {
+            ChecklistEdit::Uncheck(item)
+        } else {
+            ChecklistEdit::Check(item)
+        };
+        let board_path = self.board_path.clone();
+        self.change_cards(|service| service.edit_checklist(&board_path, &card_id, &edit))?;
+        self.load_board()?;
+        Ok(())
+    }
+
+    pub fn start_add_comment(&mut self) {
+        if self.selected_card_id().is_some() {
+            self.comment_input.clear();
+            self.state = AppState::AddComment;
+        }
+    }
+
+    pub fn submit_comment(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.comment_input.trim().is_empty() {
+            self.error_message = Some("Comment cannot be empty".to_string());
+            return Ok(());
+        }
+
+        let card_id = self.selected_card_id().ok_or("No card selected")?;
+        let board_path = self.board_path.clone();
+        let body = self.comment_input.clone();
+        self.change_cards(|service| {
+            service.add_comment(&board_path, &card_id, &current_actor(), &body)
+        })?;
+
+        self.load_board()?;
+        self.comment_input.clear();
+        self.state = AppState::CardDetail;
+        Ok(())
+    }
+
+    pub fn cancel_comment(&mut self) {
+        self.comment_input.clear();
+        self.state = AppState::CardDetail;
+        self.clear_error();
     }
 
     fn init_pre_selected_card(&mut self) {
         if let Some(board) = &self.board {
             if let Some(column) = board.columns.get(self.selected_column) {
-                let card_count = board
-                    .cards
-                    .iter()
-                    .filter(|c| c.column_id == column.id)
-                    .count();
+                let card_count = board.get_cards_in_column(&column.id).len();
                 if card_count > 0 {
                     self.pre_selected_card = Some(0);
                 }
@@ -323,15 +407,17 @@ impl App {
         self.form_field = match self.form_field {
             FormField::Title => FormField::Description,
             FormField::Description => FormField::Assignee,
-            FormField::Assignee => FormField::Title,
+            FormField::Assignee => FormField::Priority,
+            FormField::Priority => FormField::Title,
         };
     }
 
     pub fn prev_form_field(&mut self) {
         self.form_field = match self.form_field {
-            FormField::Title => FormField::Assignee,
+            FormField::Title => FormField::Priority,
             FormField::Description => FormField::Title,
             FormField::Assignee => FormField::Description,
+            FormField::Priority => FormField::Assignee,
         };
     }
 
@@ -340,6 +426,7 @@ impl App {
             FormField::Title => &mut self.form_data.title,
             FormField::Description => &mut self.form_data.description,
             FormField::Assignee => &mut self.form_data.assignee,
+            FormField::Priority => &mut self.form_data.priority,
         }
     }
 
@@ -362,16 +449,30 @@ impl App {
             Some(self.form_data.assignee.clone())
         };
 
+        let priority = if self.form_data.priority.trim().is_empty() {
+            None
+        } else {
+            match self.form_data.priority.parse::<Priority>() {
+                Ok(priority) => Some(priority),
+                Err(e) => {
+                    self.error_message = Some(e);
+                    return Ok(());
+                }
+            }
+        };
+
         let column_id = self.get_current_column().unwrap_or("todo").to_string();
 
-        let card_service = CardService::new();
-        card_service.create(
-            &self.board_path,
-            title.to_string(),
+        let new_card = NewCard {
+            title: title.to_string(),
             description,
             assignee,
-            Some(column_id),
-        )?;
+            column_id: Some(column_id),
+            priority,
+            ..Default::default()
+        };
+        let board_path = self.board_path.clone();
+        self.change_cards(|service| service.create(&board_path, new_card))?;
 
         self.load_board()?;
         self.clear_form();
@@ -424,8 +525,8 @@ impl App {
             .map(|c| c.id.clone())
             .ok_or("Invalid target column")?;
 
-        let card_service = CardService::new();
-        card_service.move_to(&self.board_path, &card_id, &target_column_id)?;
+        let board_path = self.board_path.clone();
+        self.change_cards(|service| service.move_to(&board_path, &card_id, &target_column_id))?;
 
         self.load_board()?;
         self.state = AppState::CardDetail;
@@ -475,8 +576,8 @@ impl App {
             .map(|c| c.id.clone())
             .ok_or("Invalid target column")?;
 
-        let card_service = CardService::new();
-        card_service.move_to(&self.board_path, &card_id, &target_column_id)?;
+        let board_path = self.board_path.clone();
+        self.change_cards(|service| service.move_to(&board_path, &card_id, &target_column_id))?;
         self.load_board()?;
 
         self.selected_column = target_column_idx;
@@ -488,6 +589,144 @@ impl App {
         Ok(())
     }
 
+    /// Moves the selected card one place up or down in its column.
+    pub fn rank_selected_card(&mut self, up: bool) -> Result<(), Box<dyn std::error::Error>> {
+        let card_id = self.selected_card_id().ok_or("No card selected")?;
+        let index = self.get_selected_card_index().ok_or("No card selected")?;
+        let neighbour = if up {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1)
+        };
+        let Some(neighbour) = neighbour.and_then(|i| self.card_id_in_column(i)) else {
+            return Ok(());
+        };
+        let position = if up {
+            CardPosition::Before(neighbour)
+        } else {
+            CardPosition::After(neighbour)
+        };
+
+        let board_path = self.board_path.clone();
+        self.change_cards(|service| service.rank(&board_path, &card_id, &position))?;
+        self.load_board()?;
+
+        self.selected_card_id = Some(card_id);
+        self.selected_card = self.get_selected_card_index();
+        Ok(())
+    }
+
+    /// Gets the ID of the card at `index` in the selected column.
+    fn card_id_in_column(&self, index: usize) -> Option<String> {
+        let board = self.board.as_ref()?;
+        let column = board.columns.get(self.selected_column)?;
+        let cards = board.get_cards_in_column(&column.id);
+        cards.get(index).map(|c| c.id.clone())
+    }
+
+    pub fn delete_card(&mut self, card_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let board_path = self.board_path.clone();
+        self.change_cards(|service| service.delete(&board_path, card_id))?;
+        Ok(())
+    }
+
+    /// Archives a card and reloads the board.
+    pub fn archive_card(&mut self, card_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let board_path = self.board_path.clone();
+        self.change_cards(|service| service.archive(&board_path, card_id))?;
+        self.load_board()?;
+        self.exit_cards();
+        self.state = AppState::Board;
+        Ok(())
+    }
+
+    /// Opens the view of archived cards.
+    pub fn open_archive(&mut self) {
+        self.archive_selected = 0;
+        self.state = AppState::Archive;
+        self.clear_error();
+    }
+
+    /// Returns from the archive view to the board.
+    pub fn close_archive(&mut self) {
+        self.state = AppState::Board;
+        self.clear_error();
+    }
+
+    /// Gets the ID of the card selected in the archive view.
+    pub fn selected_archived_card_id(&self) -> Option<String> {
+        let board = self.board.as_ref()?;
+        let archived = board.archived_cards();
+        archived.get(self.archive_selected).map(|c| c.id.clone())
+    }
+
+    pub fn archive_move_up(&mut self) {
+        self.archive_selected = self.archive_selected.saturating_sub(1);
+    }
+
+    pub fn archive_move_down(&mut self) {
+        let count = self.board.as_ref().map_or(0, |b| b.archived_cards().len());
+        if self.archive_selected + 1 < count {
+            self.archive_selected += 1;
+        }
+    }
+
+    /// Returns the card selected in the archive view to the board.
+    pub fn unarchive_selected(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let card_id = self
+            .selected_archived_card_id()
+            .ok_or("No archived card selected")?;
+        let board_path = self.board_path.clone();
+        self.change_cards(|service| service.unarchive(&board_path, &card_id))?;
+        self.load_board()?;
+
+        let count = self.board.as_ref().map_or(0, |b| b.archived_cards().len());
+        self.archive_selected = self.archive_selected.min(count.saturating_sub(1));
+        Ok(())
+    }
+
+    /// Undoes the latest card change and reloads the board.
+    pub fn undo(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let board_path = self.board_path.clone();
+        self.change_cards(|service| service.undo(&board_path, 1))?;
+        self.load_board()?;
+        self.exit_cards();
+        Ok(())
+    }
+
+    /// Redoes the latest undone card change and reloads the board.
+    pub fn redo(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let board_path = self.board_path.clone();
+        self.change_cards(|service| service.redo(&board_path, 1))?;
+        self.load_board()?;
+        self.exit_cards();
+        Ok(())
+    }
+
+    /// Applies a card change based on the board as currently displayed.
+    ///
+    /// If the board was changed elsewhere since it was loaded, the change is
+    /// rejected instead of overwriting the newer data, and the board is
+    /// reloaded so the user can review it and try again.
+    fn change_cards<T>(
+        &mut self,
+        change: impl FnOnce(&CardService) -> Result<T, CardServiceError>,
+    ) -> Result<T, Box<dyn std::error::Error>> {
+        let revision = self.board.as_ref().map(|b| b.revision);
+        let service = CardService::new().if_revision(revision);
+
+        match change(&service) {
+            Err(CardServiceError::BoardService(BoardServiceError::Conflict { .. })) => {
+                self.load_board()?;
+                Err(
+                    "The board was changed elsewhere and has been reloaded; please try again"
+                        .into(),
+                )
+            }
+            result => Ok(result?),
+        }
+    }
+
     pub fn deselect_card(&mut self) {
         self.card_selected = false;
         self.selected_card = None;
@@ -1,6 +1,7 @@
 use super::*;
 use crate::application::BoardService;
 use crate::cli::tui::handle_create_card_input;
+use crate::domain::Priority;
 use tempfile::TempDir;
 
 #[test]
@@ -16,11 +17,14 @@ fn test_form_navigation() {
     app.next_form_field();
     assert_eq!(app.form_field, FormField::Assignee);
 
+    app.next_form_field();
+    assert_eq!(app.form_field, FormField::Priority);
+
     app.next_form_field();
     assert_eq!(app.form_field, FormField::Title);
 
     app.prev_form_field();
-    assert_eq!(app.form_field, FormField::Assignee);
+    assert_eq!(app.form_field, FormField::Priority);
 }
 
 #[test]
@@ -121,7 +125,7 @@ fn test_create_card_full_workflow_with_typing() {
 
     handle_create_card_input(&mut app, &enter);
 
-    assert_eq!(app.form_field, FormField::Title);
+    assert_eq!(app.form_field, FormField::Priority);
     assert_eq!(app.input_mode, InputMode::Normal);
 
     app.submit_card().unwrap();
@@ -205,7 +209,7 @@ fn test_delete_card() {
 
     let card_service = CardService::new();
     let created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -230,7 +234,7 @@ fn test_start_move_card() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -254,7 +258,7 @@ fn test_move_card_navigation() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -285,7 +289,7 @@ fn test_move_card_right_boundary() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -313,7 +317,7 @@ fn test_move_card_left_boundary() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -338,7 +342,7 @@ fn test_confirm_move_card_success() {
 
     let card_service = CardService::new();
     let created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -363,6 +367,39 @@ fn test_confirm_move_card_success() {
     assert_eq!(card.column_id, "in_progress");
 }
 
+#[test]
+fn test_confirm_move_card_on_stale_board() {
+    let temp_dir = TempDir::new().unwrap();
+    let board_service = BoardService::new();
+    board_service
+        .initialize(temp_dir.path(), Some("Test".to_string()))
+        .unwrap();
+
+    let card_service = CardService::new();
+    let created = card_service
+        .create(temp_dir.path(), NewCard::new("Test Card"))
+        .unwrap();
+
+    let mut app = App::new(temp_dir.path().to_path_buf());
+    app.load_board().unwrap();
+    app.selected_column = 0;
+    app.selected_card = Some(0);
+
+    // Another process changes the board behind the TUI's back
+    card_service
+        .create(temp_dir.path(), NewCard::new("Other Card"))
+        .unwrap();
+
+    app.start_move_card();
+    app.move_card_right();
+    let result = app.confirm_move_card();
+
+    assert!(result.is_err());
+    let board = app.board.as_ref().unwrap();
+    assert_eq!(board.cards.len(), 2);
+    assert_eq!(board.get_card(&created.card_id).unwrap().column_id, "todo");
+}
+
 #[test]
 fn test_cancel_move_card() {
     let temp_dir = TempDir::new().unwrap();
@@ -373,7 +410,7 @@ fn test_cancel_move_card() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -400,7 +437,7 @@ fn test_complete_move_flow() {
 
     let card_service = CardService::new();
     let created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -443,7 +480,7 @@ fn test_enter_cards_focus_transitions_to_card_detail() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -472,7 +509,7 @@ fn test_first_enter_selects_card_with_details() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -496,7 +533,7 @@ fn test_second_enter_deselects_card() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -523,7 +560,7 @@ fn test_quick_move_card_right() {
 
     let card_service = CardService::new();
     let created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -557,7 +594,7 @@ fn test_quick_move_card_left() {
 
     let card_service = CardService::new();
     let created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     card_service
@@ -593,7 +630,7 @@ fn test_esc_deselects_card() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -620,7 +657,7 @@ fn test_move_without_card_selection() {
 
     let card_service = CardService::new();
     let created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -653,7 +690,7 @@ fn test_quick_move_right_boundary() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -680,7 +717,7 @@ fn test_quick_move_left_boundary() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -704,7 +741,7 @@ fn test_selected_card_id_set_when_entering_cards() {
 
     let card_service = CardService::new();
     let created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -727,7 +764,7 @@ fn test_selected_card_id_cleared_when_exiting() {
 
     let card_service = CardService::new();
     let created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -753,15 +790,15 @@ fn test_selected_card_id_matches_selected_card_when_moving() {
 
     let card_service = CardService::new();
     let card1_id = card_service
-        .create(temp_dir.path(), "Card 1".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 1"))
         .unwrap()
         .card_id;
     let card2_id = card_service
-        .create(temp_dir.path(), "Card 2".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 2"))
         .unwrap()
         .card_id;
     let card3_id = card_service
-        .create(temp_dir.path(), "Card 3".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 3"))
         .unwrap()
         .card_id;
 
@@ -796,14 +833,14 @@ fn test_get_selected_card_index_returns_correct_index() {
 
     let card_service = CardService::new();
     let card1_id = card_service
-        .create(temp_dir.path(), "Card 1".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 1"))
         .unwrap()
         .card_id;
     card_service
-        .create(temp_dir.path(), "Card 2".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 2"))
         .unwrap();
     let card3_id = card_service
-        .create(temp_dir.path(), "Card 3".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 3"))
         .unwrap()
         .card_id;
 
@@ -837,7 +874,7 @@ fn test_get_selected_card_index_returns_none_when_no_selection() {
 
     let card_service = CardService::new();
     card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -856,7 +893,7 @@ fn test_get_selected_card_index_after_quick_move_right() {
 
     let card_service = CardService::new();
     let created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -886,7 +923,7 @@ fn test_get_selected_card_index_after_quick_move_left() {
 
     let card_service = CardService::new();
     let created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     card_service
@@ -919,13 +956,13 @@ fn test_get_selected_card_index_with_multiple_cards_after_move() {
 
     let card_service = CardService::new();
     card_service
-        .create(temp_dir.path(), "Card 1".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 1"))
         .unwrap();
     let card2 = card_service
-        .create(temp_dir.path(), "Card 2".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 2"))
         .unwrap();
     card_service
-        .create(temp_dir.path(), "Card 3".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 3"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -956,7 +993,7 @@ fn test_exit_cards_clears_card_selection() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -983,7 +1020,7 @@ fn test_enter_deselects_card_and_returns_to_column_focus() {
 
     let card_service = CardService::new();
     let _created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -1011,7 +1048,7 @@ fn test_open_card_detail_view_from_board_state() {
 
     let card_service = CardService::new();
     let created = card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -1038,7 +1075,7 @@ fn test_open_card_detail_view_without_card_selection() {
 
     let card_service = CardService::new();
     card_service
-        .create(temp_dir.path(), "Test Card".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Test Card"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -1068,13 +1105,13 @@ fn test_move_down_in_column_focus_updates_pre_selected() {
 
     let card_service = CardService::new();
     card_service
-        .create(temp_dir.path(), "Card 1".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 1"))
         .unwrap();
     card_service
-        .create(temp_dir.path(), "Card 2".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 2"))
         .unwrap();
     card_service
-        .create(temp_dir.path(), "Card 3".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 3"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -1107,13 +1144,13 @@ fn test_move_up_in_column_focus_updates_pre_selected() {
 
     let card_service = CardService::new();
     card_service
-        .create(temp_dir.path(), "Card 1".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 1"))
         .unwrap();
     card_service
-        .create(temp_dir.path(), "Card 2".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 2"))
         .unwrap();
     card_service
-        .create(temp_dir.path(), "Card 3".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 3"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -1149,13 +1186,13 @@ fn test_enter_cards_uses_pre_selected_index() {
 
     let card_service = CardService::new();
     card_service
-        .create(temp_dir.path(), "Card 1".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 1"))
         .unwrap();
     let card2 = card_service
-        .create(temp_dir.path(), "Card 2".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 2"))
         .unwrap();
     card_service
-        .create(temp_dir.path(), "Card 3".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 3"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -1183,10 +1220,10 @@ fn test_move_left_resets_pre_selected_card() {
 
     let card_service = CardService::new();
     card_service
-        .create(temp_dir.path(), "Card 1".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 1"))
         .unwrap();
     let card2 = card_service
-        .create(temp_dir.path(), "Card 2".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 2"))
         .unwrap();
     card_service
         .move_to(temp_dir.path(), &card2.card_id, "in_progress")
@@ -1215,13 +1252,13 @@ fn test_move_right_resets_pre_selected_card() {
 
     let card_service = CardService::new();
     card_service
-        .create(temp_dir.path(), "Card 1".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 1"))
         .unwrap();
     card_service
-        .create(temp_dir.path(), "Card 2".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 2"))
         .unwrap();
     let card3 = card_service
-        .create(temp_dir.path(), "Card 3".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 3"))
         .unwrap();
     card_service
         .move_to(temp_dir.path(), &card3.card_id, "in_progress")
@@ -1250,10 +1287,10 @@ fn test_pre_selected_card_wraps_around() {
 
     let card_service = CardService::new();
     card_service
-        .create(temp_dir.path(), "Card 1".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 1"))
         .unwrap();
     card_service
-        .create(temp_dir.path(), "Card 2".to_string(), None, None, None)
+        .create(temp_dir.path(), NewCard::new("Card 2"))
         .unwrap();
 
     let mut app = App::new(temp_dir.path().to_path_buf());
@@ -1275,3 +1312,332 @@ fn test_pre_selected_card_wraps_around() {
     app.move_up();
     assert_eq!(app.pre_selected_card, Some(1));
 }
+
+#[test]
+fn test_submit_card_with_priority() {
+    let temp_dir = TempDir::new().unwrap();
+    let board_service = BoardService::new();
+    board_service
+        .initialize(temp_dir.path(), Some("Test".to_string()))
+        .unwrap();
+
+    let mut app = App::new(temp_dir.path().to_path_buf());
+    app.load_board().unwrap();
+    app.start_create_card();
+
+    app.form_data.title = "Urgent".to_string();
+    app.form_data.priority = "high".to_string();
+    app.submit_card().unwrap();
+
+    assert_eq!(app.state, AppState::Board);
+    let board = app.board.as_ref().unwrap();
+    assert_eq!(board.cards[0].priority, Some(Priority::High));
+}
+
+#[test]
+fn test_submit_card_invalid_priority() {
+    let temp_dir = TempDir::new().unwrap();
+    let board_service = BoardService::new();
+    board_service
+        .initialize(temp_dir.path(), Some("Test".to_string()))
+        .unwrap();
+
+    let mut app = App::new(temp_dir.path().to_path_buf());
+    app.load_board().unwrap();
+    app.start_create_card();
+
+    app.form_data.title = "Task".to_string();
+    app.form_data.priority = "urgent".to_string();
+    app.submit_card().unwrap();
+
+    assert!(app.error_message.is_some());
+    assert_eq!(app.state, AppState::CreateCard);
+}
+
+#[test]
+fn test_add_comment_from_card_detail() {
+    use crate::application::CardService;
+
+    let temp_dir = TempDir::new().unwrap();
+    let board_service = BoardService::new();
+    board_service
+        .initialize(temp_dir.path(), Some("Test".to_string()))
+        .unwrap();
+
+    let card_service = CardService::new();
+    let created = card_service
+        .create(temp_dir.path(), NewCard::new("Test Card"))
+        .unwrap();
+
+    let mut app = App::new(temp_dir.path().to_path_buf());
+    app.load_board().unwrap();
+    app.open_card_detail();
+
+    app.start_add_comment();
+    assert_eq!(app.state, AppState::AddComment);
+
+    app.comment_input = "Looks good".to_string();
+    app.submit_comment().unwrap();
+
+    assert_eq!(app.state, AppState::CardDetail);
+    assert!(app.comment_input.is_empty());
+    let card = app
+        .board
+        .as_ref()
+        .unwrap()
+        .get_card(&created.card_id)
+        .unwrap();
+    assert_eq!(card.comments.len(), 1);
+    assert_eq!(card.comments[0].body, "Looks good");
+}
+
+#[test]
+fn test_submit_empty_comment_keeps_input_open() {
+    use crate::application::CardService;
+
+    let temp_dir = TempDir::new().unwrap();
+    let board_service = BoardService::new();
+    board_service
+        .initialize(temp_dir.path(), Some("Test".to_string()))
+        .unwrap();
+
+    CardService::new()
+        .create(temp_dir.path(), NewCard::new("Test Card"))
+        .unwrap();
+
+    let mut app = App::new(temp_dir.path().to_path_buf());
+    app.load_board().unwrap();
+    app.open_card_detail();
+    app.start_add_comment();
+
+    app.submit_comment().unwrap();
+
+    assert_eq!(app.state, AppState::AddComment);
+    assert!(app.error_message.is_some());
+}
+
+#[test]
+fn test_scroll_comments_is_bounded() {
+    use crate::application::CardService;
+
+    let temp_dir = TempDir::new().unwrap();
+    let board_service = BoardService::new();
+    board_service
+        .initialize(temp_dir.path(), Some("Test".to_string()))
+        .unwrap();
+
+    let card_service = CardService::new();
+    let created = card_service
+        .create(temp_dir.path(), NewCard::new("Test Card"))
+        .unwrap();
+    card_service
+        .add_comment(temp_dir.path(), &created.card_id, "alice", "One")
+        .unwrap();
+
+    let mut app = App::new(temp_dir.path().to_path_buf());
+    app.load_board().unwrap();
+    app.open_card_detail();
+
+    app.scroll_comments_up();
+    assert_eq!(app.comment_scroll, 0);
+
+    for _ in 0..10 {
+        app.scroll_comments_down();
+    }
+    assert_eq!(app.comment_scroll, 2);
+}
+
+#[test]
+fn test_toggle_checklist_item_from_card_detail() {
+    use crate::application::{CardService, ChecklistEdit};
+
+    let temp_dir = TempDir::new().unwrap();
+    let board_service = BoardService::new();
+    board_service
+        .initialize(temp_dir.path(), Some("Test".to_string()))
+        .unwrap();
+
+    let card_service = CardService::new();
+    let created = card_service
+        .create(temp_dir.path(), NewCard::new("Test Card"))
+        .unwrap();
+    for text in ["One", "Two"] {
+        card_service
+            .edit_checklist(
+                temp_dir.path(),
+                &created.card_id,
+                &ChecklistEdit::Add(text.to_string()),
+            )
+            .unwrap();
+    }
+
+    let mut app = App::new(temp_dir.path().to_path_buf());
+    app.load_board().unwrap();
+    app.open_card_detail();
+
+    app.select_next_checklist_item();
+    app.select_next_checklist_item();
+    assert_eq!(app.checklist_selected, 1);
+
+    app.toggle_checklist_item().unwrap();
+    let card = app
+        .board
+        .as_ref()
+        .unwrap()
+        .get_card(&created.card_id)
+        .unwrap();
+    assert!(!card.checklist[0].done);
+    assert!(card.checklist[1].done);
+
+    app.toggle_checklist_item().unwrap();
+    let card = app
+        .board
+        .as_ref()
+        .unwrap()
+        .get_card(&created.card_id)
+        .unwrap();
+    assert_eq!(card.checklist_progress(), Some((0, 2)));
+}
+
+#[test]
+fn test_undo_and_redo_keys() {
+    use crate::cli::tui::handle_board_input;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let temp_dir = TempDir::new().unwrap();
+    let board_service = BoardService::new();
+    board_service
+        .initialize(temp_dir.path(), Some("Test".to_string()))
+        .unwrap();
+
+    let card_service = CardService::new();
+    let created = card_service
+        .create(temp_dir.path(), NewCard::new("Test Card"))
+        .unwrap();
+    card_service
+        .move_to(temp_dir.path(), &created.card_id, "done")
+        .unwrap();
+
+    let mut app = App::new(temp_dir.path().to_path_buf());
+    app.load_board().unwrap();
+
+    let undo = KeyEvent::new(KeyCode::Char('u'), KeyModifiers::empty());
+    handle_board_input(&mut app, &undo);
+
+    assert!(app.error_message.is_none());
+    let card = app.board.as_ref().unwrap().get_card(&created.card_id);
+    assert_eq!(card.unwrap().column_id, "todo");
+
+    let redo = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL);
+    handle_board_input(&mut app, &redo);
+
+    assert!(app.error_message.is_none());
+    let card = app.board.as_ref().unwrap().get_card(&created.card_id);
+    assert_eq!(card.unwrap().column_id, "done");
+
+    handle_board_input(&mut app, &redo);
+
+    assert_eq!(
+        app.error_message.as_deref(),
+        Some("Failed to redo: Nothing to redo")
+    );
+}
+
+#[test]
+fn test_archive_view_keys() {
+    use crate::cli::tui::{handle_archive_input, handle_board_input};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let temp_dir = TempDir::new().unwrap();
+    let board_service = BoardService::new();
+    board_service
+        .initialize(temp_dir.path(), Some("Test".to_string()))
+        .unwrap();
+
+    let card_service = CardService::new();
+    let created = card_service
+        .create(temp_dir.path(), NewCard::new("Test Card"))
+        .unwrap();
+
+    let mut app = App::new(temp_dir.path().to_path_buf());
+    app.load_board().unwrap();
+    app.archive_card(&created.card_id).unwrap();
+    assert!(app
+        .board
+        .as_ref()
+        .unwrap()
+        .get_cards_in_column("todo")
+        .is_empty());
+
+    let open = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty());
+    handle_board_input(&mut app, &open);
+
+    assert_eq!(app.state, AppState::Archive);
+    assert_eq!(
+        app.selected_archived_card_id(),
+        Some(created.card_id.clone())
+    );
+
+    let restore = KeyEvent::new(KeyCode::Char('r'), KeyModifiers::empty());
+    handle_archive_input(&mut app, &restore);
+
+    assert!(app.error_message.is_none());
+    assert_eq!(app.selected_archived_card_id(), None);
+    assert_eq!(
+        app.board
+            .as_ref()
+            .unwrap()
+            .get_cards_in_column("todo")
+            .len(),
+        1
+    );
+
+    let back = KeyEvent::new(KeyCode::Esc, KeyModifiers::empty());
+    handle_archive_input(&mut app, &back);
+
+    assert_eq!(app.state, AppState::Board);
+}
+
+#[test]
+fn test_reorder_keys() {
+    use crate::cli::tui::handle_board_input;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let temp_dir = TempDir::new().unwrap();
+    let board_service = BoardService::new();
+    board_service
+        .initialize(temp_dir.path(), Some("Test".to_string()))
+        .unwrap();
+
+    let card_service = CardService::new();
+    for title in ["First", "Second"] {
+        card_service
+            .create(temp_dir.path(), NewCard::new(title))
+            .unwrap();
+    }
+
+    let mut app = App::new(temp_dir.path().to_path_buf());
+    app.load_board().unwrap();
+    app.enter_cards();
+    assert_eq!(app.selected_card_id(), Some("TES-001".to_string()));
+
+    let down = KeyEvent::new(KeyCode::Char('J'), KeyModifiers::SHIFT);
+    handle_board_input(&mut app, &down);
+
+    assert!(app.error_message.is_none());
+    assert_eq!(
+        app.board.as_ref().unwrap().columns[0].cards,
+        vec!["TES-002", "TES-001"]
+    );
+    assert_eq!(app.selected_card_id(), Some("TES-001".to_string()));
+
+    handle_board_input(&mut app, &down);
+    let up = KeyEvent::new(KeyCode::Char('K'), KeyModifiers::SHIFT);
+    handle_board_input(&mut app, &up);
+
+    assert_eq!(
+        app.board.as_ref().unwrap().columns[0].cards,
+        vec!["TES-001", "TES-002"]
+    );
+    assert_eq!(app.selected_card, Some(0));
+}
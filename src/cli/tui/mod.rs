@@ -64,10 +64,11 @@ where
     loop {
         terminal.draw(|f| ui::draw(f, app))?;
 
-        let should_show_cursor = matches!(
+        let should_show_cursor = (matches!(
             app.state,
             state::AppState::CreateCard | state::AppState::EditCard
-        ) && app.input_mode == state::InputMode::Editing;
+        ) && app.input_mode == state::InputMode::Editing)
+            || app.state == state::AppState::AddComment;
 
         if should_show_cursor {
             execute!(io::stdout(), Show)?;
@@ -85,6 +86,8 @@ where
                         state::AppState::EditCard => handle_edit_card_input(app, &key),
                         state::AppState::ConfirmDelete => handle_confirm_delete_input(app, &key),
                         state::AppState::MoveCard => handle_move_card_input(app, &key),
+                        state::AppState::AddComment => handle_add_comment_input(app, &key),
+                        state::AppState::Archive => handle_archive_input(app, &key),
                         state::AppState::Help => {
                             if key.code == KeyCode::Esc || key.code == KeyCode::Char('?') {
                                 app.toggle_help();
@@ -109,25 +112,34 @@ where
 
 fn handle_board_input(app: &mut App, key: &crossterm::event::KeyEvent) {
     use crate::cli::tui::state::Focus;
-    use crossterm::event::KeyCode;
+    use crossterm::event::{KeyCode, KeyModifiers};
 
     match key.code {
         KeyCode::Left | KeyCode::Char('h') => {
             if app.card_selected {
-                let _ = app.quick_move_card_left();
+                if let Err(e) = app.quick_move_card_left() {
+                    app.error_message = Some(format!("Failed to move card: {}", e));
+                }
             } else {
                 app.move_left();
             }
         }
         KeyCode::Right | KeyCode::Char('l') => {
             if app.card_selected {
-                let _ = app.quick_move_card_right();
+                if let Err(e) = app.quick_move_card_right() {
+                    app.error_message = Some(format!("Failed to move card: {}", e));
+                }
             } else {
                 app.move_right();
             }
         }
         KeyCode::Up | KeyCode::Char('k') => app.move_up(),
         KeyCode::Down | KeyCode::Char('j') => app.move_down(),
+        KeyCode::Char(c @ ('K' | 'J')) if app.focus == Focus::Cards => {
+            if let Err(e) = app.rank_selected_card(c == 'K') {
+                app.error_message = Some(format!("Failed to reorder: {}", e));
+            }
+        }
         KeyCode::Enter => {
             if app.focus == Focus::Cards {
                 app.enter_card_detail();
@@ -148,9 +160,20 @@ fn handle_board_input(app: &mut App, key: &crossterm::event::KeyEvent) {
         KeyCode::Char('d') => {
             app.open_card_detail();
         }
-        KeyCode::Char('m') => {
-            if app.get_selected_card_index().is_some() && !app.card_selected {
-                app.start_move_card();
+        KeyCode::Char('m') if app.get_selected_card_index().is_some() && !app.card_selected => {
+            app.start_move_card();
+        }
+        KeyCode::Char('a') => {
+            app.open_archive();
+        }
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            if let Err(e) = app.redo() {
+                app.error_message = Some(format!("Failed to redo: {}", e));
+            }
+        }
+        KeyCode::Char('u') => {
+            if let Err(e) = app.undo() {
+                app.error_message = Some(format!("Failed to undo: {}", e));
             }
         }
         KeyCode::Char('q') => {}
@@ -171,9 +194,36 @@ fn handle_card_detail_input(app: &mut App, key: &crossterm::event::KeyEvent) {
         KeyCode::Char('d') => {
             app.state = state::AppState::ConfirmDelete;
         }
+        KeyCode::Char('a') => {
+            if let Some(card_id) = app.selected_card_id() {
+                if let Err(e) = app.archive_card(&card_id) {
+                    app.error_message = Some(format!("Failed to archive: {}", e));
+                }
+            }
+        }
         KeyCode::Char('m') => {
             app.start_move_card();
         }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.scroll_comments_down();
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.scroll_comments_up();
+        }
+        KeyCode::Char('J') => {
+            app.select_next_checklist_item();
+        }
+        KeyCode::Char('K') => {
+            app.select_previous_checklist_item();
+        }
+        KeyCode::Char(' ') => {
+            if let Err(e) = app.toggle_checklist_item() {
+                app.error_message = Some(format!("Failed to toggle item: {}", e));
+            }
+        }
+        KeyCode::Char('c') => {
+            app.start_add_comment();
+        }
         KeyCode::Esc | KeyCode::Char('q') => {
             app.state = state::AppState::Board;
             app.card_selected = false;
@@ -240,14 +290,12 @@ fn handle_edit_card_input(app: &mut App, key: &crossterm::event::KeyEvent) {
 }
 
 fn handle_confirm_delete_input(app: &mut App, key: &crossterm::event::KeyEvent) {
-    use crate::application::CardService;
     use crossterm::event::KeyCode;
 
     match key.code {
         KeyCode::Char('y') => {
             if let Some(card_id) = app.selected_card_id() {
-                let card_service = CardService::new();
-                match card_service.delete(&app.board_path, &card_id) {
+                match app.delete_card(&card_id) {
                     Ok(_) => {
                         app.selected_card = None;
                         app.selected_card_id = None;
@@ -295,3 +343,50 @@ fn handle_move_card_input(app: &mut App, key: &crossterm::event::KeyEvent) {
         _ => {}
     }
 }
+
+fn handle_add_comment_input(app: &mut App, key: &crossterm::event::KeyEvent) {
+    use crossterm::event::KeyCode;
+
+    match key.code {
+        KeyCode::Char(c) => {
+            app.comment_input.push(c);
+        }
+        KeyCode::Backspace => {
+            app.comment_input.pop();
+        }
+        KeyCode::Enter => {
+            if let Err(e) = app.submit_comment() {
+                app.error_message = Some(format!("Failed to add comment: {}", e));
+            }
+        }
+        KeyCode::Esc => {
+            app.cancel_comment();
+        }
+        _ => {}
+    }
+}
+
+fn handle_archive_input(app: &mut App, key: &crossterm::event::KeyEvent) {
+    use crossterm::event::KeyCode;
+
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.archive_move_up();
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.archive_move_down();
+        }
+        KeyCode::Char('r') => {
+            if let Err(e) = app.unarchive_selected() {
+                app.error_message = Some(format!("Failed to restore: {}", e));
+            }
+        }
+        KeyCode::Esc => {
+            app.close_archive();
+        }
+        KeyCode::Char('?') => {
+            app.toggle_help();
+        }
+        _ => {}
+    }
+}
@@ -14,6 +14,10 @@ pub enum AppState {
     ConfirmDelete,
     /// Move card to different column
     MoveCard,
+    /// Write a comment on the selected card
+    AddComment,
+    /// Browse archived cards
+    Archive,
     /// Help overlay
     #[allow(dead_code)]
     Help,
@@ -47,6 +51,7 @@ pub enum FormField {
     Title,
     Description,
     Assignee,
+    Priority,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -54,4 +59,5 @@ pub struct CardFormData {
     pub title: String,
     pub description: String,
     pub assignee: String,
+    pub priority: String,
 }
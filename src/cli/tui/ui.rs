@@ -1,5 +1,6 @@
 //! TUI rendering functions.
 
+use chrono::Local;
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -9,10 +10,12 @@ use ratatui::{
     Frame, Terminal,
 };
 use std::io;
+use std::str::FromStr;
 
 use crate::cli::tui::app::App;
 use crate::cli::tui::state::AppState;
 use crate::cli::tui::state::{Focus, FormField, InputMode};
+use crate::domain::{Board, Card, Priority};
 
 /// Type alias for terminal
 #[allow(dead_code)]
@@ -23,22 +26,26 @@ fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
     let hints = match app.state {
         AppState::Board => match app.focus {
             Focus::Columns => {
-                "h/l/← → Select column | k/j/↑↓ Navigate | Enter Select | d Details | c Create | q Quit | ? Help"
+                "h/l/← → Select column | k/j/↑↓ Navigate | Enter Select | d Details | c Create | a Archive | u Undo | q Quit | ? Help"
             }
             Focus::Cards => {
                 if app.card_selected {
-                    "Enter Deselect | d Details | ←/→/h/l Move card | Esc Deselect | q Quit | ? Help"
+                    "Enter Deselect | d Details | ←/→/h/l Move card | J/K Reorder | Esc Deselect | q Quit | ? Help"
                 } else {
-                    "Enter Select card | d Details | Esc Deselect | q Quit | ? Help"
+                    "Enter Select card | d Details | J/K Reorder | Esc Deselect | q Quit | ? Help"
                 }
             }
             _ => "? Help",
         },
-        AppState::CardDetail => "e Edit | d Delete | m Move | q Back | ? Help",
+        AppState::CardDetail => {
+            "j/k Scroll comments | J/K Select item | Space Toggle item | c Comment | e Edit | a Archive | d Delete | m Move | q Back | ? Help"
+        }
+        AppState::AddComment => "Type comment | Enter Save | Esc Cancel",
         AppState::CreateCard => "↑↓ Select field | Type to edit | Enter Save | Esc Cancel | ? Help",
         AppState::EditCard => "↑↓ Select field | Type to edit | Enter Save | Esc Cancel | ? Help",
         AppState::ConfirmDelete => "y Confirm | n Cancel",
         AppState::MoveCard => "h/l/← → Select column | Enter Confirm | Esc Cancel | ? Help",
+        AppState::Archive => "k/j/↑↓ Navigate | r Restore to board | Esc Back | ? Help",
         AppState::Help => "Esc Close help | ? Toggle",
     };
 
@@ -71,8 +78,12 @@ fn draw_help_overlay(frame: &mut Frame) {
         Line::from("   Enter Deselect card (when selected)"),
         Line::from("   d     Show card details"),
         Line::from("   ←/→   Move selected card between columns"),
+        Line::from("   J/K   Move card down/up in its column"),
         Line::from("   Esc   Deselect card / Exit selection"),
         Line::from("   c     Create new card"),
+        Line::from("   a     Browse archived cards"),
+        Line::from("   u     Undo last card change"),
+        Line::from("   Ctrl-r Redo undone card change"),
         Line::from(""),
         Line::from(" CREATE/EDIT CARD:"),
         Line::from("   ↑/k   Previous field"),
@@ -84,7 +95,17 @@ fn draw_help_overlay(frame: &mut Frame) {
         Line::from(" CARD DETAIL:"),
         Line::from("   e     Edit card"),
         Line::from("   d     Delete card"),
+        Line::from("   a     Archive card"),
         Line::from("   m     Move card"),
+        Line::from("   j/k   Scroll comments"),
+        Line::from("   J/K   Select checklist item"),
+        Line::from("   Space Check/uncheck checklist item"),
+        Line::from("   c     Add comment"),
+        Line::from("   Esc   Return to board"),
+        Line::from(""),
+        Line::from(" ARCHIVE:"),
+        Line::from("   j/k   Select archived card"),
+        Line::from("   r     Restore card to the board"),
         Line::from("   Esc   Return to board"),
         Line::from(""),
         Line::from(" Press ? or Esc to close "),
@@ -128,11 +149,12 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
     // Draw main content based on state
     match app.state {
         AppState::Board => draw_board_view(frame, app, chunks[1]),
-        AppState::CardDetail => draw_card_detail_view(frame, app, chunks[1]),
+        AppState::CardDetail | AppState::AddComment => draw_card_detail_view(frame, app, chunks[1]),
         AppState::CreateCard => draw_create_card_view(frame, app, chunks[1]),
         AppState::EditCard => draw_edit_card_view(frame, app, chunks[1]),
         AppState::ConfirmDelete => draw_confirm_delete_view(frame, app, chunks[1]),
         AppState::MoveCard => draw_move_card_view(frame, app, chunks[1]),
+        AppState::Archive => draw_archive_view(frame, app, chunks[1]),
         AppState::Help => {}
     }
 
@@ -203,11 +225,8 @@ fn draw_column(frame: &mut Frame, area: Rect, app: &App, column_id: &str, index:
         return;
     };
 
-    let cards: Vec<_> = board
-        .cards
-        .iter()
-        .filter(|c| c.column_id == column_id)
-        .collect();
+    let cards = board.get_cards_in_column(column_id);
+    let today = Local::now().date_naive();
 
     let is_focused = app.selected_column == index;
 
@@ -228,8 +247,10 @@ fn draw_column(frame: &mut Frame, area: Rect, app: &App, column_id: &str, index:
                 .add_modifier(Modifier::BOLD)
         } else if is_pre_selected {
             Style::default().fg(Color::Cyan)
+        } else if board.is_card_overdue(card, today) {
+            Style::default().fg(Color::Red)
         } else {
-            Style::default()
+            priority_style(card.priority)
         };
 
         let assignee_short = card
@@ -238,13 +259,25 @@ fn draw_column(frame: &mut Frame, area: Rect, app: &App, column_id: &str, index:
             .map(|a| format!(" [@{}]", a))
             .unwrap_or_default();
 
+        let checklist_short = card
+            .checklist_progress()
+            .map(|(done, total)| format!(" [{}/{}]", done, total))
+            .unwrap_or_default();
+
         let title = if card.title.len() > 25 {
-            format!("{}...{}", &card.title[..25], assignee_short)
+            format!(
+                "{}...{}{}",
+                &card.title[..25],
+                checklist_short,
+                assignee_short
+            )
         } else {
-            format!("{}{}", card.title, assignee_short)
+            format!("{}{}{}", card.title, checklist_short, assignee_short)
         };
 
-        items.push(ListItem::new(Span::styled(title, style)));
+        let mut line = vec![Span::styled(title, style)];
+        line.extend(label_spans(board, card));
+        items.push(ListItem::new(Line::from(line)));
 
         if is_selected {
             if let Some(desc) = &card.description {
@@ -260,13 +293,39 @@ fn draw_column(frame: &mut Frame, area: Rect, app: &App, column_id: &str, index:
                     style,
                 )));
             }
+
+            if let Some(priority) = card.priority {
+                items.push(ListItem::new(Span::styled(
+                    format!("  Priority: {}", priority),
+                    style,
+                )));
+            }
+
+            if let Some(due) = card.due {
+                items.push(ListItem::new(Span::styled(
+                    format!("  Due: {}", due),
+                    style,
+                )));
+            }
         }
     }
 
-    // Column block
+    // Column block, with the card count against the WIP limit
+    let count = match column.wip_limit {
+        Some(limit) => format!("{}/{}", cards.len(), limit),
+        None => cards.len().to_string(),
+    };
+    let title_style = if board.check_wip_limit(&column.id).is_err() {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
     let block = Block::default()
         .borders(Borders::ALL)
-        .title(format!(" {} ({}) ", column.name, cards.len()))
+        .title(Span::styled(
+            format!(" {} ({}) ", column.name, count),
+            title_style,
+        ))
         .border_style(if is_focused && app.focus == Focus::Columns {
             Style::default()
                 .fg(Color::Cyan)
@@ -284,6 +343,32 @@ fn draw_column(frame: &mut Frame, area: Rect, app: &App, column_id: &str, index:
     frame.render_widget(list, area);
 }
 
+/// Spans rendering a card's labels in their registered colors.
+fn label_spans<'a>(board: &Board, card: &'a Card) -> Vec<Span<'a>> {
+    card.labels
+        .iter()
+        .map(|label| {
+            let color = board
+                .get_label(label)
+                .and_then(|l| l.color.as_deref())
+                .and_then(|c| Color::from_str(c).ok())
+                .unwrap_or(Color::Gray);
+            Span::styled(format!(" #{}", label), Style::default().fg(color))
+        })
+        .collect()
+}
+
+/// Style used to highlight a card according to its priority.
+fn priority_style(priority: Option<Priority>) -> Style {
+    match priority {
+        Some(Priority::Critical) => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        Some(Priority::High) => Style::default().fg(Color::LightRed),
+        Some(Priority::Medium) => Style::default(),
+        Some(Priority::Low) => Style::default().fg(Color::DarkGray),
+        None => Style::default(),
+    }
+}
+
 /// Draw card detail view.
 fn draw_card_detail_view(frame: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::default()
@@ -329,11 +414,105 @@ fn draw_card_detail_view(frame: &mut Frame, app: &App, area: Rect) {
                     text.push(Line::from(""));
                 }
 
+                if let Some(priority) = card.priority {
+                    text.push(Line::from(Span::styled(
+                        format!("Priority: {}", priority),
+                        priority_style(Some(priority)),
+                    )));
+                    text.push(Line::from(""));
+                }
+
+                if let Some(due) = card.due {
+                    let (due_text, style) =
+                        if board.is_card_overdue(card, Local::now().date_naive()) {
+                            (
+                                format!("Due: {} (overdue)", due),
+                                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                            )
+                        } else {
+                            (format!("Due: {}", due), Style::default())
+                        };
+                    text.push(Line::from(Span::styled(due_text, style)));
+                    text.push(Line::from(""));
+                }
+
+                if !card.labels.is_empty() {
+                    let mut line = vec![Span::raw("Labels:")];
+                    line.extend(label_spans(board, card));
+                    text.push(Line::from(line));
+                    text.push(Line::from(""));
+                }
+
                 if let Some(column) = column {
                     text.push(Line::from(format!("Column: {}", column.name)));
                     text.push(Line::from(""));
                 }
 
+                if let Some(parent) = board.parent_of(card) {
+                    text.push(Line::from(format!(
+                        "Parent: {}: {}",
+                        parent.id, parent.title
+                    )));
+                    text.push(Line::from(""));
+                }
+
+                if let Some((done, total)) = board.child_progress(&card.id) {
+                    text.push(Line::from(format!("Children ({}/{} done):", done, total)));
+                    for child in board.children(&card.id) {
+                        let (mark, style) = if board.is_done_column(&child.column_id) {
+                            ("✓", Style::default().fg(Color::Green))
+                        } else {
+                            ("·", Style::default())
+                        };
+                        text.push(Line::from(Span::styled(
+                            format!("  {} {}: {}", mark, child.id, child.title),
+                            style,
+                        )));
+                    }
+                    text.push(Line::from(""));
+                }
+
+                if let Some((done, total)) = card.checklist_progress() {
+                    text.push(Line::from(format!("Checklist [{}/{}]:", done, total)));
+                    for (i, item) in card.checklist.iter().enumerate() {
+                        let style = if i == app.checklist_selected {
+                            Style::default()
+                                .fg(Color::Yellow)
+                                .add_modifier(Modifier::BOLD)
+                        } else if item.done {
+                            Style::default().fg(Color::Green)
+                        } else {
+                            Style::default()
+                        };
+                        text.push(Line::from(Span::styled(format!("  {}", item), style)));
+                    }
+                    text.push(Line::from(""));
+                }
+
+                let blockers = board.blockers(card);
+                if !blockers.is_empty() {
+                    text.push(Line::from("Blocked by:"));
+                    for blocker in blockers {
+                        let (mark, style) = if board.is_done_column(&blocker.column_id) {
+                            ("✓", Style::default().fg(Color::Green))
+                        } else {
+                            ("✗", Style::default().fg(Color::Red))
+                        };
+                        text.push(Line::from(Span::styled(
+                            format!("  {} {}: {}", mark, blocker.id, blocker.title),
+                            style,
+                        )));
+                    }
+                    text.push(Line::from(""));
+                }
+
+                let blocked = board.blocked_cards(&card.id);
+                if !blocked.is_empty() {
+                    let ids: Vec<&str> = blocked.iter().map(|c| c.id.as_str()).collect();
+                    text.push(Line::from(format!("Blocks: {}", ids.join(", "))));
+                    text.push(Line::from(""));
+                }
+
                 text.push(Line::from(format!(
                     "Created: {}",
                     card.created_at.format("%Y-%m-%d %H:%M")
@@ -343,11 +522,76 @@ fn draw_card_detail_view(frame: &mut Frame, app: &App, area: Rect) {
                     card.updated_at.format("%Y-%m-%d %H:%M")
                 )));
 
+                let content_chunks = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+                    .split(chunks[1]);
+
                 let paragraph = Paragraph::new(text).wrap(Wrap { trim: true });
-                frame.render_widget(paragraph, chunks[1]);
+                frame.render_widget(paragraph, content_chunks[0]);
+
+                draw_comments(frame, app, card, content_chunks[1]);
             }
         }
     }
+
+    if app.state == AppState::AddComment {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" New comment ")
+            .border_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            );
+        let paragraph = Paragraph::new(app.comment_input.as_str()).block(block);
+        frame.render_widget(paragraph, chunks[2]);
+
+        let cursor_x = chunks[2].x + 1 + app.comment_input.chars().count() as u16;
+        frame.set_cursor_position((
+            cursor_x.min(chunks[2].right().saturating_sub(2)),
+            chunks[2].y + 1,
+        ));
+    }
+}
+
+/// Draw the scrollable comment thread of a card.
+fn draw_comments(frame: &mut Frame, app: &App, card: &Card, area: Rect) {
+    let mut lines: Vec<Line> = Vec::new();
+
+    if card.comments.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No comments yet. Press c to add one.",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    for comment in &card.comments {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{} · {}",
+                comment.author,
+                comment.created_at.format("%Y-%m-%d %H:%M")
+            ),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for body_line in comment.body.lines() {
+            lines.push(Line::from(body_line.to_string()));
+        }
+        lines.push(Line::from(""));
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Comments ({}) ", card.comments.len()));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.comment_scroll, 0));
+    frame.render_widget(paragraph, area);
 }
 
 /// Draw create card view.
@@ -361,6 +605,7 @@ fn draw_create_card_view(frame: &mut Frame, app: &App, area: Rect) {
             Constraint::Length(3),
             Constraint::Length(3),
             Constraint::Length(3),
+            Constraint::Length(3),
             Constraint::Min(0),
         ])
         .split(area);
@@ -459,12 +704,41 @@ fn draw_create_card_view(frame: &mut Frame, app: &App, area: Rect) {
         .wrap(Wrap { trim: false });
     frame.render_widget(assignee_paragraph, chunks[3]);
 
+    // Priority field
+    let priority_focused = app.form_field == FormField::Priority;
+    let priority_block = Block::default()
+        .borders(Borders::ALL)
+        .title(if priority_focused {
+            " Priority (critical/high/medium/low) * "
+        } else {
+            " Priority (critical/high/medium/low) "
+        })
+        .border_style(if priority_focused {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        });
+
+    let priority_text = if priority_focused {
+        format!("{} ", app.form_data.priority)
+    } else {
+        app.form_data.priority.clone()
+    };
+
+    let priority_paragraph = Paragraph::new(priority_text.as_str())
+        .block(priority_block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(priority_paragraph, chunks[4]);
+
     // Set cursor position if in editing mode
     if app.input_mode == InputMode::Editing {
         let (field_area, input_text) = match app.form_field {
             FormField::Title => (chunks[1], &app.form_data.title),
             FormField::Description => (chunks[2], &app.form_data.description),
             FormField::Assignee => (chunks[3], &app.form_data.assignee),
+            FormField::Priority => (chunks[4], &app.form_data.priority),
         };
 
         let cursor_x = field_area.x + input_text.len() as u16 + 1;
@@ -488,7 +762,7 @@ fn draw_create_card_view(frame: &mut Frame, app: &App, area: Rect) {
     let column_paragraph = Paragraph::new(column_name)
         .block(column_block)
         .alignment(Alignment::Center);
-    frame.render_widget(column_paragraph, chunks[4]);
+    frame.render_widget(column_paragraph, chunks[5]);
 }
 
 /// Draw edit card view.
@@ -576,11 +850,7 @@ fn draw_move_card_view(frame: &mut Frame, app: &App, area: Rect) {
                 let text = format!(
                     "  {} ({}) ",
                     column.name,
-                    board
-                        .cards
-                        .iter()
-                        .filter(|c| c.column_id == column.id)
-                        .count()
+                    board.get_cards_in_column(&column.id).len()
                 );
                 ListItem::new(Span::styled(text, style))
             })
@@ -603,6 +873,64 @@ fn draw_move_card_view(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(paragraph, chunks[3]);
 }
 
+/// Draw the list of archived cards, most recently archived first.
+fn draw_archive_view(frame: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let header = Paragraph::new(" Archived Cards ")
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center);
+    frame.render_widget(header, chunks[0]);
+
+    let Some(board) = &app.board else { return };
+    let cards = board.archived_cards();
+
+    let items: Vec<ListItem> = if cards.is_empty() {
+        vec![ListItem::new("  (no archived cards)")]
+    } else {
+        cards
+            .iter()
+            .enumerate()
+            .map(|(i, card)| {
+                let style = if i == app.archive_selected {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                let archived = card
+                    .archived_at
+                    .map(|t| t.with_timezone(&Local).format("%Y-%m-%d").to_string())
+                    .unwrap_or_default();
+                let mut line = vec![Span::styled(
+                    format!(
+                        "  {}: {}  ({}, archived {})",
+                        card.id, card.title, card.column_id, archived
+                    ),
+                    style,
+                )];
+                line.extend(label_spans(board, card));
+                ListItem::new(Line::from(line))
+            })
+            .collect()
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Archive ({}) ", cards.len())),
+    );
+    frame.render_widget(list, chunks[1]);
+}
+
 /// Draw error message overlay.
 fn draw_error_message(frame: &mut Frame, message: &str) {
     let area = centered_rect(frame.area(), 60, 6);
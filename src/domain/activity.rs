@@ -0,0 +1,249 @@
+use std::fmt;
+
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::Card;
+
+/// A recorded change to a card.
+///
+/// Activities are kept on the board, so the history of a card survives
+/// its deletion.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Activity {
+    /// Card the change applies to
+    pub card_id: String,
+    /// Person or agent who made the change
+    pub actor: String,
+    /// When the change was made
+    pub timestamp: DateTime<Utc>,
+    /// What changed
+    #[serde(flatten)]
+    pub kind: ActivityKind,
+}
+
+/// The kind of change recorded by an [`Activity`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ActivityKind {
+    /// The card was created
+    Created { title: String, column_id: String },
+    /// The card moved between columns
+    Moved { from: String, to: String },
+    /// One or more fields of the card changed
+    Updated { changes: Vec<FieldChange> },
+    /// A comment was added
+    Commented,
+    /// The card was archived
+    Archived,
+    /// The card was returned from the archive
+    Unarchived,
+    /// The card was claimed until the claim expires
+    Claimed { expires_at: DateTime<Utc> },
+    /// The claim on the card was released, or expired
+    Released { expired: bool },
+    /// The card was deleted
+    Deleted { title: String },
+}
+
+/// The value of a card field before and after an update.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl Activity {
+    /// Creates an activity timestamped now.
+    pub fn new(card_id: String, actor: String, kind: ActivityKind) -> Self {
+        Self {
+            card_id,
+            actor,
+            timestamp: Utc::now(),
+            kind,
+        }
+    }
+}
+
+impl FieldChange {
+    /// Lists the fields that differ between two versions of a card.
+    pub fn between(before: &Card, after: &Card) -> Vec<FieldChange> {
+        let fields: [(&str, Option<String>, Option<String>); 9] = [
+            (
+                "title",
+                Some(before.title.clone()),
+                Some(after.title.clone()),
+            ),
+            (
+                "description",
+                before.description.clone(),
+                after.description.clone(),
+            ),
+            ("assignee", before.assignee.clone(), after.assignee.clone()),
+            (
+                "priority",
+                before.priority.map(|p| p.to_string()),
+                after.priority.map(|p| p.to_string()),
+            ),
+            (
+                "due",
+                before.due.map(|d| d.to_string()),
+                after.due.map(|d| d.to_string()),
+            ),
+            ("labels", join(&before.labels), join(&after.labels)),
+            ("parent", before.parent.clone(), after.parent.clone()),
+            (
+                "blocked_by",
+                join(&before.blocked_by),
+                join(&after.blocked_by),
+            ),
+            ("checklist", join(&before.checklist), join(&after.checklist)),
+        ];
+
+        fields
+            .into_iter()
+            .filter(|(_, before, after)| before != after)
+            .map(|(field, before, after)| FieldChange {
+                field: field.to_string(),
+                before,
+                after,
+            })
+            .collect()
+    }
+}
+
+fn join<'a, T: fmt::Display + 'a>(values: impl IntoIterator<Item = &'a T>) -> Option<String> {
+    let values: Vec<String> = values.into_iter().map(|v| v.to_string()).collect();
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.join(", "))
+    }
+}
+
+impl fmt::Display for ActivityKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActivityKind::Created { title, column_id } => {
+                write!(f, "created \"{}\" in {}", title, column_id)
+            }
+            ActivityKind::Moved { from, to } => write!(f, "moved {} → {}", from, to),
+            ActivityKind::Updated { changes } => {
+                let changes: Vec<String> = changes.iter().map(|c| c.to_string()).collect();
+                write!(f, "updated {}", changes.join("; "))
+            }
+            ActivityKind::Commented => write!(f, "commented"),
+            ActivityKind::Archived => write!(f, "archived"),
+            ActivityKind::Unarchived => write!(f, "unarchived"),
+            ActivityKind::Claimed { expires_at } => write!(
+                f,
+                "claimed until {}",
+                expires_at.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+            ),
+            ActivityKind::Released { expired: false } => write!(f, "released claim"),
+            ActivityKind::Released { expired: true } => write!(f, "claim expired"),
+            ActivityKind::Deleted { title } => write!(f, "deleted \"{}\"", title),
+        }
+    }
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |value: &Option<String>| match value {
+            Some(v) => format!("\"{}\"", v),
+            None => "(none)".to_string(),
+        };
+        write!(
+            f,
+            "{}: {} → {}",
+            self.field,
+            show(&self.before),
+            show(&self.after)
+        )
+    }
+}
+
+/// Criteria for selecting activities.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityFilter {
+    /// Only activities of this card
+    pub card_id: Option<String>,
+    /// Only activities by this actor
+    pub actor: Option<String>,
+    /// Only activities at or after this time
+    pub since: Option<DateTime<Utc>>,
+}
+
+impl ActivityFilter {
+    /// Returns true if the activity satisfies every criterion of the filter.
+    pub fn matches(&self, activity: &Activity) -> bool {
+        self.card_id
+            .as_ref()
+            .is_none_or(|id| activity.card_id == *id)
+            && self.actor.as_ref().is_none_or(|a| activity.actor == *a)
+            && self.since.is_none_or(|since| activity.timestamp >= since)
+    }
+}
+
+/// Parses the start of a time window relative to `now`.
+///
+/// Accepted forms:
+/// - RFC 3339 timestamps: `2024-05-15T10:00:00Z`
+/// - Dates, meaning local midnight: `2024-05-15`
+/// - Durations ago: `30m`, `12h`, `7d`, `2w`
+pub fn parse_since(input: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    let value = input.trim();
+    let invalid = || {
+        format!(
+            "Invalid time '{}': use YYYY-MM-DD, an RFC 3339 timestamp or a duration like 12h, 7d, 2w",
+            input
+        )
+    };
+
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Local
+            .from_local_datetime(&date.and_hms_opt(0, 0, 0).ok_or_else(invalid)?)
+            .earliest()
+            .map(|t| t.with_timezone(&Utc))
+            .ok_or_else(invalid);
+    }
+
+    parse_duration(value)
+        .ok()
+        .and_then(|d| now.checked_sub_signed(d))
+        .ok_or_else(invalid)
+}
+
+/// Parses a duration: a number followed by `m`, `h`, `d` or `w`, like
+/// `30m` or `2w`.
+pub fn parse_duration(input: &str) -> Result<Duration, String> {
+    let value = input.trim();
+    let invalid = || {
+        format!(
+            "Invalid duration '{}': use a number followed by m, h, d or w, like 30m",
+            input
+        )
+    };
+
+    let unit = value.chars().last().ok_or_else(invalid)?;
+    let amount: i64 = value[..value.len() - unit.len_utf8()]
+        .parse()
+        .map_err(|_| invalid())?;
+    match unit {
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    }
+    .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+#[path = "./activity_tests.rs"]
+mod activity_tests;
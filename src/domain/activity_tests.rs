@@ -0,0 +1,110 @@
+use super::*;
+use crate::domain::Priority;
+
+fn card() -> Card {
+    Card::new(
+        "PRJ-001".to_string(),
+        "Task".to_string(),
+        "todo".to_string(),
+    )
+}
+
+#[test]
+fn test_field_changes_between_cards() {
+    // Arrange
+    let before = card();
+    let mut after = before.clone();
+    after.title = "Renamed".to_string();
+    after.priority = Some(Priority::High);
+    after.add_label("bug".to_string());
+
+    // Act
+    let changes = FieldChange::between(&before, &after);
+
+    // Assert
+    let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+    assert_eq!(fields, vec!["title", "priority", "labels"]);
+    assert_eq!(changes[0].before.as_deref(), Some("Task"));
+    assert_eq!(changes[0].after.as_deref(), Some("Renamed"));
+    assert_eq!(changes[1].before, None);
+    assert_eq!(
+        changes[1].to_string(),
+        "priority: (none) → \"high\"".to_string()
+    );
+}
+
+#[test]
+fn test_activity_serialization_is_flat() {
+    let activity = Activity::new(
+        "PRJ-001".to_string(),
+        "alice".to_string(),
+        ActivityKind::Moved {
+            from: "todo".to_string(),
+            to: "done".to_string(),
+        },
+    );
+
+    let json = serde_json::to_value(&activity).unwrap();
+
+    assert_eq!(json["type"], "moved");
+    assert_eq!(json["from"], "todo");
+    assert_eq!(json["actor"], "alice");
+    let parsed: Activity = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, activity);
+}
+
+#[test]
+fn test_activity_filter() {
+    // Arrange
+    let activity = Activity::new(
+        "PRJ-001".to_string(),
+        "agent-7".to_string(),
+        ActivityKind::Commented,
+    );
+    let by_actor = ActivityFilter {
+        actor: Some("agent-7".to_string()),
+        ..Default::default()
+    };
+    let by_other_card = ActivityFilter {
+        card_id: Some("PRJ-002".to_string()),
+        ..Default::default()
+    };
+    let in_future = ActivityFilter {
+        since: Some(Utc::now() + Duration::hours(1)),
+        ..Default::default()
+    };
+
+    // Act & Assert
+    assert!(ActivityFilter::default().matches(&activity));
+    assert!(by_actor.matches(&activity));
+    assert!(!by_other_card.matches(&activity));
+    assert!(!in_future.matches(&activity));
+}
+
+#[test]
+fn test_parse_since() {
+    let now = DateTime::parse_from_rfc3339("2024-05-15T12:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+
+    assert_eq!(parse_since("12h", now), Ok(now - Duration::hours(12)));
+    assert_eq!(parse_since("2w", now), Ok(now - Duration::weeks(2)));
+    assert_eq!(
+        parse_since("2024-05-01T08:00:00Z", now),
+        Ok(DateTime::parse_from_rfc3339("2024-05-01T08:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc))
+    );
+    assert!(parse_since("2024-05-01", now).is_ok());
+    assert!(parse_since("yesterday", now).is_err());
+    assert!(parse_since("3y", now).is_err());
+}
+
+#[test]
+fn test_parse_duration() {
+    assert_eq!(parse_duration("30m"), Ok(Duration::minutes(30)));
+    assert_eq!(parse_duration(" 2d "), Ok(Duration::days(2)));
+    assert!(parse_duration("").is_err());
+    assert!(parse_duration("30").is_err());
+    assert!(parse_duration("soon").is_err());
+}
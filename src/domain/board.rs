@@ -1,7 +1,10 @@
-use chrono::{DateTime, Utc};
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-use super::{Card, Column};
+use super::{Activity, ActivityKind, Card, CardFilter, CardPosition, Column, Label, WipPolicy};
 
 /// Represents a kanban board containing columns and cards.
 ///
@@ -17,16 +20,61 @@ pub struct Board {
     pub card_id_prefix: String,
     /// Counter for generating next card ID
     pub next_card_number: u32,
+    /// How new card IDs are generated
+    #[serde(default)]
+    pub card_id_scheme: CardIdScheme,
     /// Columns in this board
     pub columns: Vec<Column>,
     /// All cards in this board
     pub cards: Vec<Card>,
+    /// Whether changes may take columns over their WIP limit
+    #[serde(default)]
+    pub wip_policy: WipPolicy,
+    /// Registry of labels with their display settings
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    /// Log of changes made to cards, oldest first
+    #[serde(default)]
+    pub activity: Vec<Activity>,
+    /// Number of times the board has been saved, used to detect
+    /// concurrent modifications
+    #[serde(default)]
+    pub revision: u64,
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
     /// Last update timestamp
     pub updated_at: DateTime<Utc>,
 }
 
+/// How a board generates IDs for new cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardIdScheme {
+    /// Numbered in creation order: PRJ-001, PRJ-002, ...
+    #[default]
+    Sequential,
+    /// Random suffixes such as PRJ-k7f3q9, which do not collide when cards
+    /// are created on different branches of a repository
+    Random,
+}
+
+/// Characters of random card ID suffixes: lowercase, without the easily
+/// confused i, l, o and u.
+const ID_ALPHABET: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+
+/// Length of random card ID suffixes (about a billion possible IDs).
+const RANDOM_ID_LENGTH: usize = 6;
+
+/// Generates a random card ID suffix.
+fn random_id_suffix() -> String {
+    Uuid::new_v4()
+        .as_bytes()
+        .iter()
+        .take(RANDOM_ID_LENGTH)
+        .map(|byte| char::from(ID_ALPHABET[usize::from(byte % 32)]))
+        .collect()
+}
+
 impl Board {
     /// Creates a new board with default columns.
     ///
@@ -58,8 +106,13 @@ impl Board {
             name,
             card_id_prefix: prefix,
             next_card_number: 1,
+            card_id_scheme: CardIdScheme::default(),
             columns: default_columns,
             cards: Vec::new(),
+            wip_policy: WipPolicy::default(),
+            labels: Vec::new(),
+            activity: Vec::new(),
+            revision: 0,
             created_at: now,
             updated_at: now,
         }
@@ -79,24 +132,40 @@ impl Board {
 
     /// Generates the next unique card ID.
     ///
-    /// Format: PREFIX-NNN (e.g., "PRJ-001")
+    /// Format depends on the board's [`CardIdScheme`]: PREFIX-NNN
+    /// (e.g., "PRJ-001") or PREFIX-xxxxxx (e.g., "PRJ-k7f3q9").
     pub fn generate_card_id(&mut self) -> String {
-        let id = format!("{}-{:03}", self.card_id_prefix, self.next_card_number);
-        self.next_card_number += 1;
+        let id = match self.card_id_scheme {
+            CardIdScheme::Sequential => {
+                let id = format!("{}-{:03}", self.card_id_prefix, self.next_card_number);
+                self.next_card_number += 1;
+                id
+            }
+            // IDs of deleted cards stay taken, so their history is not
+            // mixed up with a new card's.
+            CardIdScheme::Random => loop {
+                let id = format!("{}-{}", self.card_id_prefix, random_id_suffix());
+                if self.get_card(&id).is_none() && self.card_history(&id).is_empty() {
+                    break id;
+                }
+            },
+        };
         self.updated_at = Utc::now();
         id
     }
 
     /// Adds a new card to the board.
     ///
-    /// The card is placed in the specified column (or "todo" if not specified).
+    /// The card is placed in the specified column, or the first column if
+    /// none is specified or the column does not exist. A board without
+    /// columns gets a "To Do" column, so every card is listed in a column.
     /// Returns the generated card ID.
     ///
     /// # Arguments
     /// * `title` - Card title
     /// * `description` - Optional description
     /// * `assignee` - Optional assignee
-    /// * `column_id` - Target column (defaults to "todo")
+    /// * `column_id` - Target column (defaults to the first column)
     pub fn create_card(
         &mut self,
         title: String,
@@ -105,17 +174,19 @@ impl Board {
         column_id: Option<String>,
     ) -> String {
         let card_id = self.generate_card_id();
-        let target_column = column_id.unwrap_or_else(|| "todo".to_string());
+        if self.columns.is_empty() {
+            self.add_column("todo".to_string(), "To Do".to_string(), 0);
+        }
+        let index = column_id
+            .and_then(|id| self.columns.iter().position(|c| c.id == id))
+            .unwrap_or(0);
+        let column = &mut self.columns[index];
+        column.add_card(card_id.clone());
 
-        let mut card = Card::new(card_id.clone(), title, target_column.clone());
+        let mut card = Card::new(card_id.clone(), title, column.id.clone());
         card.description = description;
         card.assignee = assignee;
 
-        // Add to column
-        if let Some(column) = self.columns.iter_mut().find(|c| c.id == target_column) {
-            column.add_card(card_id.clone());
-        }
-
         self.cards.push(card);
         self.updated_at = Utc::now();
 
@@ -124,35 +195,107 @@ impl Board {
 
     /// Moves a card to a different column.
     ///
+    /// The card goes to its priority's place in the target column, see
+    /// [`Board::priority_position`], or keeps its place if it is already in
+    /// it.
+    ///
     /// Returns true if successful, false if card or column not found.
     pub fn move_card(&mut self, card_id: &str, target_column_id: &str) -> bool {
-        // Check if target column exists
-        if !self.columns.iter().any(|c| c.id == target_column_id) {
+        let in_target = self
+            .get_card(card_id)
+            .is_some_and(|c| c.column_id == target_column_id);
+        if !in_target {
+            let position = self.priority_position(card_id, target_column_id);
+            return self.move_card_to(card_id, target_column_id, &position);
+        }
+
+        if let Some(card) = self.get_card_mut(card_id) {
+            card.move_to(target_column_id.to_string());
+        }
+        self.updated_at = Utc::now();
+        true
+    }
+
+    /// Moves a card to `position` in a column, which may be its current one.
+    ///
+    /// Returns false, leaving the board unchanged, if the card or column is
+    /// not found or the card to place it before or after is not in the
+    /// column.
+    pub fn move_card_to(
+        &mut self,
+        card_id: &str,
+        target_column_id: &str,
+        position: &CardPosition,
+    ) -> bool {
+        if self.get_card(card_id).is_none() || !self.place_card(card_id, target_column_id, position)
+        {
             return false;
         }
 
-        // Find and update the card
-        if let Some(card) = self.cards.iter_mut().find(|c| c.id == card_id) {
-            let source_column_id = card.column_id.clone();
+        if let Some(card) = self.get_card_mut(card_id) {
+            card.move_to(target_column_id.to_string());
+        }
+        self.updated_at = Utc::now();
+        true
+    }
 
-            // Remove from source column
-            if let Some(column) = self.columns.iter_mut().find(|c| c.id == source_column_id) {
-                column.remove_card(card_id);
-            }
+    /// Moves a card to `position` within its column.
+    ///
+    /// Returns false, leaving the board unchanged, if the card is not found
+    /// or the card to place it before or after is not in its column.
+    pub fn rank_card(&mut self, card_id: &str, position: &CardPosition) -> bool {
+        let Some(column_id) = self.get_card(card_id).map(|c| c.column_id.clone()) else {
+            return false;
+        };
+        if !self.place_card(card_id, &column_id, position) {
+            return false;
+        }
 
-            // Add to target column
-            if let Some(column) = self.columns.iter_mut().find(|c| c.id == target_column_id) {
-                column.add_card(card_id.to_string());
-            }
+        self.updated_at = Utc::now();
+        true
+    }
 
-            // Update card
-            card.move_to(target_column_id.to_string());
-            self.updated_at = Utc::now();
+    /// Gets the place of a card in a column by its priority: before the
+    /// first card shown there with a lower priority, or at the bottom.
+    ///
+    /// Cards without a priority rank below all others. Cards placed this way
+    /// keep a column that was not ranked by hand sorted by priority.
+    pub fn priority_position(&self, card_id: &str, column_id: &str) -> CardPosition {
+        let priority = self.get_card(card_id).and_then(|c| c.priority);
+        self.get_cards_in_column(column_id)
+            .into_iter()
+            .find(|c| c.id != card_id && c.priority < priority)
+            .map_or(CardPosition::Bottom, |c| CardPosition::Before(c.id.clone()))
+    }
 
-            true
-        } else {
-            false
+    /// Puts a card at `position` in a column's list and takes it out of the
+    /// other columns.
+    ///
+    /// Indexes count the cards shown in the column, so archived cards in
+    /// between are skipped.
+    fn place_card(&mut self, card_id: &str, column_id: &str, position: &CardPosition) -> bool {
+        let position = match position {
+            CardPosition::Index(index) => self
+                .get_cards_in_column(column_id)
+                .into_iter()
+                .filter(|c| c.id != card_id)
+                .nth(*index)
+                .map_or(CardPosition::Bottom, |c| CardPosition::Before(c.id.clone())),
+            position => position.clone(),
+        };
+        let Some(target) = self.columns.iter().position(|c| c.id == column_id) else {
+            return false;
+        };
+        if !self.columns[target].insert_card(card_id, &position) {
+            return false;
+        }
+
+        for (index, column) in self.columns.iter_mut().enumerate() {
+            if index != target {
+                column.remove_card(card_id);
+            }
         }
+        true
     }
 
     /// Gets a card by ID.
@@ -165,7 +308,8 @@ impl Board {
         self.cards.iter_mut().find(|c| c.id == card_id)
     }
 
-    /// Deletes a card from the board.
+    /// Deletes a card from the board, along with the links that make it
+    /// block other cards.
     ///
     /// Returns true if the card was found and deleted.
     pub fn delete_card(&mut self, card_id: &str) -> bool {
@@ -179,7 +323,15 @@ impl Board {
 
             // Remove card
             self.cards.remove(pos);
-            self.updated_at = Utc::now();
+
+            // Remove links, so a later card with the same ID is no blocker
+            let now = Utc::now();
+            for card in &mut self.cards {
+                if card.blocked_by.remove(card_id) {
+                    card.updated_at = now;
+                }
+            }
+            self.updated_at = now;
 
             true
         } else {
@@ -188,7 +340,6 @@ impl Board {
     }
 
     /// Adds a column to the board.
-    #[allow(dead_code)]
     pub fn add_column(&mut self, id: String, name: String, order: u32) {
         let column = Column::new(id, name, order);
         self.columns.push(column);
@@ -198,47 +349,302 @@ impl Board {
 
     /// Removes a column from the board.
     ///
-    /// Cards in the column are moved to the first column.
-    #[allow(dead_code)]
-    pub fn remove_column(&mut self, column_id: &str) -> bool {
+    /// Cards in the column are moved to `target_column_id`. A target is only
+    /// required when the column still contains cards.
+    ///
+    /// Returns false if the column is the last one, the column or target is
+    /// not found, or the column has cards but no valid target was given.
+    pub fn remove_column(&mut self, column_id: &str, target_column_id: Option<&str>) -> bool {
         if self.columns.len() <= 1 {
             return false; // Can't remove the last column
         }
 
-        if let Some(pos) = self.columns.iter().position(|c| c.id == column_id) {
-            let column = &self.columns[pos];
-            let cards_to_move: Vec<String> = column.cards.clone();
+        let Some(pos) = self.columns.iter().position(|c| c.id == column_id) else {
+            return false;
+        };
 
-            // Find first remaining column
-            let first_column_id = self
-                .columns
-                .iter()
-                .find(|c| c.id != column_id)
-                .map(|c| c.id.clone())
-                .unwrap();
+        let cards_to_move: Vec<String> = self
+            .cards
+            .iter()
+            .filter(|c| c.column_id == column_id)
+            .map(|c| c.id.clone())
+            .collect();
+
+        if !cards_to_move.is_empty() {
+            let Some(target) = target_column_id else {
+                return false;
+            };
+            if target == column_id || !self.columns.iter().any(|c| c.id == target) {
+                return false;
+            }
 
-            // Move cards
             for card_id in cards_to_move {
-                self.move_card(&card_id, &first_column_id);
+                self.move_card(&card_id, target);
             }
+        }
 
-            // Remove column
-            self.columns.remove(pos);
-            self.updated_at = Utc::now();
+        // Remove column and close the gap in the ordering
+        self.columns.remove(pos);
+        self.renumber_columns();
+        self.updated_at = Utc::now();
 
+        true
+    }
+
+    /// Renames a column.
+    ///
+    /// Returns true if the column was found.
+    pub fn rename_column(&mut self, column_id: &str, name: String) -> bool {
+        if let Some(column) = self.columns.iter_mut().find(|c| c.id == column_id) {
+            column.name = name;
+            self.updated_at = Utc::now();
             true
         } else {
             false
         }
     }
 
-    /// Gets cards in a specific column.
+    /// Moves a column to a new position (0 = leftmost).
+    ///
+    /// Positions past the end place the column last. Returns true if the
+    /// column was found.
+    pub fn move_column(&mut self, column_id: &str, position: usize) -> bool {
+        let Some(pos) = self.columns.iter().position(|c| c.id == column_id) else {
+            return false;
+        };
+
+        let column = self.columns.remove(pos);
+        let position = position.min(self.columns.len());
+        self.columns.insert(position, column);
+        self.renumber_columns();
+        self.updated_at = Utc::now();
+
+        true
+    }
+
+    /// Reassigns column `order` values to match their position.
+    fn renumber_columns(&mut self) {
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            column.order = i as u32;
+        }
+    }
+
+    /// Gets the cards shown in a specific column, leaving out archived
+    /// cards.
+    ///
+    /// Cards are in the order of the column's card list, which users set by
+    /// ranking cards.
     pub fn get_cards_in_column(&self, column_id: &str) -> Vec<&Card> {
-        self.cards
+        self.all_cards_in_column(column_id)
+            .into_iter()
+            .filter(|c| !c.is_archived())
+            .collect()
+    }
+
+    /// Gets the cards in a column that match a filter, in column order.
+    ///
+    /// Cards in the done column are never reported as overdue.
+    pub fn get_matching_cards_in_column(&self, column_id: &str, filter: &CardFilter) -> Vec<&Card> {
+        if filter.overdue_on.is_some() && self.is_done_column(column_id) {
+            return Vec::new();
+        }
+
+        self.all_cards_in_column(column_id)
+            .into_iter()
+            .filter(|c| filter.matches(c))
+            .collect()
+    }
+
+    /// Gets all cards in a column, archived or not, in column order.
+    ///
+    /// Listed cards that are not loaded, as with filtered loads, are left
+    /// out.
+    fn all_cards_in_column(&self, column_id: &str) -> Vec<&Card> {
+        let Some(column) = self.columns.iter().find(|c| c.id == column_id) else {
+            return Vec::new();
+        };
+        let cards: HashMap<&str, &Card> = self.cards.iter().map(|c| (c.id.as_str(), c)).collect();
+        column
+            .cards
             .iter()
+            .filter_map(|id| cards.get(id.as_str()).copied())
             .filter(|c| c.column_id == column_id)
             .collect()
     }
+
+    /// Gets the archived cards, most recently archived first.
+    pub fn archived_cards(&self) -> Vec<&Card> {
+        let mut cards: Vec<&Card> = self.cards.iter().filter(|c| c.is_archived()).collect();
+        cards.sort_by_key(|c| std::cmp::Reverse(c.archived_at));
+        cards
+    }
+
+    /// Gets when a card entered its current column.
+    ///
+    /// Taken from the activity log, falling back to the card's creation time
+    /// for cards without recorded activity.
+    pub fn entered_column_at(&self, card: &Card) -> DateTime<Utc> {
+        self.activity
+            .iter()
+            .rev()
+            .filter(|a| a.card_id == card.id)
+            .find_map(|a| match &a.kind {
+                ActivityKind::Moved { to, .. } if *to == card.column_id => Some(a.timestamp),
+                ActivityKind::Created { column_id, .. } if *column_id == card.column_id => {
+                    Some(a.timestamp)
+                }
+                _ => None,
+            })
+            .unwrap_or(card.created_at)
+    }
+
+    /// Archives the cards that entered the done column before `cutoff`.
+    ///
+    /// Returns the IDs of the archived cards.
+    pub fn archive_done_cards(&mut self, cutoff: DateTime<Utc>) -> Vec<String> {
+        let Some(done) = self.columns.last().map(|c| c.id.clone()) else {
+            return Vec::new();
+        };
+        let stale: Vec<String> = self
+            .get_cards_in_column(&done)
+            .into_iter()
+            .filter(|c| self.entered_column_at(c) < cutoff)
+            .map(|c| c.id.clone())
+            .collect();
+        for card in self.cards.iter_mut().filter(|c| stale.contains(&c.id)) {
+            card.archive();
+        }
+        if !stale.is_empty() {
+            self.updated_at = Utc::now();
+        }
+        stale
+    }
+
+    /// Returns true if the column is where work has not started yet (the
+    /// first one).
+    pub fn is_backlog_column(&self, column_id: &str) -> bool {
+        self.columns.first().is_some_and(|c| c.id == column_id)
+    }
+
+    /// Gets the column where work on cards happens: the one after the first,
+    /// unless that is the done column.
+    pub fn in_progress_column(&self) -> Option<&Column> {
+        self.columns
+            .get(1)
+            .filter(|column| !self.is_done_column(&column.id))
+    }
+
+    /// Returns true if the column is the board's done column (the last one).
+    pub fn is_done_column(&self, column_id: &str) -> bool {
+        self.columns.last().is_some_and(|c| c.id == column_id)
+    }
+
+    /// Returns true if the card is past its due date and not yet done.
+    pub fn is_card_overdue(&self, card: &Card, today: NaiveDate) -> bool {
+        card.is_overdue(today) && !self.is_done_column(&card.column_id)
+    }
+
+    /// Appends an entry to the activity log.
+    pub fn record_activity(&mut self, activity: Activity) {
+        self.activity.push(activity);
+    }
+
+    /// Gets the recorded activity of a card, oldest first.
+    pub fn card_history(&self, card_id: &str) -> Vec<&Activity> {
+        self.activity
+            .iter()
+            .filter(|a| a.card_id == card_id)
+            .collect()
+    }
+
+    /// Gets a registered label by name.
+    pub fn get_label(&self, name: &str) -> Option<&Label> {
+        self.labels.iter().find(|l| l.name == name)
+    }
+
+    /// Adds a label to the registry if it is not already known.
+    pub fn register_label(&mut self, name: &str) {
+        if self.get_label(name).is_none() {
+            self.labels.push(Label::new(name.to_string()));
+            self.labels.sort_by(|a, b| a.name.cmp(&b.name));
+            self.updated_at = Utc::now();
+        }
+    }
+
+    /// Returns the names of all labels, registered or used by a card, sorted.
+    pub fn label_names(&self) -> Vec<String> {
+        let names: BTreeSet<&String> = self
+            .labels
+            .iter()
+            .map(|l| &l.name)
+            .chain(self.cards.iter().flat_map(|c| c.labels.iter()))
+            .collect();
+        names.into_iter().cloned().collect()
+    }
+
+    /// Counts the cards carrying a label.
+    pub fn count_cards_with_label(&self, name: &str) -> usize {
+        self.cards.iter().filter(|c| c.has_label(name)).count()
+    }
+
+    /// Renames a label on every card and in the registry.
+    ///
+    /// If the new name already exists the two labels are merged, keeping
+    /// the color of the new label when it has one.
+    ///
+    /// Returns the number of cards that were relabeled.
+    pub fn rename_label(&mut self, old_name: &str, new_name: String) -> usize {
+        let mut count = 0;
+        for card in &mut self.cards {
+            if card.remove_label(old_name) {
+                card.add_label(new_name.clone());
+                count += 1;
+            }
+        }
+
+        if let Some(index) = self.labels.iter().position(|l| l.name == old_name) {
+            let old = self.labels.remove(index);
+            match self.labels.iter_mut().find(|l| l.name == new_name) {
+                Some(existing) => {
+                    if existing.color.is_none() {
+                        existing.color = old.color;
+                    }
+                }
+                None => self.labels.push(Label {
+                    name: new_name,
+                    color: old.color,
+                }),
+            }
+            self.labels.sort_by(|a, b| a.name.cmp(&b.name));
+        }
+
+        self.updated_at = Utc::now();
+        count
+    }
+
+    /// Removes a label from every card and from the registry.
+    ///
+    /// Returns the number of cards the label was removed from.
+    pub fn delete_label(&mut self, name: &str) -> usize {
+        let mut count = 0;
+        for card in &mut self.cards {
+            if card.remove_label(name) {
+                count += 1;
+            }
+        }
+        self.labels.retain(|l| l.name != name);
+        self.updated_at = Utc::now();
+        count
+    }
+
+    /// Sets or clears the color of a label, registering it if needed.
+    pub fn set_label_color(&mut self, name: &str, color: Option<String>) {
+        self.register_label(name);
+        if let Some(label) = self.labels.iter_mut().find(|l| l.name == name) {
+            label.color = color;
+        }
+        self.updated_at = Utc::now();
+    }
 }
 
 #[cfg(test)]
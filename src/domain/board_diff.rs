@@ -0,0 +1,89 @@
+use serde::Serialize;
+
+use super::{Board, Card, FieldChange};
+
+/// Differences between two versions of a board's cards.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct BoardDiff {
+    /// Cards only in the newer version
+    pub added: Vec<Card>,
+    /// Cards only in the older version
+    pub removed: Vec<Card>,
+    /// Cards in both versions that differ
+    pub changed: Vec<CardDiff>,
+}
+
+/// The changes made to one card between two versions of a board.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CardDiff {
+    pub card_id: String,
+    /// Title in the newer version
+    pub title: String,
+    pub changes: Vec<FieldChange>,
+}
+
+impl BoardDiff {
+    /// Compares the cards of an older and a newer version of a board.
+    ///
+    /// Besides the fields tracked in the activity log, reports moves
+    /// between columns, archiving and the number of comments.
+    pub fn between(before: &Board, after: &Board) -> Self {
+        let mut diff = BoardDiff::default();
+
+        for card in &after.cards {
+            let Some(old) = before.get_card(&card.id) else {
+                diff.added.push(card.clone());
+                continue;
+            };
+
+            let mut changes = Vec::new();
+            if old.column_id != card.column_id {
+                changes.push(FieldChange {
+                    field: "column".to_string(),
+                    before: Some(old.column_id.clone()),
+                    after: Some(card.column_id.clone()),
+                });
+            }
+            changes.extend(FieldChange::between(old, card));
+            if old.is_archived() != card.is_archived() {
+                changes.push(FieldChange {
+                    field: "archived".to_string(),
+                    before: Some(old.is_archived().to_string()),
+                    after: Some(card.is_archived().to_string()),
+                });
+            }
+            if old.comments.len() != card.comments.len() {
+                changes.push(FieldChange {
+                    field: "comments".to_string(),
+                    before: Some(old.comments.len().to_string()),
+                    after: Some(card.comments.len().to_string()),
+                });
+            }
+
+            if !changes.is_empty() {
+                diff.changed.push(CardDiff {
+                    card_id: card.id.clone(),
+                    title: card.title.clone(),
+                    changes,
+                });
+            }
+        }
+
+        diff.removed = before
+            .cards
+            .iter()
+            .filter(|card| after.get_card(&card.id).is_none())
+            .cloned()
+            .collect();
+        diff
+    }
+
+    /// Returns true if the versions have the same cards.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[cfg(test)]
+#[path = "./board_diff_tests.rs"]
+mod board_diff_tests;
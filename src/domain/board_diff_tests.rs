@@ -0,0 +1,46 @@
+use super::*;
+use crate::domain::Priority;
+
+#[test]
+fn test_diff_of_identical_boards_is_empty() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    board.create_card("Task".to_string(), None, None, None);
+
+    // Act
+    let diff = BoardDiff::between(&board, &board.clone());
+
+    // Assert
+    assert!(diff.is_empty());
+}
+
+#[test]
+fn test_diff_reports_added_removed_and_changed_cards() {
+    // Arrange
+    let mut before = Board::new("test".to_string(), "Test".to_string());
+    before.create_card("Kept".to_string(), None, None, None);
+    before.create_card("Deleted".to_string(), None, None, None);
+    let mut after = before.clone();
+    after.delete_card("TES-002");
+    after.create_card("New".to_string(), None, None, None);
+    after.move_card("TES-001", "done");
+    let card = after.get_card_mut("TES-001").unwrap();
+    card.set_priority(Some(Priority::High));
+    card.add_comment("alice".to_string(), "Done".to_string());
+
+    // Act
+    let diff = BoardDiff::between(&before, &after);
+
+    // Assert
+    assert_eq!(diff.added.len(), 1);
+    assert_eq!(diff.added[0].title, "New");
+    assert_eq!(diff.removed.len(), 1);
+    assert_eq!(diff.removed[0].title, "Deleted");
+    assert_eq!(diff.changed.len(), 1);
+    let fields: Vec<&str> = diff.changed[0]
+        .changes
+        .iter()
+        .map(|c| c.field.as_str())
+        .collect();
+    assert_eq!(fields, vec!["column", "priority", "comments"]);
+}
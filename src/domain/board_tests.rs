@@ -1,4 +1,5 @@
 use super::*;
+use crate::domain::{Activity, CardPosition, Priority};
 
 #[test]
 fn test_board_creation() {
@@ -52,6 +53,20 @@ fn test_create_card() {
     assert!(todo_column.has_card(&card_id));
 }
 
+#[test]
+fn test_create_card_in_unknown_column_uses_first_column() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+
+    // Act
+    let card_id = board.create_card("Task".to_string(), None, None, Some("missing".to_string()));
+
+    // Assert
+    assert_eq!(board.get_card(&card_id).unwrap().column_id, "todo");
+    assert!(board.columns[0].has_card(&card_id));
+    assert!(board.check().is_empty());
+}
+
 #[test]
 fn test_move_card() {
     // Arrange
@@ -93,3 +108,363 @@ fn test_delete_card() {
     let todo_column = board.columns.iter().find(|c| c.id == "todo").unwrap();
     assert!(!todo_column.has_card(&card_id));
 }
+
+#[test]
+fn test_remove_column_moves_cards_to_target() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    let card_id = board.create_card("Task".to_string(), None, None, None);
+
+    // Act
+    let removed = board.remove_column("todo", Some("done"));
+
+    // Assert
+    assert!(removed);
+    assert!(board.columns.iter().all(|c| c.id != "todo"));
+    assert_eq!(board.get_card(&card_id).unwrap().column_id, "done");
+    let done_column = board.columns.iter().find(|c| c.id == "done").unwrap();
+    assert!(done_column.has_card(&card_id));
+    assert_eq!(board.columns[0].order, 0);
+    assert_eq!(board.columns[1].order, 1);
+}
+
+#[test]
+fn test_remove_column_with_cards_requires_target() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    board.create_card("Task".to_string(), None, None, None);
+
+    // Act & Assert
+    assert!(!board.remove_column("todo", None));
+    assert!(!board.remove_column("todo", Some("todo")));
+    assert!(!board.remove_column("todo", Some("missing")));
+    assert_eq!(board.columns.len(), 3);
+}
+
+#[test]
+fn test_remove_empty_column_without_target() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+
+    // Act
+    let removed = board.remove_column("in_progress", None);
+
+    // Assert
+    assert!(removed);
+    assert_eq!(board.columns.len(), 2);
+}
+
+#[test]
+fn test_rename_column() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+
+    // Act & Assert
+    assert!(board.rename_column("todo", "Backlog".to_string()));
+    assert_eq!(board.columns[0].name, "Backlog");
+    assert!(!board.rename_column("missing", "Nope".to_string()));
+}
+
+#[test]
+fn test_move_column() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+
+    // Act
+    let moved = board.move_column("done", 0);
+
+    // Assert
+    assert!(moved);
+    let ids: Vec<&str> = board.columns.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, vec!["done", "todo", "in_progress"]);
+    let orders: Vec<u32> = board.columns.iter().map(|c| c.order).collect();
+    assert_eq!(orders, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_get_cards_in_column_follows_column_order() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    let first_id = board.create_card("First".to_string(), None, None, None);
+    let second_id = board.create_card("Second".to_string(), None, None, None);
+    let third_id = board.create_card("Third".to_string(), None, None, None);
+    board
+        .get_card_mut(&third_id)
+        .unwrap()
+        .set_priority(Some(Priority::Critical));
+    board.columns[0].cards = vec![second_id.clone(), third_id.clone(), first_id.clone()];
+
+    // Act
+    let ids: Vec<&str> = board
+        .get_cards_in_column("todo")
+        .iter()
+        .map(|c| c.id.as_str())
+        .collect();
+
+    // Assert
+    assert_eq!(
+        ids,
+        vec![second_id.as_str(), third_id.as_str(), first_id.as_str()]
+    );
+}
+
+#[test]
+fn test_rank_card() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    for title in ["A", "B", "C"] {
+        board.create_card(title.to_string(), None, None, None);
+    }
+
+    // Act & Assert
+    assert!(board.rank_card("TES-003", &CardPosition::Top));
+    assert_eq!(
+        board.columns[0].cards,
+        vec!["TES-003", "TES-001", "TES-002"]
+    );
+    assert!(board.rank_card("TES-003", &CardPosition::After("TES-002".to_string())));
+    assert_eq!(
+        board.columns[0].cards,
+        vec!["TES-001", "TES-002", "TES-003"]
+    );
+    assert!(board.rank_card("TES-001", &CardPosition::Index(1)));
+    assert_eq!(
+        board.columns[0].cards,
+        vec!["TES-002", "TES-001", "TES-003"]
+    );
+    assert!(!board.rank_card("TES-001", &CardPosition::Before("TES-009".to_string())));
+    assert_eq!(
+        board.columns[0].cards,
+        vec!["TES-002", "TES-001", "TES-003"]
+    );
+}
+
+#[test]
+fn test_rank_card_by_index_skips_archived_cards() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    for title in ["A", "B", "C"] {
+        board.create_card(title.to_string(), None, None, None);
+    }
+    board.get_card_mut("TES-001").unwrap().archive();
+
+    // Act
+    board.rank_card("TES-003", &CardPosition::Index(0));
+
+    // Assert
+    assert_eq!(
+        board.columns[0].cards,
+        vec!["TES-001", "TES-003", "TES-002"]
+    );
+    let shown: Vec<&str> = board
+        .get_cards_in_column("todo")
+        .iter()
+        .map(|c| c.id.as_str())
+        .collect();
+    assert_eq!(shown, vec!["TES-003", "TES-002"]);
+}
+
+#[test]
+fn test_move_card_keeps_order() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    for title in ["A", "B", "C"] {
+        board.create_card(title.to_string(), None, None, None);
+    }
+    board.move_card("TES-001", "done");
+
+    // Act
+    board.move_card("TES-002", "todo");
+    board.move_card_to("TES-003", "done", &CardPosition::Top);
+
+    // Assert
+    assert_eq!(board.columns[0].cards, vec!["TES-002"]);
+    assert_eq!(board.columns[2].cards, vec!["TES-003", "TES-001"]);
+    assert_eq!(board.get_card("TES-003").unwrap().column_id, "done");
+}
+
+#[test]
+fn test_move_card_places_card_by_priority() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    for (title, priority) in [
+        ("A", Some(Priority::High)),
+        ("B", Some(Priority::Low)),
+        ("C", None),
+        ("D", Some(Priority::Medium)),
+    ] {
+        let id = board.create_card(title.to_string(), None, None, None);
+        board.get_card_mut(&id).unwrap().set_priority(priority);
+        board.move_card(&id, "done");
+    }
+
+    // Act
+    for id in ["TES-003", "TES-002", "TES-001", "TES-004"] {
+        board.move_card(id, "todo");
+    }
+
+    // Assert
+    assert_eq!(
+        board.columns[0].cards,
+        vec!["TES-001", "TES-004", "TES-002", "TES-003"]
+    );
+}
+
+#[test]
+fn test_rename_label_merges_into_existing() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    let first = board.create_card("One".to_string(), None, None, None);
+    let second = board.create_card("Two".to_string(), None, None, None);
+    board
+        .get_card_mut(&first)
+        .unwrap()
+        .add_label("bug".to_string());
+    board
+        .get_card_mut(&second)
+        .unwrap()
+        .add_label("defect".to_string());
+    board.set_label_color("defect", Some("red".to_string()));
+
+    // Act
+    let count = board.rename_label("defect", "bug".to_string());
+
+    // Assert
+    assert_eq!(count, 1);
+    assert!(board.get_card(&second).unwrap().has_label("bug"));
+    assert_eq!(board.label_names(), vec!["bug".to_string()]);
+    assert_eq!(
+        board.get_label("bug").unwrap().color.as_deref(),
+        Some("red")
+    );
+}
+
+#[test]
+fn test_delete_label() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    let card_id = board.create_card("One".to_string(), None, None, None);
+    board
+        .get_card_mut(&card_id)
+        .unwrap()
+        .add_label("bug".to_string());
+    board.register_label("bug");
+
+    // Act
+    let count = board.delete_label("bug");
+
+    // Assert
+    assert_eq!(count, 1);
+    assert!(board.label_names().is_empty());
+    assert_eq!(board.count_cards_with_label("bug"), 0);
+}
+
+#[test]
+fn test_overdue_ignores_done_column() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    let today = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+    let open = board.create_card("Open".to_string(), None, None, None);
+    let done = board.create_card("Done".to_string(), None, None, Some("done".to_string()));
+    for id in [&open, &done] {
+        board.get_card_mut(id).unwrap().due = NaiveDate::from_ymd_opt(2024, 5, 1);
+    }
+    let filter = CardFilter {
+        overdue_on: Some(today),
+        ..Default::default()
+    };
+
+    // Act
+    let overdue_todo = board.get_matching_cards_in_column("todo", &filter);
+    let overdue_done = board.get_matching_cards_in_column("done", &filter);
+
+    // Assert
+    assert_eq!(overdue_todo.len(), 1);
+    assert!(overdue_done.is_empty());
+    assert!(board.is_card_overdue(board.get_card(&open).unwrap(), today));
+    assert!(!board.is_card_overdue(board.get_card(&done).unwrap(), today));
+}
+
+#[test]
+fn test_random_card_ids() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    board.card_id_scheme = CardIdScheme::Random;
+
+    // Act
+    let ids: BTreeSet<String> = (0..50)
+        .map(|_| board.create_card("Task".to_string(), None, None, None))
+        .collect();
+
+    // Assert
+    assert_eq!(ids.len(), 50);
+    assert_eq!(board.next_card_number, 1);
+    for id in &ids {
+        let suffix = id.strip_prefix("TES-").unwrap();
+        assert_eq!(suffix.len(), 6);
+        assert!(suffix.bytes().all(|b| ID_ALPHABET.contains(&b)));
+    }
+}
+
+#[test]
+fn test_archived_cards_are_hidden_from_column() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    board.create_card("Kept".to_string(), None, None, None);
+    board.create_card("Archived".to_string(), None, None, None);
+
+    // Act
+    board.get_card_mut("TES-002").unwrap().archive();
+
+    // Assert
+    let shown: Vec<&str> = board
+        .get_cards_in_column("todo")
+        .iter()
+        .map(|c| c.id.as_str())
+        .collect();
+    assert_eq!(shown, vec!["TES-001"]);
+    assert_eq!(board.archived_cards()[0].id, "TES-002");
+    assert_eq!(board.cards.len(), 2);
+}
+
+#[test]
+fn test_archive_done_cards_before_cutoff() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    board.create_card("Done".to_string(), None, None, None);
+    board.create_card("Open".to_string(), None, None, None);
+    board.move_card("TES-001", "done");
+    let cutoff = Utc::now() + chrono::Duration::seconds(1);
+
+    // Act
+    let archived = board.archive_done_cards(cutoff);
+
+    // Assert
+    assert_eq!(archived, vec!["TES-001"]);
+    assert!(board.get_card("TES-001").unwrap().is_archived());
+    assert!(!board.get_card("TES-002").unwrap().is_archived());
+    assert!(board.archive_done_cards(cutoff).is_empty());
+}
+
+#[test]
+fn test_archive_done_cards_keeps_recent_cards() {
+    // Arrange
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    board.create_card("Done".to_string(), None, None, None);
+    board.move_card("TES-001", "done");
+    board.record_activity(Activity::new(
+        "TES-001".to_string(),
+        "alice".to_string(),
+        ActivityKind::Moved {
+            from: "todo".to_string(),
+            to: "done".to_string(),
+        },
+    ));
+    let cutoff = board.get_card("TES-001").unwrap().created_at;
+
+    // Act
+    let archived = board.archive_done_cards(cutoff);
+
+    // Assert
+    assert!(archived.is_empty());
+}
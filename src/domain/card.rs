@@ -1,6 +1,10 @@
-use chrono::{DateTime, Utc};
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::{ChecklistItem, Claim, Comment, Priority};
+
 /// Represents a task card in the kanban board.
 ///
 /// Cards are the primary unit of work in Clicky. Each card has a unique ID,
@@ -17,9 +21,39 @@ pub struct Card {
     pub column_id: String,
     /// Optional assignee name
     pub assignee: Option<String>,
+    /// Optional priority (absent in boards created before priorities existed)
+    #[serde(default)]
+    pub priority: Option<Priority>,
+    /// Labels attached to the card, kept sorted and unique
+    #[serde(default)]
+    pub labels: BTreeSet<String>,
+    /// Optional due date
+    #[serde(default)]
+    pub due: Option<NaiveDate>,
+    /// Lease held by whoever is working on the card
+    #[serde(default)]
+    pub claim: Option<Claim>,
+    /// ID of the card this one is part of, e.g. its epic
+    #[serde(default)]
+    pub parent: Option<String>,
+    /// IDs of the cards that must be done before this one can start
+    #[serde(default)]
+    pub blocked_by: BTreeSet<String>,
+    /// Steps to complete the card, in order
+    #[serde(default)]
+    pub checklist: Vec<ChecklistItem>,
+    /// Discussion thread, oldest first
+    #[serde(default)]
+    pub comments: Vec<Comment>,
+    /// When the card was archived; archived cards are hidden from the board
+    #[serde(default)]
+    pub archived_at: Option<DateTime<Utc>>,
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
     /// Last update timestamp
+    ///
+    /// Every change to the card's fields sets it: the SQLite backend only
+    /// rewrites cards whose timestamp differs from the stored one.
     pub updated_at: DateTime<Utc>,
 }
 
@@ -47,6 +81,15 @@ impl Card {
             description: None,
             column_id,
             assignee: None,
+            priority: None,
+            labels: BTreeSet::new(),
+            due: None,
+            claim: None,
+            parent: None,
+            blocked_by: BTreeSet::new(),
+            checklist: Vec::new(),
+            comments: Vec::new(),
+            archived_at: None,
             created_at: now,
             updated_at: now,
         }
@@ -78,6 +121,80 @@ impl Card {
         self.assignee = assignee;
         self.updated_at = Utc::now();
     }
+
+    /// Updates the card's priority.
+    pub fn set_priority(&mut self, priority: Option<Priority>) {
+        self.priority = priority;
+        self.updated_at = Utc::now();
+    }
+
+    /// Updates the card's due date.
+    pub fn set_due(&mut self, due: Option<NaiveDate>) {
+        self.due = due;
+        self.updated_at = Utc::now();
+    }
+
+    /// Returns true if the card's due date lies before `today`.
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.due.is_some_and(|due| due < today)
+    }
+
+    /// Appends a comment to the card's discussion thread.
+    pub fn add_comment(&mut self, author: String, body: String) {
+        self.comments.push(Comment::new(author, body));
+        self.updated_at = Utc::now();
+    }
+
+    /// Returns true if the card is archived.
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    /// Archives the card, hiding it from the board.
+    pub fn archive(&mut self) {
+        let now = Utc::now();
+        self.archived_at = Some(now);
+        self.updated_at = now;
+    }
+
+    /// Returns an archived card to the board.
+    pub fn unarchive(&mut self) {
+        self.archived_at = None;
+        self.updated_at = Utc::now();
+    }
+
+    /// Returns true if the card has the given label.
+    pub fn has_label(&self, label: &str) -> bool {
+        self.labels.contains(label)
+    }
+
+    /// Adds a label to the card.
+    ///
+    /// Returns false if the card already had the label.
+    pub fn add_label(&mut self, label: String) -> bool {
+        let added = self.labels.insert(label);
+        if added {
+            self.updated_at = Utc::now();
+        }
+        added
+    }
+
+    /// Removes a label from the card.
+    ///
+    /// Returns false if the card did not have the label.
+    pub fn remove_label(&mut self, label: &str) -> bool {
+        let removed = self.labels.remove(label);
+        if removed {
+            self.updated_at = Utc::now();
+        }
+        removed
+    }
+
+    /// Replaces all labels of the card.
+    pub fn set_labels(&mut self, labels: BTreeSet<String>) {
+        self.labels = labels;
+        self.updated_at = Utc::now();
+    }
 }
 
 #[cfg(test)]
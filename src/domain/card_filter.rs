@@ -0,0 +1,68 @@
+use chrono::NaiveDate;
+
+use super::{Card, Priority};
+
+/// How multiple label criteria are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LabelMatch {
+    /// The card must carry every requested label
+    #[default]
+    All,
+    /// The card must carry at least one requested label
+    Any,
+}
+
+/// Criteria for selecting cards.
+///
+/// Criteria left empty match every card; all set criteria must match.
+#[derive(Debug, Clone, Default)]
+pub struct CardFilter {
+    /// Only cards in this column
+    pub column: Option<String>,
+    /// Only cards assigned to this person
+    pub assignee: Option<String>,
+    /// Only cards with this priority
+    pub priority: Option<Priority>,
+    /// Only cards carrying these labels
+    pub labels: Vec<String>,
+    /// How `labels` are combined
+    pub label_match: LabelMatch,
+    /// Only cards due strictly before this date
+    pub due_before: Option<NaiveDate>,
+    /// Only cards that are overdue as of this date
+    pub overdue_on: Option<NaiveDate>,
+    /// Only archived cards instead of the cards on the board
+    pub archived: bool,
+}
+
+impl CardFilter {
+    /// Returns true if the card satisfies every criterion of the filter.
+    pub fn matches(&self, card: &Card) -> bool {
+        card.is_archived() == self.archived
+            && self.column.as_ref().is_none_or(|c| card.column_id == *c)
+            && self
+                .assignee
+                .as_ref()
+                .is_none_or(|a| card.assignee.as_ref() == Some(a))
+            && self.priority.is_none_or(|p| card.priority == Some(p))
+            && self.matches_labels(card)
+            && self
+                .due_before
+                .is_none_or(|date| card.due.is_some_and(|due| due < date))
+            && self.overdue_on.is_none_or(|today| card.is_overdue(today))
+    }
+
+    fn matches_labels(&self, card: &Card) -> bool {
+        if self.labels.is_empty() {
+            return true;
+        }
+        match self.label_match {
+            LabelMatch::All => self.labels.iter().all(|l| card.has_label(l)),
+            LabelMatch::Any => self.labels.iter().any(|l| card.has_label(l)),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./card_filter_tests.rs"]
+mod card_filter_tests;
@@ -0,0 +1,125 @@
+use super::*;
+
+fn card_with_labels(labels: &[&str]) -> Card {
+    let mut card = Card::new(
+        "PRJ-001".to_string(),
+        "Task".to_string(),
+        "todo".to_string(),
+    );
+    for label in labels {
+        card.add_label(label.to_string());
+    }
+    card
+}
+
+#[test]
+fn test_empty_filter_matches_everything() {
+    // Arrange
+    let card = card_with_labels(&[]);
+
+    // Act & Assert
+    assert!(CardFilter::default().matches(&card));
+}
+
+#[test]
+fn test_filter_by_column_and_assignee() {
+    // Arrange
+    let mut card = card_with_labels(&[]);
+    card.assignee = Some("Alice".to_string());
+
+    let filter = CardFilter {
+        column: Some("todo".to_string()),
+        assignee: Some("Alice".to_string()),
+        ..Default::default()
+    };
+    let other_column = CardFilter {
+        column: Some("done".to_string()),
+        ..Default::default()
+    };
+
+    // Act & Assert
+    assert!(filter.matches(&card));
+    assert!(!other_column.matches(&card));
+}
+
+#[test]
+fn test_filter_by_labels_all() {
+    // Arrange
+    let card = card_with_labels(&["bug", "frontend"]);
+    let both = CardFilter {
+        labels: vec!["bug".to_string(), "frontend".to_string()],
+        ..Default::default()
+    };
+    let with_missing = CardFilter {
+        labels: vec!["bug".to_string(), "backend".to_string()],
+        ..Default::default()
+    };
+
+    // Act & Assert
+    assert!(both.matches(&card));
+    assert!(!with_missing.matches(&card));
+}
+
+#[test]
+fn test_filter_by_labels_any() {
+    // Arrange
+    let card = card_with_labels(&["bug"]);
+    let filter = CardFilter {
+        labels: vec!["bug".to_string(), "backend".to_string()],
+        label_match: LabelMatch::Any,
+        ..Default::default()
+    };
+    let none_present = CardFilter {
+        labels: vec!["docs".to_string()],
+        label_match: LabelMatch::Any,
+        ..Default::default()
+    };
+
+    // Act & Assert
+    assert!(filter.matches(&card));
+    assert!(!none_present.matches(&card));
+}
+
+#[test]
+fn test_filter_by_due_dates() {
+    // Arrange
+    let mut card = card_with_labels(&[]);
+    card.due = NaiveDate::from_ymd_opt(2024, 5, 10);
+    let undated = card_with_labels(&[]);
+
+    let due_before = CardFilter {
+        due_before: NaiveDate::from_ymd_opt(2024, 5, 11),
+        ..Default::default()
+    };
+    let overdue_early = CardFilter {
+        overdue_on: NaiveDate::from_ymd_opt(2024, 5, 10),
+        ..Default::default()
+    };
+    let overdue_late = CardFilter {
+        overdue_on: NaiveDate::from_ymd_opt(2024, 5, 11),
+        ..Default::default()
+    };
+
+    // Act & Assert
+    assert!(due_before.matches(&card));
+    assert!(!due_before.matches(&undated));
+    assert!(!overdue_early.matches(&card));
+    assert!(overdue_late.matches(&card));
+    assert!(!overdue_late.matches(&undated));
+}
+
+#[test]
+fn test_filter_by_archived() {
+    // Arrange
+    let mut card = card_with_labels(&[]);
+    let archived = CardFilter {
+        archived: true,
+        ..Default::default()
+    };
+
+    // Act & Assert
+    assert!(!archived.matches(&card));
+    card.archive();
+    assert!(archived.matches(&card));
+    assert!(!CardFilter::default().matches(&card));
+}
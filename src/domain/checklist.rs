@@ -0,0 +1,103 @@
+use std::fmt;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::Card;
+
+/// One step of the checklist inside a card.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    /// What needs to be done
+    pub text: String,
+    /// Whether the step is done
+    #[serde(default)]
+    pub done: bool,
+}
+
+impl ChecklistItem {
+    /// Creates an open checklist item.
+    pub fn new(text: String) -> Self {
+        Self { text, done: false }
+    }
+}
+
+impl fmt::Display for ChecklistItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mark = if self.done { "x" } else { " " };
+        write!(f, "[{}] {}", mark, self.text)
+    }
+}
+
+impl Card {
+    /// Appends an open item to the checklist.
+    ///
+    /// Returns the index of the new item.
+    pub fn add_checklist_item(&mut self, text: String) -> usize {
+        self.checklist.push(ChecklistItem::new(text));
+        self.updated_at = Utc::now();
+        self.checklist.len() - 1
+    }
+
+    /// Checks or unchecks a checklist item.
+    ///
+    /// Returns false if there is no item at `index`.
+    pub fn set_checklist_item_done(&mut self, index: usize, done: bool) -> bool {
+        let Some(item) = self.checklist.get_mut(index) else {
+            return false;
+        };
+        if item.done != done {
+            item.done = done;
+            self.updated_at = Utc::now();
+        }
+        true
+    }
+
+    /// Moves a checklist item so that it ends up at index `to`.
+    ///
+    /// Indexes past the end move the item to the end. Returns false if there
+    /// is no item at `from`.
+    pub fn move_checklist_item(&mut self, from: usize, to: usize) -> bool {
+        if from >= self.checklist.len() {
+            return false;
+        }
+        let item = self.checklist.remove(from);
+        let to = to.min(self.checklist.len());
+        self.checklist.insert(to, item);
+        if from != to {
+            self.updated_at = Utc::now();
+        }
+        true
+    }
+
+    /// Removes a checklist item.
+    ///
+    /// Returns the removed item, or `None` if there is no item at `index`.
+    pub fn remove_checklist_item(&mut self, index: usize) -> Option<ChecklistItem> {
+        if index >= self.checklist.len() {
+            return None;
+        }
+        let item = self.checklist.remove(index);
+        self.updated_at = Utc::now();
+        Some(item)
+    }
+
+    /// Gets the number of done items and the total number of items, or
+    /// `None` if the card has no checklist.
+    pub fn checklist_progress(&self) -> Option<(usize, usize)> {
+        if self.checklist.is_empty() {
+            return None;
+        }
+        let done = self.checklist.iter().filter(|item| item.done).count();
+        Some((done, self.checklist.len()))
+    }
+
+    /// Gets the number of checklist items that are not done yet.
+    pub fn open_checklist_items(&self) -> usize {
+        self.checklist.iter().filter(|item| !item.done).count()
+    }
+}
+
+#[cfg(test)]
+#[path = "./checklist_tests.rs"]
+mod checklist_tests;
@@ -0,0 +1,97 @@
+use super::*;
+
+fn card_with_checklist(items: &[&str]) -> Card {
+    let mut card = Card::new(
+        "PRJ-001".to_string(),
+        "Task".to_string(),
+        "todo".to_string(),
+    );
+    for item in items {
+        card.add_checklist_item(item.to_string());
+    }
+    card
+}
+
+fn texts(card: &Card) -> Vec<&str> {
+    card.checklist.iter().map(|i| i.text.as_str()).collect()
+}
+
+#[test]
+fn test_add_and_check_items() {
+    // Arrange
+    let mut card = card_with_checklist(&["Write tests"]);
+
+    // Act
+    let index = card.add_checklist_item("Update docs".to_string());
+    let checked = card.set_checklist_item_done(0, true);
+    let missing = card.set_checklist_item_done(5, true);
+
+    // Assert
+    assert_eq!(index, 1);
+    assert!(checked);
+    assert!(!missing);
+    assert_eq!(card.checklist_progress(), Some((1, 2)));
+    assert_eq!(card.open_checklist_items(), 1);
+    assert_eq!(card.checklist[0].to_string(), "[x] Write tests");
+    assert_eq!(card.checklist[1].to_string(), "[ ] Update docs");
+}
+
+#[test]
+fn test_uncheck_item() {
+    // Arrange
+    let mut card = card_with_checklist(&["Write tests"]);
+    card.set_checklist_item_done(0, true);
+
+    // Act
+    card.set_checklist_item_done(0, false);
+
+    // Assert
+    assert_eq!(card.checklist_progress(), Some((0, 1)));
+}
+
+#[test]
+fn test_move_item() {
+    // Arrange
+    let mut card = card_with_checklist(&["a", "b", "c"]);
+
+    // Act
+    let moved = card.move_checklist_item(2, 0);
+
+    // Assert
+    assert!(moved);
+    assert_eq!(texts(&card), vec!["c", "a", "b"]);
+
+    // Act
+    card.move_checklist_item(0, 10);
+
+    // Assert
+    assert_eq!(texts(&card), vec!["a", "b", "c"]);
+    assert!(!card.move_checklist_item(3, 0));
+}
+
+#[test]
+fn test_remove_item() {
+    // Arrange
+    let mut card = card_with_checklist(&["a", "b"]);
+
+    // Act
+    let removed = card.remove_checklist_item(0);
+
+    // Assert
+    assert_eq!(removed, Some(ChecklistItem::new("a".to_string())));
+    assert_eq!(texts(&card), vec!["b"]);
+    assert_eq!(card.remove_checklist_item(1), None);
+}
+
+#[test]
+fn test_progress_without_checklist() {
+    // Arrange
+    let card = card_with_checklist(&[]);
+
+    // Act
+    let progress = card.checklist_progress();
+
+    // Assert
+    assert_eq!(progress, None);
+    assert_eq!(card.open_checklist_items(), 0);
+}
@@ -0,0 +1,136 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{Board, Card};
+
+/// A lease on a card held by the person or agent working on it.
+///
+/// A claim lapses at `expires_at` unless it is renewed, so cards taken by
+/// an agent that crashed return to the pool.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Claim {
+    /// Who holds the claim
+    pub claimant: String,
+    /// When the card was claimed
+    pub claimed_at: DateTime<Utc>,
+    /// When the claim lapses unless it is renewed
+    pub expires_at: DateTime<Utc>,
+    /// How long each renewal extends the claim, in seconds
+    pub ttl_seconds: i64,
+    /// Column the card was in when it was claimed
+    pub previous_column: String,
+    /// Assignee of the card before it was claimed
+    pub previous_assignee: Option<String>,
+}
+
+impl Claim {
+    /// Returns true if the claim has lapsed at `now`.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+impl Card {
+    /// Gets the claim on the card, unless it has lapsed at `now`.
+    pub fn active_claim(&self, now: DateTime<Utc>) -> Option<&Claim> {
+        self.claim.as_ref().filter(|c| !c.is_expired(now))
+    }
+}
+
+impl Board {
+    /// Claims a card for `claimant` for `ttl` from `now`, assigning it to
+    /// them.
+    ///
+    /// An existing claim is replaced, but the card still returns to where
+    /// it was before the first claim. Returns true if the card was found.
+    pub fn claim_card(
+        &mut self,
+        card_id: &str,
+        claimant: &str,
+        ttl: Duration,
+        now: DateTime<Utc>,
+    ) -> bool {
+        let Some(card) = self.get_card_mut(card_id) else {
+            return false;
+        };
+        let (previous_column, previous_assignee) = match card.claim.take() {
+            Some(claim) => (claim.previous_column, claim.previous_assignee),
+            None => (card.column_id.clone(), card.assignee.clone()),
+        };
+        card.claim = Some(Claim {
+            claimant: claimant.to_string(),
+            claimed_at: now,
+            expires_at: now + ttl,
+            ttl_seconds: ttl.num_seconds(),
+            previous_column,
+            previous_assignee,
+        });
+        card.set_assignee(Some(claimant.to_string()));
+        self.updated_at = Utc::now();
+        true
+    }
+
+    /// Extends the claim on a card by its TTL from `now`.
+    ///
+    /// Returns true if the card has a claim.
+    pub fn renew_claim(&mut self, card_id: &str, now: DateTime<Utc>) -> bool {
+        let Some(card) = self.get_card_mut(card_id) else {
+            return false;
+        };
+        let Some(claim) = card.claim.as_mut() else {
+            return false;
+        };
+        claim.expires_at = now + Duration::seconds(claim.ttl_seconds);
+        card.updated_at = Utc::now();
+        self.updated_at = Utc::now();
+        true
+    }
+
+    /// Removes the claim on a card.
+    ///
+    /// Unless the card is done, it returns to the column and assignee it
+    /// had before it was claimed. Returns true if the card had a claim.
+    pub fn release_claim(&mut self, card_id: &str) -> bool {
+        let Some(card) = self.get_card_mut(card_id) else {
+            return false;
+        };
+        let Some(claim) = card.claim.take() else {
+            return false;
+        };
+        card.updated_at = Utc::now();
+        let done = self
+            .get_card(card_id)
+            .is_some_and(|c| self.is_done_column(&c.column_id));
+        if !done {
+            if let Some(card) = self.get_card_mut(card_id) {
+                card.set_assignee(claim.previous_assignee);
+            }
+            if self.columns.iter().any(|c| c.id == claim.previous_column) {
+                self.move_card(card_id, &claim.previous_column);
+            }
+        }
+        self.updated_at = Utc::now();
+        true
+    }
+
+    /// Releases the claims that have lapsed at `now`, see
+    /// [`Board::release_claim`].
+    ///
+    /// Returns the cards whose claims expired, as they were before.
+    pub fn expire_claims(&mut self, now: DateTime<Utc>) -> Vec<Card> {
+        let expired: Vec<Card> = self
+            .cards
+            .iter()
+            .filter(|c| c.claim.as_ref().is_some_and(|claim| claim.is_expired(now)))
+            .cloned()
+            .collect();
+        for card in &expired {
+            self.release_claim(&card.id);
+        }
+        expired
+    }
+}
+
+#[cfg(test)]
+#[path = "./claim_tests.rs"]
+mod claim_tests;
@@ -0,0 +1,97 @@
+use super::*;
+
+fn board_with_card() -> Board {
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    board.create_card("Task".to_string(), None, Some("bob".to_string()), None);
+    board
+}
+
+fn now() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2024-05-15T12:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+#[test]
+fn test_claim_card_assigns_and_records_lease() {
+    // Arrange
+    let mut board = board_with_card();
+
+    // Act
+    let claimed = board.claim_card("TES-001", "agent-7", Duration::minutes(30), now());
+
+    // Assert
+    assert!(claimed);
+    let card = board.get_card("TES-001").unwrap();
+    let claim = card.active_claim(now()).unwrap();
+    assert_eq!(card.assignee.as_deref(), Some("agent-7"));
+    assert_eq!(claim.claimant, "agent-7");
+    assert_eq!(claim.expires_at, now() + Duration::minutes(30));
+    assert_eq!(claim.previous_column, "todo");
+    assert_eq!(claim.previous_assignee.as_deref(), Some("bob"));
+    assert!(card.active_claim(now() + Duration::minutes(30)).is_none());
+}
+
+#[test]
+fn test_renew_claim_extends_by_ttl() {
+    // Arrange
+    let mut board = board_with_card();
+    board.claim_card("TES-001", "agent-7", Duration::minutes(30), now());
+    let later = now() + Duration::minutes(20);
+    let updated_at = board.get_card("TES-001").unwrap().updated_at;
+
+    // Act
+    let renewed = board.renew_claim("TES-001", later);
+
+    // Assert
+    assert!(renewed);
+    let card = board.get_card("TES-001").unwrap();
+    let claim = card.claim.as_ref().unwrap();
+    assert_eq!(claim.expires_at, later + Duration::minutes(30));
+    assert_eq!(claim.claimed_at, now());
+    assert!(card.updated_at > updated_at);
+}
+
+#[test]
+fn test_expire_claims_returns_cards() {
+    // Arrange
+    let mut board = board_with_card();
+    board.create_card("Other".to_string(), None, None, None);
+    board.claim_card("TES-001", "agent-7", Duration::minutes(30), now());
+    board.move_card("TES-001", "in_progress");
+    board.claim_card("TES-002", "agent-8", Duration::hours(2), now());
+
+    // Act
+    let expired = board.expire_claims(now() + Duration::hours(1));
+
+    // Assert
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].id, "TES-001");
+    assert_eq!(expired[0].column_id, "in_progress");
+    let card = board.get_card("TES-001").unwrap();
+    assert_eq!(card.claim, None);
+    assert_eq!(card.column_id, "todo");
+    assert_eq!(card.assignee.as_deref(), Some("bob"));
+    assert!(board.get_card("TES-002").unwrap().claim.is_some());
+}
+
+#[test]
+fn test_release_claim_keeps_done_card_in_place() {
+    // Arrange
+    let mut board = board_with_card();
+    board.claim_card("TES-001", "agent-7", Duration::minutes(30), now());
+    board.move_card("TES-001", "done");
+    let updated_at = board.get_card("TES-001").unwrap().updated_at;
+
+    // Act
+    let released = board.release_claim("TES-001");
+
+    // Assert
+    assert!(released);
+    let card = board.get_card("TES-001").unwrap();
+    assert_eq!(card.claim, None);
+    assert!(card.updated_at > updated_at);
+    assert_eq!(card.column_id, "done");
+    assert_eq!(card.assignee.as_deref(), Some("agent-7"));
+    assert!(!board.release_claim("TES-001"));
+}
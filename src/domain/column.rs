@@ -1,5 +1,20 @@
 use serde::{Deserialize, Serialize};
 
+/// Where to place a card within a column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardPosition {
+    /// First in the column
+    Top,
+    /// Last in the column
+    Bottom,
+    /// At an index (0 = top), or last if the column is shorter
+    Index(usize),
+    /// Directly before another card of the column
+    Before(String),
+    /// Directly after another card of the column
+    After(String),
+}
+
 /// Represents a column in the kanban board.
 ///
 /// Columns categorize cards by their status or workflow stage.
@@ -14,6 +29,9 @@ pub struct Column {
     pub order: u32,
     /// IDs of cards in this column, in order
     pub cards: Vec<String>,
+    /// Most cards the column should hold, leaving out archived cards
+    #[serde(default)]
+    pub wip_limit: Option<u32>,
 }
 
 impl Column {
@@ -39,6 +57,7 @@ impl Column {
             name,
             order,
             cards: Vec::new(),
+            wip_limit: None,
         }
     }
 
@@ -51,6 +70,35 @@ impl Column {
         }
     }
 
+    /// Puts a card at `position` in this column, taking it out of its
+    /// current place if it is already in the column.
+    ///
+    /// Returns false, leaving the column unchanged, if the card to place it
+    /// before or after is not in the column.
+    pub fn insert_card(&mut self, card_id: &str, position: &CardPosition) -> bool {
+        let mut cards: Vec<String> = self
+            .cards
+            .iter()
+            .filter(|id| *id != card_id)
+            .cloned()
+            .collect();
+        let find = |other: &str| cards.iter().position(|id| id == other);
+        let index = match position {
+            CardPosition::Top => Some(0),
+            CardPosition::Bottom => Some(cards.len()),
+            CardPosition::Index(index) => Some((*index).min(cards.len())),
+            CardPosition::Before(other) => find(other),
+            CardPosition::After(other) => find(other).map(|i| i + 1),
+        };
+        let Some(index) = index else {
+            return false;
+        };
+
+        cards.insert(index, card_id.to_string());
+        self.cards = cards;
+        true
+    }
+
     /// Removes a card from this column.
     ///
     /// Returns true if the card was found and removed, false otherwise.
@@ -73,3 +73,34 @@ fn test_remove_nonexistent_card() {
     assert!(!removed);
     assert_eq!(column.cards.len(), 1);
 }
+
+#[test]
+fn test_insert_card_at_positions() {
+    // Arrange
+    let mut column = Column::new("todo".to_string(), "To Do".to_string(), 0);
+    column.add_card("PRJ-001".to_string());
+    column.add_card("PRJ-002".to_string());
+
+    // Act & Assert
+    assert!(column.insert_card("PRJ-003", &CardPosition::Top));
+    assert_eq!(column.cards, vec!["PRJ-003", "PRJ-001", "PRJ-002"]);
+    assert!(column.insert_card("PRJ-003", &CardPosition::Before("PRJ-002".to_string())));
+    assert_eq!(column.cards, vec!["PRJ-001", "PRJ-003", "PRJ-002"]);
+    assert!(column.insert_card("PRJ-001", &CardPosition::Index(9)));
+    assert_eq!(column.cards, vec!["PRJ-003", "PRJ-002", "PRJ-001"]);
+}
+
+#[test]
+fn test_insert_card_relative_to_missing_card() {
+    // Arrange
+    let mut column = Column::new("todo".to_string(), "To Do".to_string(), 0);
+    column.add_card("PRJ-001".to_string());
+    column.add_card("PRJ-002".to_string());
+
+    // Act
+    let inserted = column.insert_card("PRJ-001", &CardPosition::After("PRJ-001".to_string()));
+
+    // Assert
+    assert!(!inserted);
+    assert_eq!(column.cards, vec!["PRJ-001", "PRJ-002"]);
+}
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A note left on a card by a person or an agent.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Comment {
+    /// Who wrote the comment
+    pub author: String,
+    /// When the comment was written
+    pub created_at: DateTime<Utc>,
+    /// Comment text
+    pub body: String,
+}
+
+impl Comment {
+    /// Creates a new comment timestamped now.
+    pub fn new(author: String, body: String) -> Self {
+        Self {
+            author,
+            created_at: Utc::now(),
+            body,
+        }
+    }
+}
@@ -0,0 +1,371 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use chrono::Utc;
+use serde::Serialize;
+
+use super::{Board, CardIdScheme, Column};
+
+/// Kinds of inconsistencies in a board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    /// Two columns share an ID
+    DuplicateColumn,
+    /// Two cards share an ID
+    DuplicateCard,
+    /// A card's column does not exist
+    UnknownColumn,
+    /// A column lists a card that does not exist
+    DanglingCardReference,
+    /// A column lists a card more than once
+    DuplicateColumnEntry,
+    /// A column lists a card that belongs to another column
+    MisplacedCard,
+    /// A card is not listed in its column
+    OrphanedCard,
+    /// The card counter would hand out an ID that is already used
+    CardNumberBehind,
+    /// A card ID does not start with the board's card ID prefix
+    PrefixMismatch,
+    /// A card is blocked by a card that does not exist
+    DanglingBlocker,
+    /// A card's parent does not exist
+    DanglingParent,
+}
+
+/// An inconsistency found in a board, with the repair for it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Issue {
+    pub kind: IssueKind,
+    /// What is wrong
+    pub message: String,
+    /// What repairing does (or did) about it
+    pub repair: String,
+}
+
+impl Issue {
+    fn new(kind: IssueKind, message: String, repair: String) -> Self {
+        Self {
+            kind,
+            message,
+            repair,
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Board {
+    /// Finds inconsistencies that make card operations misbehave.
+    ///
+    /// A card's `column_id` and its column's card list must agree, and IDs
+    /// must be unique. These checks are cheap enough to run on every load.
+    pub fn check_structure(&self) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        let Some(first_column) = self.columns.first() else {
+            if !self.cards.is_empty() {
+                issues.push(Issue::new(
+                    IssueKind::UnknownColumn,
+                    "board has cards but no columns".to_string(),
+                    "add a \"To Do\" column holding the cards".to_string(),
+                ));
+            }
+            return issues;
+        };
+
+        let mut column_ids = HashSet::new();
+        for column in &self.columns {
+            if !column_ids.insert(column.id.as_str()) {
+                issues.push(Issue::new(
+                    IssueKind::DuplicateColumn,
+                    format!("column {} appears more than once", column.id),
+                    "remove the duplicate column".to_string(),
+                ));
+            }
+        }
+
+        let mut card_columns: HashMap<&str, &str> = HashMap::new();
+        for card in &self.cards {
+            if card_columns.contains_key(card.id.as_str()) {
+                issues.push(Issue::new(
+                    IssueKind::DuplicateCard,
+                    format!("card {} appears more than once", card.id),
+                    "give the duplicate a new ID".to_string(),
+                ));
+                continue;
+            }
+            card_columns.insert(&card.id, &card.column_id);
+            if !column_ids.contains(card.column_id.as_str()) {
+                issues.push(Issue::new(
+                    IssueKind::UnknownColumn,
+                    format!("card {} is in unknown column {}", card.id, card.column_id),
+                    format!("move it to column {}", first_column.id),
+                ));
+            }
+        }
+
+        let mut listed = HashSet::new();
+        for column in &self.columns {
+            let mut seen = HashSet::new();
+            for card_id in &column.cards {
+                if !seen.insert(card_id.as_str()) {
+                    issues.push(Issue::new(
+                        IssueKind::DuplicateColumnEntry,
+                        format!("column {} lists card {} more than once", column.id, card_id),
+                        "remove the repeated entry".to_string(),
+                    ));
+                    continue;
+                }
+                match card_columns.get(card_id.as_str()) {
+                    None => issues.push(Issue::new(
+                        IssueKind::DanglingCardReference,
+                        format!("column {} lists missing card {}", column.id, card_id),
+                        "remove the entry".to_string(),
+                    )),
+                    Some(card_column) if *card_column != column.id => issues.push(Issue::new(
+                        IssueKind::MisplacedCard,
+                        format!(
+                            "column {} lists card {}, which is in column {}",
+                            column.id, card_id, card_column
+                        ),
+                        format!("remove it from column {}", column.id),
+                    )),
+                    Some(_) => {
+                        listed.insert(card_id.as_str());
+                    }
+                }
+            }
+        }
+
+        let mut seen = HashSet::new();
+        for card in &self.cards {
+            let first_instance = seen.insert(card.id.as_str());
+            if first_instance
+                && column_ids.contains(card.column_id.as_str())
+                && !listed.contains(card.id.as_str())
+            {
+                issues.push(Issue::new(
+                    IssueKind::OrphanedCard,
+                    format!("card {} is missing from column {}", card.id, card.column_id),
+                    format!("add it to column {}", card.column_id),
+                ));
+            }
+        }
+
+        if let Some(used) = self.highest_card_number() {
+            if self.card_id_scheme == CardIdScheme::Sequential && self.next_card_number <= used {
+                issues.push(Issue::new(
+                    IssueKind::CardNumberBehind,
+                    format!(
+                        "next card number {} is not above the used number {}",
+                        self.next_card_number, used
+                    ),
+                    format!("set it to {}", used + 1),
+                ));
+            }
+        }
+
+        issues
+    }
+
+    /// Finds all inconsistencies in the board.
+    ///
+    /// In addition to [`Board::check_structure`], reports card IDs that do
+    /// not carry the board's prefix and links to cards that do not exist,
+    /// which merges can leave behind.
+    pub fn check(&self) -> Vec<Issue> {
+        let mut issues = self.check_structure();
+        let prefix = format!("{}-", self.card_id_prefix);
+        let mut seen = HashSet::new();
+        for card in &self.cards {
+            if seen.insert(card.id.as_str()) && !card.id.starts_with(&prefix) {
+                issues.push(Issue::new(
+                    IssueKind::PrefixMismatch,
+                    format!(
+                        "card {} does not start with prefix {}",
+                        card.id, self.card_id_prefix
+                    ),
+                    "give the card a new ID".to_string(),
+                ));
+            }
+        }
+        for card in &self.cards {
+            for blocker in card
+                .blocked_by
+                .iter()
+                .filter(|id| self.get_card(id).is_none())
+            {
+                issues.push(Issue::new(
+                    IssueKind::DanglingBlocker,
+                    format!("card {} is blocked by missing card {}", card.id, blocker),
+                    "remove the link".to_string(),
+                ));
+            }
+            if let Some(parent) = card
+                .parent
+                .as_ref()
+                .filter(|id| self.get_card(id).is_none())
+            {
+                issues.push(Issue::new(
+                    IssueKind::DanglingParent,
+                    format!("card {} has missing parent {}", card.id, parent),
+                    "make it a top-level card".to_string(),
+                ));
+            }
+        }
+        issues
+    }
+
+    /// Fixes all inconsistencies found by [`Board::check`].
+    ///
+    /// Column card lists are rebuilt from the cards' `column_id`, keeping the
+    /// listed order. Duplicate cards and cards without the board's prefix get
+    /// new IDs; the activity of a renamed duplicate and the links to it stay
+    /// with the original. Links to missing cards are removed, and cards
+    /// whose parent is missing become top-level cards.
+    ///
+    /// Returns the repaired issues.
+    pub fn repair(&mut self) -> Vec<Issue> {
+        let mut issues = self.check();
+        if issues.is_empty() {
+            return issues;
+        }
+
+        if self.columns.is_empty() {
+            self.columns
+                .push(Column::new("todo".to_string(), "To Do".to_string(), 0));
+        }
+        let mut column_ids = HashSet::new();
+        self.columns.retain(|c| column_ids.insert(c.id.clone()));
+        for (order, column) in self.columns.iter_mut().enumerate() {
+            column.order = order as u32;
+        }
+
+        if let Some(used) = self.highest_card_number() {
+            if self.card_id_scheme == CardIdScheme::Sequential {
+                self.next_card_number = self.next_card_number.max(used + 1);
+            }
+        }
+
+        let first_column = self.columns[0].id.clone();
+        for card in &mut self.cards {
+            if !column_ids.contains(&card.column_id) {
+                card.move_to(first_column.clone());
+            }
+        }
+
+        // Rename cards in the order `check` reported them.
+        let prefix = format!("{}-", self.card_id_prefix);
+        let mut seen = HashSet::new();
+        let duplicates: Vec<usize> = (0..self.cards.len())
+            .filter(|&i| !seen.insert(self.cards[i].id.clone()))
+            .collect();
+        let mut new_ids = Vec::new();
+        for index in duplicates {
+            let new_id = self.generate_card_id();
+            self.cards[index].id = new_id.clone();
+            new_ids.push((IssueKind::DuplicateCard, new_id));
+        }
+        let mismatched: Vec<usize> = (0..self.cards.len())
+            .filter(|&i| !self.cards[i].id.starts_with(&prefix))
+            .collect();
+        for index in mismatched {
+            let new_id = self.generate_card_id();
+            self.rename_card(index, &new_id);
+            new_ids.push((IssueKind::PrefixMismatch, new_id));
+        }
+        for kind in [IssueKind::DuplicateCard, IssueKind::PrefixMismatch] {
+            let ids = new_ids.iter().filter(|(k, _)| *k == kind).map(|(_, id)| id);
+            for (issue, id) in issues.iter_mut().filter(|i| i.kind == kind).zip(ids) {
+                issue.repair = format!("give the card the new ID {}", id);
+            }
+        }
+
+        let card_ids: HashSet<String> = self.cards.iter().map(|c| c.id.clone()).collect();
+        for card in &mut self.cards {
+            let before = card.blocked_by.len();
+            card.blocked_by.retain(|id| card_ids.contains(id));
+            let orphaned = card
+                .parent
+                .as_ref()
+                .is_some_and(|id| !card_ids.contains(id));
+            if orphaned {
+                card.parent = None;
+            }
+            if orphaned || card.blocked_by.len() != before {
+                card.updated_at = Utc::now();
+            }
+        }
+
+        self.rebuild_column_lists();
+        self.updated_at = Utc::now();
+        issues
+    }
+
+    /// Gives the card at `index` a new ID, updating its column entry, the
+    /// links to it, its children and its activity.
+    fn rename_card(&mut self, index: usize, new_id: &str) {
+        let old_id = std::mem::replace(&mut self.cards[index].id, new_id.to_string());
+        for column in &mut self.columns {
+            for id in column.cards.iter_mut().filter(|id| **id == old_id) {
+                *id = new_id.to_string();
+            }
+        }
+        for card in &mut self.cards {
+            if card.blocked_by.remove(&old_id) {
+                card.blocked_by.insert(new_id.to_string());
+                card.updated_at = Utc::now();
+            }
+            if card.parent.as_deref() == Some(old_id.as_str()) {
+                card.parent = Some(new_id.to_string());
+                card.updated_at = Utc::now();
+            }
+        }
+        for activity in self.activity.iter_mut().filter(|a| a.card_id == old_id) {
+            activity.card_id = new_id.to_string();
+        }
+    }
+
+    /// Makes each column list exactly the cards in it, keeping the listed
+    /// order and appending unlisted cards in board order.
+    fn rebuild_column_lists(&mut self) {
+        let card_columns: HashMap<String, String> = self
+            .cards
+            .iter()
+            .map(|c| (c.id.clone(), c.column_id.clone()))
+            .collect();
+        for column in &mut self.columns {
+            let mut seen = HashSet::new();
+            column
+                .cards
+                .retain(|id| card_columns.get(id) == Some(&column.id) && seen.insert(id.clone()));
+            for card in self.cards.iter().filter(|c| c.column_id == column.id) {
+                if seen.insert(card.id.clone()) {
+                    column.cards.push(card.id.clone());
+                }
+            }
+        }
+    }
+
+    /// Highest number used by a sequential ID of a card, current or deleted.
+    fn highest_card_number(&self) -> Option<u32> {
+        let prefix = format!("{}-", self.card_id_prefix);
+        self.cards
+            .iter()
+            .map(|c| c.id.as_str())
+            .chain(self.activity.iter().map(|a| a.card_id.as_str()))
+            .filter_map(|id| id.strip_prefix(&prefix))
+            .filter(|suffix| suffix.bytes().all(|b| b.is_ascii_digit()))
+            .filter_map(|suffix| suffix.parse().ok())
+            .max()
+    }
+}
+
+#[cfg(test)]
+#[path = "./consistency_tests.rs"]
+mod consistency_tests;
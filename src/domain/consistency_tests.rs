@@ -0,0 +1,240 @@
+use super::*;
+use crate::domain::{Activity, ActivityKind};
+
+fn sample_board() -> Board {
+    let mut board = Board::new("test".to_string(), "Test Board".to_string());
+    board.create_card("First".to_string(), None, None, None);
+    board.create_card("Second".to_string(), None, None, None);
+    board.create_card("Third".to_string(), None, None, Some("done".to_string()));
+    board
+}
+
+fn kinds(issues: &[Issue]) -> Vec<IssueKind> {
+    issues.iter().map(|i| i.kind).collect()
+}
+
+#[test]
+fn test_check_consistent_board() {
+    // Arrange
+    let board = sample_board();
+
+    // Act
+    let issues = board.check();
+
+    // Assert
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_check_finds_membership_issues() {
+    // Arrange
+    let mut board = sample_board();
+    board.columns[0].cards.push("TES-404".to_string());
+    board.columns[0].cards.push("TES-001".to_string());
+    board.columns[1].cards.push("TES-003".to_string());
+    board.columns[2].cards.clear();
+
+    // Act
+    let issues = board.check_structure();
+
+    // Assert
+    assert_eq!(
+        kinds(&issues),
+        vec![
+            IssueKind::DanglingCardReference,
+            IssueKind::DuplicateColumnEntry,
+            IssueKind::MisplacedCard,
+            IssueKind::OrphanedCard,
+        ]
+    );
+    assert_eq!(issues[0].message, "column todo lists missing card TES-404");
+}
+
+#[test]
+fn test_check_finds_id_issues() {
+    // Arrange
+    let mut board = sample_board();
+    board.get_card_mut("TES-003").unwrap().id = "TES-001".to_string();
+    board.columns[2].cards = vec!["TES-001".to_string()];
+    board.get_card_mut("TES-002").unwrap().column_id = "review".to_string();
+    board.next_card_number = 2;
+    let mut foreign = board.cards[0].clone();
+    foreign.id = "OLD-7".to_string();
+    board.columns[0].cards.push(foreign.id.clone());
+    board.cards.push(foreign);
+
+    // Act
+    let structure = board.check_structure();
+    let issues = board.check();
+
+    // Assert
+    assert_eq!(
+        kinds(&structure),
+        vec![
+            IssueKind::UnknownColumn,
+            IssueKind::DuplicateCard,
+            IssueKind::MisplacedCard,
+            IssueKind::MisplacedCard,
+            IssueKind::CardNumberBehind,
+        ]
+    );
+    assert_eq!(issues.last().unwrap().kind, IssueKind::PrefixMismatch);
+}
+
+#[test]
+fn test_repair_rebuilds_column_lists() {
+    // Arrange
+    let mut board = sample_board();
+    board.columns[0].cards = vec![
+        "TES-404".to_string(),
+        "TES-002".to_string(),
+        "TES-002".to_string(),
+    ];
+    board.columns[1].cards.push("TES-003".to_string());
+    board.get_card_mut("TES-003").unwrap().column_id = "review".to_string();
+
+    // Act
+    let repaired = board.repair();
+
+    // Assert
+    assert_eq!(repaired.len(), 6);
+    assert!(board.check().is_empty());
+    assert_eq!(
+        board.columns[0].cards,
+        vec!["TES-002", "TES-001", "TES-003"]
+    );
+    assert!(board.columns[1].cards.is_empty());
+    assert!(board.columns[2].cards.is_empty());
+}
+
+#[test]
+fn test_repair_renames_duplicate_and_foreign_cards() {
+    // Arrange
+    let mut board = sample_board();
+    let mut duplicate = board.cards[0].clone();
+    duplicate.title = "Copy".to_string();
+    board.cards.push(duplicate);
+    let mut foreign = board.cards[1].clone();
+    foreign.id = "OLD-7".to_string();
+    board.columns[0].cards.push(foreign.id.clone());
+    board.cards.push(foreign);
+    board.record_activity(Activity::new(
+        "OLD-7".to_string(),
+        "alice".to_string(),
+        ActivityKind::Commented,
+    ));
+    board.next_card_number = 1;
+
+    // Act
+    let repaired = board.repair();
+
+    // Assert
+    assert!(board.check().is_empty());
+    assert_eq!(board.cards[3].id, "TES-004");
+    assert_eq!(board.cards[3].title, "Copy");
+    assert_eq!(board.cards[4].id, "TES-005");
+    assert_eq!(board.activity[0].card_id, "TES-005");
+    assert_eq!(board.next_card_number, 6);
+    assert!(repaired
+        .iter()
+        .any(|i| i.repair == "give the card the new ID TES-004"));
+}
+
+#[test]
+fn test_repair_consistent_board_changes_nothing() {
+    // Arrange
+    let mut board = sample_board();
+    let before = board.clone();
+
+    // Act
+    let repaired = board.repair();
+
+    // Assert
+    assert!(repaired.is_empty());
+    assert_eq!(board, before);
+}
+
+#[test]
+fn test_repair_renamed_card_keeps_its_links() {
+    // Arrange
+    let mut board = sample_board();
+    board.cards[0].id = "OLD-1".to_string();
+    board.columns[0].cards[0] = "OLD-1".to_string();
+    board.add_blocker("TES-002", "OLD-1").unwrap();
+
+    // Act
+    board.repair();
+
+    // Assert
+    assert!(board.check().is_empty());
+    let card = board.get_card("TES-002").unwrap();
+    assert_eq!(card.blocked_by.iter().collect::<Vec<_>>(), vec!["TES-004"]);
+}
+
+#[test]
+fn test_check_and_repair_dangling_blocker() {
+    // Arrange
+    let mut board = sample_board();
+    board.add_blocker("TES-002", "TES-001").unwrap();
+    board.cards.retain(|c| c.id != "TES-001");
+    board.columns[0].cards.retain(|id| id != "TES-001");
+
+    // Act
+    let structure = board.check_structure();
+    let issues = board.check();
+
+    // Assert
+    assert!(structure.is_empty());
+    assert_eq!(kinds(&issues), vec![IssueKind::DanglingBlocker]);
+    assert_eq!(
+        issues[0].message,
+        "card TES-002 is blocked by missing card TES-001"
+    );
+
+    // Act
+    board.repair();
+
+    // Assert
+    assert!(board.check().is_empty());
+    assert!(board.get_card("TES-002").unwrap().blocked_by.is_empty());
+}
+
+#[test]
+fn test_repair_renamed_card_keeps_its_children() {
+    // Arrange
+    let mut board = sample_board();
+    board.cards[0].id = "OLD-1".to_string();
+    board.columns[0].cards[0] = "OLD-1".to_string();
+    board.set_parent("TES-002", Some("OLD-1")).unwrap();
+
+    // Act
+    board.repair();
+
+    // Assert
+    assert!(board.check().is_empty());
+    let card = board.get_card("TES-002").unwrap();
+    assert_eq!(card.parent.as_deref(), Some("TES-004"));
+}
+
+#[test]
+fn test_check_and_repair_dangling_parent() {
+    // Arrange
+    let mut board = sample_board();
+    board.set_parent("TES-002", Some("TES-001")).unwrap();
+    board.cards.retain(|c| c.id != "TES-001");
+    board.columns[0].cards.retain(|id| id != "TES-001");
+
+    // Act
+    let issues = board.check();
+
+    // Assert
+    assert_eq!(kinds(&issues), vec![IssueKind::DanglingParent]);
+    assert_eq!(issues[0].message, "card TES-002 has missing parent TES-001");
+
+    // Act
+    board.repair();
+
+    // Assert
+    assert!(board.check().is_empty());
+    assert_eq!(board.get_card("TES-002").unwrap().parent, None);
+}
@@ -0,0 +1,129 @@
+use chrono::Utc;
+
+use super::{Board, Card};
+
+/// Why one card cannot be made to block another.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LinkError {
+    #[error("Card {0} cannot block itself")]
+    SelfLink(String),
+    #[error("{blocked} already blocks {blocker}, directly or through other cards")]
+    Cycle { blocker: String, blocked: String },
+}
+
+impl Board {
+    /// Records that `blocker` must be done before `blocked` can start.
+    ///
+    /// Returns true if the link was added, false if it already existed or
+    /// `blocked` does not exist.
+    ///
+    /// # Errors
+    /// Returns `LinkError` if the link would make a card block itself,
+    /// directly or through other cards.
+    pub fn add_blocker(&mut self, blocked: &str, blocker: &str) -> Result<bool, LinkError> {
+        if blocked == blocker {
+            return Err(LinkError::SelfLink(blocked.to_string()));
+        }
+        if self.is_blocked_by(blocker, blocked) {
+            return Err(LinkError::Cycle {
+                blocker: blocker.to_string(),
+                blocked: blocked.to_string(),
+            });
+        }
+
+        let Some(card) = self.get_card_mut(blocked) else {
+            return Ok(false);
+        };
+        let added = card.blocked_by.insert(blocker.to_string());
+        if added {
+            card.updated_at = Utc::now();
+            self.updated_at = Utc::now();
+        }
+        Ok(added)
+    }
+
+    /// Removes the link that makes `blocker` block `blocked`.
+    ///
+    /// Returns true if the link existed.
+    pub fn remove_blocker(&mut self, blocked: &str, blocker: &str) -> bool {
+        let Some(card) = self.get_card_mut(blocked) else {
+            return false;
+        };
+        let removed = card.blocked_by.remove(blocker);
+        if removed {
+            card.updated_at = Utc::now();
+            self.updated_at = Utc::now();
+        }
+        removed
+    }
+
+    /// Returns true if `card_id` waits for `blocker`, directly or through
+    /// other cards.
+    pub fn is_blocked_by(&self, card_id: &str, blocker: &str) -> bool {
+        let mut pending = vec![card_id];
+        let mut seen = vec![card_id];
+        while let Some(id) = pending.pop() {
+            let Some(card) = self.get_card(id) else {
+                continue;
+            };
+            for next in &card.blocked_by {
+                if next == blocker {
+                    return true;
+                }
+                if !seen.contains(&next.as_str()) {
+                    seen.push(next);
+                    pending.push(next);
+                }
+            }
+        }
+        false
+    }
+
+    /// Gets the cards that block a card, in ID order.
+    ///
+    /// Links to cards that were deleted are skipped.
+    pub fn blockers(&self, card: &Card) -> Vec<&Card> {
+        card.blocked_by
+            .iter()
+            .filter_map(|id| self.get_card(id))
+            .collect()
+    }
+
+    /// Gets the cards a card blocks, in board order.
+    pub fn blocked_cards(&self, card_id: &str) -> Vec<&Card> {
+        self.cards
+            .iter()
+            .filter(|c| c.blocked_by.contains(card_id))
+            .collect()
+    }
+
+    /// Gets the blockers of a card that are not yet in the done column.
+    pub fn unfinished_blockers(&self, card: &Card) -> Vec<&Card> {
+        self.blockers(card)
+            .into_iter()
+            .filter(|b| !self.is_done_column(&b.column_id))
+            .collect()
+    }
+
+    /// Returns true if all blockers of a card are in the done column.
+    pub fn is_ready(&self, card: &Card) -> bool {
+        self.unfinished_blockers(card).is_empty()
+    }
+
+    /// Gets the cards that can be worked on: cards that are neither done
+    /// nor archived and whose blockers are all done.
+    ///
+    /// Cards are returned column by column, in column order.
+    pub fn ready_cards(&self) -> Vec<&Card> {
+        self.columns
+            .iter()
+            .filter(|column| !self.is_done_column(&column.id))
+            .flat_map(|column| self.get_cards_in_column(&column.id))
+            .filter(|card| self.is_ready(card))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "./dependency_tests.rs"]
+mod dependency_tests;
@@ -0,0 +1,138 @@
+use super::*;
+
+fn board_with_cards(count: usize) -> Board {
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    for i in 0..count {
+        board.create_card(format!("Task {}", i), None, None, None);
+    }
+    board
+}
+
+#[test]
+fn test_add_and_remove_blocker() {
+    // Arrange
+    let mut board = board_with_cards(2);
+
+    // Act
+    let added = board.add_blocker("TES-002", "TES-001");
+    let added_again = board.add_blocker("TES-002", "TES-001");
+
+    // Assert
+    assert_eq!(added, Ok(true));
+    assert_eq!(added_again, Ok(false));
+    let card = board.get_card("TES-002").unwrap();
+    assert_eq!(board.blockers(card)[0].id, "TES-001");
+    assert_eq!(board.blocked_cards("TES-001")[0].id, "TES-002");
+
+    // Act & Assert
+    assert!(board.remove_blocker("TES-002", "TES-001"));
+    assert!(!board.remove_blocker("TES-002", "TES-001"));
+    assert!(board.get_card("TES-002").unwrap().blocked_by.is_empty());
+}
+
+#[test]
+fn test_add_blocker_rejects_self_link() {
+    // Arrange
+    let mut board = board_with_cards(1);
+
+    // Act
+    let result = board.add_blocker("TES-001", "TES-001");
+
+    // Assert
+    assert_eq!(result, Err(LinkError::SelfLink("TES-001".to_string())));
+}
+
+#[test]
+fn test_add_blocker_rejects_cycle() {
+    // Arrange
+    let mut board = board_with_cards(3);
+    board.add_blocker("TES-002", "TES-001").unwrap();
+    board.add_blocker("TES-003", "TES-002").unwrap();
+
+    // Act
+    let result = board.add_blocker("TES-001", "TES-003");
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(LinkError::Cycle {
+            blocker: "TES-003".to_string(),
+            blocked: "TES-001".to_string(),
+        })
+    );
+    assert!(board.get_card("TES-001").unwrap().blocked_by.is_empty());
+    assert!(board.is_blocked_by("TES-003", "TES-001"));
+}
+
+#[test]
+fn test_card_is_ready_when_blockers_are_done() {
+    // Arrange
+    let mut board = board_with_cards(3);
+    board.add_blocker("TES-003", "TES-001").unwrap();
+    board.add_blocker("TES-003", "TES-002").unwrap();
+    board.move_card("TES-001", "done");
+
+    // Act
+    let card = board.get_card("TES-003").unwrap();
+    let unfinished: Vec<&str> = board
+        .unfinished_blockers(card)
+        .iter()
+        .map(|c| c.id.as_str())
+        .collect();
+
+    // Assert
+    assert_eq!(unfinished, vec!["TES-002"]);
+    assert!(!board.is_ready(card));
+
+    // Act
+    board.move_card("TES-002", "done");
+
+    // Assert
+    assert!(board.is_ready(board.get_card("TES-003").unwrap()));
+}
+
+#[test]
+fn test_deleted_blocker_does_not_block() {
+    // Arrange
+    let mut board = board_with_cards(2);
+    board.add_blocker("TES-002", "TES-001").unwrap();
+
+    // Act
+    board.delete_card("TES-001");
+
+    // Assert
+    let card = board.get_card("TES-002").unwrap();
+    assert!(board.blockers(card).is_empty());
+    assert!(board.is_ready(card));
+}
+
+#[test]
+fn test_delete_card_removes_its_links() {
+    // Arrange
+    let mut board = board_with_cards(3);
+    board.add_blocker("TES-002", "TES-001").unwrap();
+    board.add_blocker("TES-002", "TES-003").unwrap();
+
+    // Act
+    board.delete_card("TES-001");
+
+    // Assert
+    let card = board.get_card("TES-002").unwrap();
+    assert_eq!(card.blocked_by.iter().collect::<Vec<_>>(), vec!["TES-003"]);
+}
+
+#[test]
+fn test_ready_cards_skip_blocked_done_and_archived_cards() {
+    // Arrange
+    let mut board = board_with_cards(4);
+    board.add_blocker("TES-002", "TES-001").unwrap();
+    board.move_card("TES-003", "done");
+    board.get_card_mut("TES-004").unwrap().archive();
+    board.move_card("TES-001", "in_progress");
+
+    // Act
+    let ready: Vec<&str> = board.ready_cards().iter().map(|c| c.id.as_str()).collect();
+
+    // Assert
+    assert_eq!(ready, vec!["TES-001"]);
+}
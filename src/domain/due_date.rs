@@ -0,0 +1,54 @@
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+
+/// Parses a due date relative to `today`.
+///
+/// Accepted forms:
+/// - ISO dates: `2024-06-30`
+/// - `today` and `tomorrow`
+/// - Offsets: `+3d`, `+2w`, `+1m` (days, weeks, months)
+/// - Weekday names: `friday` or `fri`, meaning the next such day after today
+pub fn parse_due_date(input: &str, today: NaiveDate) -> Result<NaiveDate, String> {
+    let value = input.trim().to_lowercase();
+    let invalid = || {
+        format!(
+            "Invalid due date '{}': use YYYY-MM-DD, today, tomorrow, +Nd, +Nw, +Nm or a weekday",
+            input
+        )
+    };
+
+    if let Ok(date) = NaiveDate::parse_from_str(&value, "%Y-%m-%d") {
+        return Ok(date);
+    }
+
+    match value.as_str() {
+        "today" => return Ok(today),
+        "tomorrow" => return today.checked_add_days(Days::new(1)).ok_or_else(invalid),
+        _ => {}
+    }
+
+    if let Some(offset) = value.strip_prefix('+') {
+        let unit = offset.chars().last().ok_or_else(invalid)?;
+        let amount: u32 = offset[..offset.len() - unit.len_utf8()]
+            .parse()
+            .map_err(|_| invalid())?;
+        let date = match unit {
+            'd' => today.checked_add_days(Days::new(amount.into())),
+            'w' => today.checked_add_days(Days::new(u64::from(amount) * 7)),
+            'm' => today.checked_add_months(Months::new(amount)),
+            _ => None,
+        };
+        return date.ok_or_else(invalid);
+    }
+
+    let weekday: Weekday = value.parse().map_err(|_| invalid())?;
+    let days_ahead =
+        (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+    let days_ahead = if days_ahead == 0 { 7 } else { days_ahead };
+    today
+        .checked_add_days(Days::new(days_ahead.into()))
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+#[path = "./due_date_tests.rs"]
+mod due_date_tests;
@@ -0,0 +1,45 @@
+use super::*;
+
+/// A Wednesday.
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 5, 15).unwrap()
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn test_parse_iso_date() {
+    assert_eq!(parse_due_date("2024-06-30", today()), Ok(date(2024, 6, 30)));
+}
+
+#[test]
+fn test_parse_keywords() {
+    assert_eq!(parse_due_date("today", today()), Ok(today()));
+    assert_eq!(parse_due_date("Tomorrow", today()), Ok(date(2024, 5, 16)));
+}
+
+#[test]
+fn test_parse_offsets() {
+    assert_eq!(parse_due_date("+3d", today()), Ok(date(2024, 5, 18)));
+    assert_eq!(parse_due_date("+2w", today()), Ok(date(2024, 5, 29)));
+    assert_eq!(parse_due_date("+1m", today()), Ok(date(2024, 6, 15)));
+}
+
+#[test]
+fn test_parse_weekdays() {
+    assert_eq!(parse_due_date("friday", today()), Ok(date(2024, 5, 17)));
+    assert_eq!(parse_due_date("mon", today()), Ok(date(2024, 5, 20)));
+    // The same weekday as today means next week
+    assert_eq!(parse_due_date("wednesday", today()), Ok(date(2024, 5, 22)));
+}
+
+#[test]
+fn test_parse_invalid() {
+    assert!(parse_due_date("", today()).is_err());
+    assert!(parse_due_date("soon", today()).is_err());
+    assert!(parse_due_date("+3x", today()).is_err());
+    assert!(parse_due_date("+d", today()).is_err());
+    assert!(parse_due_date("2024-13-01", today()).is_err());
+}
@@ -0,0 +1,200 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::{Board, Card};
+
+/// Why a card cannot be made a child of another.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParentError {
+    #[error("Card {0} cannot be its own parent")]
+    SelfParent(String),
+    #[error("{parent} is below {card}, so it cannot be its parent")]
+    Cycle { card: String, parent: String },
+}
+
+/// What happens to the children of a card that is deleted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChildPolicy {
+    /// The children lose their parent and become top-level cards
+    Orphan,
+    /// The children move up to the parent of the deleted card
+    Reparent,
+    /// The children and everything below them are deleted too
+    Cascade,
+}
+
+impl ChildPolicy {
+    /// Returns the lowercase name of the policy.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ChildPolicy::Orphan => "orphan",
+            ChildPolicy::Reparent => "reparent",
+            ChildPolicy::Cascade => "cascade",
+        }
+    }
+}
+
+impl fmt::Display for ChildPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ChildPolicy {
+    type Err = String;
+
+    /// Parses a policy name (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "orphan" => Ok(ChildPolicy::Orphan),
+            "reparent" => Ok(ChildPolicy::Reparent),
+            "cascade" => Ok(ChildPolicy::Cascade),
+            _ => Err(format!(
+                "Invalid child policy '{}': expected orphan, reparent or cascade",
+                s
+            )),
+        }
+    }
+}
+
+impl Board {
+    /// Makes `parent` the parent of a card, or makes the card top-level.
+    ///
+    /// Returns true if the parent changed, false if it was already set or
+    /// the card does not exist.
+    ///
+    /// # Errors
+    /// Returns `ParentError` if the card would end up below itself.
+    pub fn set_parent(&mut self, card_id: &str, parent: Option<&str>) -> Result<bool, ParentError> {
+        if let Some(parent) = parent {
+            if parent == card_id {
+                return Err(ParentError::SelfParent(card_id.to_string()));
+            }
+            if self.is_descendant_of(parent, card_id) {
+                return Err(ParentError::Cycle {
+                    card: card_id.to_string(),
+                    parent: parent.to_string(),
+                });
+            }
+        }
+
+        let Some(card) = self.get_card_mut(card_id) else {
+            return Ok(false);
+        };
+        if card.parent.as_deref() == parent {
+            return Ok(false);
+        }
+        card.parent = parent.map(str::to_string);
+        card.updated_at = Utc::now();
+        self.updated_at = Utc::now();
+        Ok(true)
+    }
+
+    /// Gets the parent of a card, unless it has none or it was deleted.
+    pub fn parent_of(&self, card: &Card) -> Option<&Card> {
+        card.parent.as_deref().and_then(|id| self.get_card(id))
+    }
+
+    /// Returns true if `card_id` is below `ancestor`, directly or through
+    /// other cards.
+    pub fn is_descendant_of(&self, card_id: &str, ancestor: &str) -> bool {
+        let mut seen = vec![card_id];
+        let mut current = self.get_card(card_id);
+        while let Some(parent) = current.and_then(|c| c.parent.as_deref()) {
+            if parent == ancestor {
+                return true;
+            }
+            if seen.contains(&parent) {
+                return false;
+            }
+            seen.push(parent);
+            current = self.get_card(parent);
+        }
+        false
+    }
+
+    /// Gets the direct children of a card, in board order.
+    pub fn children(&self, card_id: &str) -> Vec<&Card> {
+        self.cards
+            .iter()
+            .filter(|c| c.parent.as_deref() == Some(card_id))
+            .collect()
+    }
+
+    /// Gets every card below a card, parents before their children.
+    pub fn descendants(&self, card_id: &str) -> Vec<&Card> {
+        let mut descendants: Vec<&Card> = Vec::new();
+        let mut pending: Vec<&Card> = self.children(card_id).into_iter().rev().collect();
+        while let Some(card) = pending.pop() {
+            if card.id == card_id || descendants.iter().any(|d| d.id == card.id) {
+                continue;
+            }
+            descendants.push(card);
+            pending.extend(self.children(&card.id).into_iter().rev());
+        }
+        descendants
+    }
+
+    /// Gets how many of a card's children are in the done column, and how
+    /// many children it has, or `None` if it has no children.
+    pub fn child_progress(&self, card_id: &str) -> Option<(usize, usize)> {
+        let children = self.children(card_id);
+        if children.is_empty() {
+            return None;
+        }
+        let done = children
+            .iter()
+            .filter(|c| self.is_done_column(&c.column_id))
+            .count();
+        Some((done, children.len()))
+    }
+
+    /// Deletes a card and deals with its children according to `policy`.
+    ///
+    /// Returns the IDs of the deleted cards, the card itself first, or an
+    /// empty list if it does not exist.
+    pub fn delete_card_with_children(&mut self, card_id: &str, policy: ChildPolicy) -> Vec<String> {
+        let Some(card) = self.get_card(card_id) else {
+            return Vec::new();
+        };
+        let grandparent = card.parent.clone();
+
+        let mut deleted = vec![card_id.to_string()];
+        match policy {
+            ChildPolicy::Cascade => {
+                deleted.extend(self.descendants(card_id).iter().map(|c| c.id.clone()));
+            }
+            ChildPolicy::Orphan | ChildPolicy::Reparent => {
+                let parent = if policy == ChildPolicy::Reparent {
+                    grandparent
+                } else {
+                    None
+                };
+                let children: Vec<String> = self
+                    .children(card_id)
+                    .iter()
+                    .map(|c| c.id.clone())
+                    .collect();
+                for child in children {
+                    if let Some(card) = self.get_card_mut(&child) {
+                        card.parent = parent.clone();
+                        card.updated_at = Utc::now();
+                    }
+                }
+            }
+        }
+
+        for id in &deleted {
+            self.delete_card(id);
+        }
+        deleted
+    }
+}
+
+#[cfg(test)]
+#[path = "./hierarchy_tests.rs"]
+mod hierarchy_tests;
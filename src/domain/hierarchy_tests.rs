@@ -0,0 +1,118 @@
+use super::*;
+
+/// Creates cards TES-001 to TES-00n, with TES-002 and TES-003 below
+/// TES-001 and TES-004 below TES-002.
+fn board_with_tree(count: usize) -> Board {
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    for i in 0..count {
+        board.create_card(format!("Task {}", i), None, None, None);
+    }
+    for (card, parent) in [
+        ("TES-002", "TES-001"),
+        ("TES-003", "TES-001"),
+        ("TES-004", "TES-002"),
+    ] {
+        if board.get_card(card).is_some() {
+            board.set_parent(card, Some(parent)).unwrap();
+        }
+    }
+    board
+}
+
+fn ids(cards: Vec<&Card>) -> Vec<&str> {
+    cards.into_iter().map(|c| c.id.as_str()).collect()
+}
+
+#[test]
+fn test_set_and_clear_parent() {
+    // Arrange
+    let mut board = board_with_tree(3);
+
+    // Act
+    let unchanged = board.set_parent("TES-002", Some("TES-001"));
+    let cleared = board.set_parent("TES-003", None);
+
+    // Assert
+    assert_eq!(unchanged, Ok(false));
+    assert_eq!(cleared, Ok(true));
+    let card = board.get_card("TES-002").unwrap();
+    assert_eq!(board.parent_of(card).unwrap().id, "TES-001");
+    assert_eq!(ids(board.children("TES-001")), vec!["TES-002"]);
+    assert_eq!(board.get_card("TES-003").unwrap().parent, None);
+}
+
+#[test]
+fn test_set_parent_rejects_cycles() {
+    // Arrange
+    let mut board = board_with_tree(4);
+
+    // Act
+    let own = board.set_parent("TES-001", Some("TES-001"));
+    let below = board.set_parent("TES-001", Some("TES-004"));
+
+    // Assert
+    assert_eq!(own, Err(ParentError::SelfParent("TES-001".to_string())));
+    assert_eq!(
+        below,
+        Err(ParentError::Cycle {
+            card: "TES-001".to_string(),
+            parent: "TES-004".to_string(),
+        })
+    );
+    assert_eq!(board.get_card("TES-001").unwrap().parent, None);
+}
+
+#[test]
+fn test_descendants_and_progress() {
+    // Arrange
+    let mut board = board_with_tree(5);
+    board.move_card("TES-003", "done");
+
+    // Act
+    let descendants = board.descendants("TES-001");
+
+    // Assert
+    assert_eq!(ids(descendants), vec!["TES-002", "TES-004", "TES-003"]);
+    assert!(board.is_descendant_of("TES-004", "TES-001"));
+    assert!(!board.is_descendant_of("TES-005", "TES-001"));
+    assert_eq!(board.child_progress("TES-001"), Some((1, 2)));
+    assert_eq!(board.child_progress("TES-005"), None);
+}
+
+#[test]
+fn test_delete_card_with_children() {
+    // Arrange
+    let policies = [
+        (ChildPolicy::Orphan, None),
+        (ChildPolicy::Reparent, Some("TES-001".to_string())),
+    ];
+
+    for (policy, parent) in policies {
+        let mut board = board_with_tree(4);
+
+        // Act
+        let deleted = board.delete_card_with_children("TES-002", policy);
+
+        // Assert
+        assert_eq!(deleted, vec!["TES-002"]);
+        assert_eq!(board.get_card("TES-004").unwrap().parent, parent);
+    }
+
+    // Arrange
+    let mut board = board_with_tree(4);
+
+    // Act
+    let deleted = board.delete_card_with_children("TES-001", ChildPolicy::Cascade);
+
+    // Assert
+    assert_eq!(deleted, vec!["TES-001", "TES-002", "TES-004", "TES-003"]);
+    assert!(board.cards.is_empty());
+}
+
+#[test]
+fn test_parse_child_policy() {
+    // Act & Assert
+    assert_eq!("Cascade".parse(), Ok(ChildPolicy::Cascade));
+    assert_eq!(ChildPolicy::Reparent.to_string(), "reparent");
+    assert!("keep".parse::<ChildPolicy>().is_err());
+}
@@ -0,0 +1,291 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{Board, Card};
+
+/// Number of operations the journal keeps for undoing.
+pub const JOURNAL_LIMIT: usize = 100;
+
+/// A card as it was before or after an operation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardState {
+    pub card: Card,
+    /// Index of the card in its column's card list
+    pub position: usize,
+}
+
+impl CardState {
+    /// Captures the current state of a card, if it exists.
+    pub fn of(board: &Board, card_id: &str) -> Option<Self> {
+        let card = board.get_card(card_id)?;
+        let position = board
+            .columns
+            .iter()
+            .find(|c| c.id == card.column_id)
+            .and_then(|c| c.cards.iter().position(|id| id == card_id))
+            .unwrap_or(0);
+        Some(Self {
+            card: card.clone(),
+            position,
+        })
+    }
+}
+
+/// The change an operation made to one card.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardChange {
+    pub card_id: String,
+    /// The card before the operation, `None` if the operation created it
+    pub before: Option<CardState>,
+    /// The card after the operation, `None` if the operation deleted it
+    pub after: Option<CardState>,
+}
+
+/// A change to cards that can be undone and redone.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Operation {
+    /// What the operation did, e.g. "move TES-001 to done"
+    pub description: String,
+    /// Who made the change
+    pub actor: String,
+    /// When the change was made
+    pub timestamp: DateTime<Utc>,
+    pub changes: Vec<CardChange>,
+}
+
+impl Operation {
+    /// Creates an operation that changed one card from `before` to its
+    /// current state in `board`.
+    pub fn new(
+        description: String,
+        actor: String,
+        board: &Board,
+        card_id: &str,
+        before: Option<CardState>,
+    ) -> Self {
+        Self::of_cards(
+            description,
+            actor,
+            board,
+            vec![(card_id.to_string(), before)],
+        )
+    }
+
+    /// Creates an operation that changed several cards, each from its
+    /// state in `before` to its current state in `board`.
+    ///
+    /// The changes are ordered by the cards' earlier positions, so that
+    /// undoing puts cards of the same column back in their places.
+    pub fn of_cards(
+        description: String,
+        actor: String,
+        board: &Board,
+        before: Vec<(String, Option<CardState>)>,
+    ) -> Self {
+        let mut changes: Vec<CardChange> = before
+            .into_iter()
+            .map(|(card_id, before)| CardChange {
+                after: CardState::of(board, &card_id),
+                card_id,
+                before,
+            })
+            .collect();
+        changes.sort_by_key(|c| c.before.as_ref().map_or(0, |s| s.position));
+        Self {
+            description,
+            actor,
+            timestamp: Utc::now(),
+            changes,
+        }
+    }
+
+    /// Returns true if the operation left every card as it was.
+    pub fn is_empty(&self) -> bool {
+        self.changes.iter().all(|c| c.before == c.after)
+    }
+
+    /// Returns the operation that reverses this one.
+    pub fn inverse(&self) -> Self {
+        Self {
+            changes: self
+                .changes
+                .iter()
+                .map(|c| CardChange {
+                    card_id: c.card_id.clone(),
+                    before: c.after.clone(),
+                    after: c.before.clone(),
+                })
+                .collect(),
+            ..self.clone()
+        }
+    }
+}
+
+/// Operations that can be undone and redone, oldest first.
+///
+/// Several people and agents share the journal of a board, and each only
+/// undoes and redoes their own operations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Journal {
+    #[serde(default)]
+    pub undo: Vec<Operation>,
+    #[serde(default)]
+    pub redo: Vec<Operation>,
+}
+
+impl Journal {
+    /// Records a new operation for undoing.
+    ///
+    /// Operations that changed nothing are ignored. Recording an operation
+    /// discards the operations its actor undid, and the oldest operations
+    /// beyond [`JOURNAL_LIMIT`] are forgotten.
+    pub fn record(&mut self, operation: Operation) {
+        if operation.is_empty() {
+            return;
+        }
+        self.redo.retain(|o| o.actor != operation.actor);
+        self.undo.push(operation);
+        let excess = self.undo.len().saturating_sub(JOURNAL_LIMIT);
+        self.undo.drain(..excess);
+    }
+
+    /// Reverses the latest operation of `actor` in `board` and makes it
+    /// available for redoing.
+    ///
+    /// Returns `Ok(None)` if there is nothing to undo.
+    ///
+    /// # Errors
+    /// Returns a message if a card the operation touched was changed since,
+    /// leaving the board and the journal unchanged.
+    pub fn undo(&mut self, board: &mut Board, actor: &str) -> Result<Option<Operation>, String> {
+        let Some(index) = self.undo.iter().rposition(|o| o.actor == actor) else {
+            return Ok(None);
+        };
+        let operation = &self.undo[index];
+        board
+            .apply_operation(&operation.inverse())
+            .map_err(|e| format!("Cannot undo \"{}\": {}", operation.description, e))?;
+        let operation = self.undo.remove(index);
+        self.redo.push(operation.clone());
+        Ok(Some(operation))
+    }
+
+    /// Repeats the latest operation `actor` undid in `board`.
+    ///
+    /// Returns `Ok(None)` if there is nothing to redo.
+    ///
+    /// # Errors
+    /// Returns a message if a card the operation touches was changed since
+    /// it was undone, leaving the board and the journal unchanged.
+    pub fn redo(&mut self, board: &mut Board, actor: &str) -> Result<Option<Operation>, String> {
+        let Some(index) = self.redo.iter().rposition(|o| o.actor == actor) else {
+            return Ok(None);
+        };
+        let operation = &self.redo[index];
+        board
+            .apply_operation(operation)
+            .map_err(|e| format!("Cannot redo \"{}\": {}", operation.description, e))?;
+        let operation = self.redo.remove(index);
+        self.undo.push(operation.clone());
+        Ok(Some(operation))
+    }
+}
+
+impl Board {
+    /// Changes the cards an operation touched from their `before` to their
+    /// `after` state.
+    ///
+    /// Only those cards are touched, so changes made to other cards in the
+    /// meantime are kept. Cards return to their recorded position in the
+    /// column, or the end of it if the column got shorter.
+    ///
+    /// # Errors
+    /// Returns a message, leaving the board unchanged, if a card is no
+    /// longer in its `before` state, its `after` column no longer exists, or
+    /// it would be removed while other cards link to it or have it as their
+    /// parent.
+    pub fn apply_operation(&mut self, operation: &Operation) -> Result<(), String> {
+        let touched: Vec<&str> = operation
+            .changes
+            .iter()
+            .map(|c| c.card_id.as_str())
+            .collect();
+        let remaining: Vec<&Card> = self
+            .cards
+            .iter()
+            .filter(|c| !touched.contains(&c.id.as_str()))
+            .chain(
+                operation
+                    .changes
+                    .iter()
+                    .filter_map(|c| c.after.as_ref().map(|a| &a.card)),
+            )
+            .collect();
+        for change in operation.changes.iter().filter(|c| c.after.is_none()) {
+            let removed = change.card_id.as_str();
+            let referrer = remaining
+                .iter()
+                .find(|c| c.parent.as_deref() == Some(removed) || c.blocked_by.contains(removed));
+            if let Some(referrer) = referrer {
+                return Err(format!("card {} refers to card {}", referrer.id, removed));
+            }
+        }
+
+        for change in &operation.changes {
+            let current = self.get_card(&change.card_id);
+            match (&change.before, current) {
+                (None, Some(_)) => {
+                    return Err(format!("card {} exists again", change.card_id));
+                }
+                (Some(_), None) => {
+                    return Err(format!("card {} was deleted since", change.card_id));
+                }
+                (Some(before), Some(card)) if before.card != *card => {
+                    return Err(format!("card {} was changed since", change.card_id));
+                }
+                _ => {}
+            }
+            if let Some(after) = &change.after {
+                if !self.columns.iter().any(|c| c.id == after.card.column_id) {
+                    return Err(format!(
+                        "column {} of card {} no longer exists",
+                        after.card.column_id, change.card_id
+                    ));
+                }
+            }
+        }
+
+        for change in &operation.changes {
+            let index = self.cards.iter().position(|c| c.id == change.card_id);
+            for column in &mut self.columns {
+                column.cards.retain(|id| *id != change.card_id);
+            }
+            let Some(after) = &change.after else {
+                if let Some(index) = index {
+                    self.cards.remove(index);
+                }
+                continue;
+            };
+
+            match index {
+                Some(index) => self.cards[index] = after.card.clone(),
+                None => self.cards.push(after.card.clone()),
+            }
+            if let Some(column) = self
+                .columns
+                .iter_mut()
+                .find(|c| c.id == after.card.column_id)
+            {
+                let position = after.position.min(column.cards.len());
+                column.cards.insert(position, change.card_id.clone());
+            }
+        }
+
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "./journal_tests.rs"]
+mod journal_tests;
@@ -0,0 +1,214 @@
+use super::*;
+
+fn sample_board() -> Board {
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    board.create_card("First".to_string(), None, None, None);
+    board.create_card("Second".to_string(), None, None, None);
+    board
+}
+
+/// Moves a card the way the card service does and records the operation.
+fn move_card(journal: &mut Journal, board: &mut Board, card_id: &str, column_id: &str) {
+    let before = CardState::of(board, card_id);
+    board.move_card(card_id, column_id);
+    journal.record(Operation::new(
+        format!("move {} to {}", card_id, column_id),
+        "alice".to_string(),
+        board,
+        card_id,
+        before,
+    ));
+}
+
+#[test]
+fn test_undo_and_redo_move() {
+    // Arrange
+    let mut board = sample_board();
+    let mut journal = Journal::default();
+    move_card(&mut journal, &mut board, "TES-001", "done");
+
+    // Act
+    let undone = journal.undo(&mut board, "alice").unwrap().unwrap();
+
+    // Assert
+    assert_eq!(undone.description, "move TES-001 to done");
+    assert_eq!(board.get_card("TES-001").unwrap().column_id, "todo");
+    assert_eq!(board.columns[0].cards, vec!["TES-001", "TES-002"]);
+    assert!(board.columns[2].cards.is_empty());
+
+    // Act
+    journal.redo(&mut board, "alice").unwrap();
+
+    // Assert
+    assert_eq!(board.get_card("TES-001").unwrap().column_id, "done");
+    assert_eq!(board.columns[2].cards, vec!["TES-001"]);
+    assert_eq!(journal.undo.len(), 1);
+    assert!(journal.redo.is_empty());
+}
+
+#[test]
+fn test_undo_delete_restores_card() {
+    // Arrange
+    let mut board = sample_board();
+    let mut journal = Journal::default();
+    let before = CardState::of(&board, "TES-001");
+    let original = board.get_card("TES-001").unwrap().clone();
+    board.delete_card("TES-001");
+    journal.record(Operation::new(
+        "delete TES-001".to_string(),
+        "alice".to_string(),
+        &board,
+        "TES-001",
+        before,
+    ));
+
+    // Act
+    journal.undo(&mut board, "alice").unwrap();
+
+    // Assert
+    assert_eq!(board.get_card("TES-001"), Some(&original));
+    assert_eq!(board.columns[0].cards, vec!["TES-001", "TES-002"]);
+}
+
+#[test]
+fn test_undo_keeps_changes_to_other_cards() {
+    // Arrange
+    let mut board = sample_board();
+    let mut journal = Journal::default();
+    move_card(&mut journal, &mut board, "TES-001", "done");
+    board
+        .get_card_mut("TES-002")
+        .unwrap()
+        .set_title("Renamed".to_string());
+
+    // Act
+    journal.undo(&mut board, "alice").unwrap();
+
+    // Assert
+    assert_eq!(board.get_card("TES-001").unwrap().column_id, "todo");
+    assert_eq!(board.get_card("TES-002").unwrap().title, "Renamed");
+}
+
+#[test]
+fn test_undo_rejects_card_changed_since() {
+    // Arrange
+    let mut board = sample_board();
+    let mut journal = Journal::default();
+    move_card(&mut journal, &mut board, "TES-001", "done");
+    board
+        .get_card_mut("TES-001")
+        .unwrap()
+        .set_title("Renamed".to_string());
+    let changed = board.clone();
+
+    // Act
+    let result = journal.undo(&mut board, "alice");
+
+    // Assert
+    assert_eq!(
+        result,
+        Err("Cannot undo \"move TES-001 to done\": card TES-001 was changed since".to_string())
+    );
+    assert_eq!(board, changed);
+    assert_eq!(journal.undo.len(), 1);
+}
+
+#[test]
+fn test_undo_create_rejects_card_referenced_since() {
+    // Arrange
+    let mut board = sample_board();
+    let mut journal = Journal::default();
+    let created = board.create_card("Epic".to_string(), None, None, None);
+    journal.record(Operation::new(
+        format!("create {}", created),
+        "alice".to_string(),
+        &board,
+        &created,
+        None,
+    ));
+    board.set_parent("TES-001", Some(&created)).unwrap();
+    board.add_blocker("TES-002", &created).unwrap();
+    let referenced = board.clone();
+
+    // Act
+    let result = journal.undo(&mut board, "alice");
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(format!(
+            "Cannot undo \"create {0}\": card TES-001 refers to card {0}",
+            created
+        ))
+    );
+    assert_eq!(board, referenced);
+    assert_eq!(journal.undo.len(), 1);
+}
+
+#[test]
+fn test_record_discards_redo_and_limits_undo() {
+    // Arrange
+    let mut board = sample_board();
+    let mut journal = Journal::default();
+    move_card(&mut journal, &mut board, "TES-001", "done");
+    journal.undo(&mut board, "alice").unwrap();
+
+    // Act
+    for i in 0..=JOURNAL_LIMIT {
+        let column = if i % 2 == 0 { "in_progress" } else { "todo" };
+        move_card(&mut journal, &mut board, "TES-002", column);
+    }
+
+    // Assert
+    assert!(journal.redo.is_empty());
+    assert_eq!(journal.undo.len(), JOURNAL_LIMIT);
+    assert_eq!(journal.undo[0].description, "move TES-002 to todo");
+}
+
+#[test]
+fn test_record_ignores_operation_without_changes() {
+    // Arrange
+    let mut board = sample_board();
+    let mut journal = Journal::default();
+    let before = CardState::of(&board, "TES-001");
+
+    // Act
+    journal.record(Operation::new(
+        "update TES-001".to_string(),
+        "alice".to_string(),
+        &board,
+        "TES-001",
+        before,
+    ));
+
+    // Assert
+    assert!(journal.undo.is_empty());
+    assert_eq!(journal.undo(&mut board, "alice"), Ok(None));
+}
+
+#[test]
+fn test_undo_only_takes_own_operations() {
+    // Arrange
+    let mut board = sample_board();
+    let mut journal = Journal::default();
+    move_card(&mut journal, &mut board, "TES-001", "done");
+    let before = CardState::of(&board, "TES-002");
+    board.move_card("TES-002", "in_progress");
+    journal.record(Operation::new(
+        "move TES-002 to in_progress".to_string(),
+        "agent-7".to_string(),
+        &board,
+        "TES-002",
+        before,
+    ));
+
+    // Act
+    let undone = journal.undo(&mut board, "alice").unwrap().unwrap();
+
+    // Assert
+    assert_eq!(undone.description, "move TES-001 to done");
+    assert_eq!(board.get_card("TES-001").unwrap().column_id, "todo");
+    assert_eq!(board.get_card("TES-002").unwrap().column_id, "in_progress");
+    assert_eq!(journal.undo(&mut board, "alice"), Ok(None));
+    assert_eq!(journal.undo.len(), 1);
+}
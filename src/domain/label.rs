@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+/// Color names accepted for labels, in addition to `#rrggbb` hex values.
+pub const LABEL_COLORS: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "gray",
+    "darkgray",
+    "lightred",
+    "lightgreen",
+    "lightyellow",
+    "lightblue",
+    "lightmagenta",
+    "lightcyan",
+    "white",
+];
+
+/// A label registered on a board.
+///
+/// Cards refer to labels by name. The board keeps a registry of known
+/// labels so that each one can carry display settings such as a color.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Label {
+    /// Label name (e.g., "bug")
+    pub name: String,
+    /// Optional display color (a name from [`LABEL_COLORS`] or `#rrggbb`)
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+impl Label {
+    /// Creates a new label without a color.
+    pub fn new(name: String) -> Self {
+        Self { name, color: None }
+    }
+
+    /// Validates and normalizes a label name.
+    ///
+    /// Surrounding whitespace is trimmed. Names must be non-empty and may
+    /// not contain whitespace or commas.
+    pub fn normalize_name(name: &str) -> Result<String, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Label name cannot be empty".to_string());
+        }
+        if name.chars().any(|c| c.is_whitespace() || c == ',') {
+            return Err(format!(
+                "Invalid label '{}': labels may not contain spaces or commas",
+                name
+            ));
+        }
+        Ok(name.to_string())
+    }
+
+    /// Validates and normalizes a label color.
+    ///
+    /// Accepts a color name from [`LABEL_COLORS`] (case-insensitive) or a
+    /// `#rrggbb` hex value.
+    pub fn normalize_color(color: &str) -> Result<String, String> {
+        let color = color.trim().to_lowercase();
+        let is_hex = color.len() == 7
+            && color.starts_with('#')
+            && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+
+        if is_hex || LABEL_COLORS.contains(&color.as_str()) {
+            Ok(color)
+        } else {
+            Err(format!(
+                "Invalid color '{}': use #rrggbb or one of {}",
+                color,
+                LABEL_COLORS.join(", ")
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./label_tests.rs"]
+mod label_tests;
@@ -0,0 +1,28 @@
+use super::*;
+
+#[test]
+fn test_normalize_name() {
+    assert_eq!(Label::normalize_name(" bug "), Ok("bug".to_string()));
+    assert_eq!(
+        Label::normalize_name("agent-ok"),
+        Ok("agent-ok".to_string())
+    );
+    assert!(Label::normalize_name("").is_err());
+    assert!(Label::normalize_name("two words").is_err());
+    assert!(Label::normalize_name("a,b").is_err());
+}
+
+#[test]
+fn test_normalize_color() {
+    assert_eq!(Label::normalize_color("Red"), Ok("red".to_string()));
+    assert_eq!(Label::normalize_color("#FF8800"), Ok("#ff8800".to_string()));
+    assert!(Label::normalize_color("purple").is_err());
+    assert!(Label::normalize_color("#12345").is_err());
+}
+
+#[test]
+fn test_label_deserialize_without_color() {
+    let label: Label = serde_json::from_str(r#"{"name": "bug"}"#).unwrap();
+
+    assert_eq!(label, Label::new("bug".to_string()));
+}
@@ -0,0 +1,545 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use super::{Activity, Board, Card, Column, Label};
+
+/// Board fields merged by dedicated rules rather than value by value.
+const STRUCTURED_BOARD_FIELDS: [&str; 8] = [
+    "cards",
+    "columns",
+    "labels",
+    "activity",
+    "next_card_number",
+    "revision",
+    "created_at",
+    "updated_at",
+];
+
+/// Result of a three-way merge of two versions of a board.
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeOutcome {
+    /// The merged board; conflicting fields keep our value
+    #[serde(skip)]
+    pub board: Board,
+    /// Cards created on both sides with the same ID; their card was renamed
+    pub renumbered: Vec<Renumbering>,
+    /// Changes that could not be reconciled
+    pub conflicts: Vec<MergeConflict>,
+}
+
+/// A card of the other side that received a new ID during a merge.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Renumbering {
+    pub from: String,
+    pub to: String,
+}
+
+/// A field changed differently on both sides of a merge.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MergeConflict {
+    /// What the field belongs to, e.g. "board", "card PRJ-004" or "column review"
+    pub scope: String,
+    /// Name of the conflicting field
+    pub field: String,
+    /// Our value, which the merged board keeps
+    pub ours: String,
+    /// Their value
+    pub theirs: String,
+}
+
+impl MergeConflict {
+    fn new(scope: &str, field: &str, ours: impl Into<String>, theirs: impl Into<String>) -> Self {
+        Self {
+            scope: scope.to_string(),
+            field: field.to_string(),
+            ours: ours.into(),
+            theirs: theirs.into(),
+        }
+    }
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}: ours {}, theirs {}",
+            self.scope, self.field, self.ours, self.theirs
+        )
+    }
+}
+
+/// Merges the changes two sides made to a common base board.
+///
+/// - Cards added on either side are kept. Cards added on both sides with
+///   the same ID get a new ID on their side.
+/// - Cards edited on both sides are merged field by field; labels,
+///   blockers and comments are combined.
+/// - Column membership follows the merged cards.
+/// - Columns and labels are merged by ID and name.
+/// - The activity logs are combined.
+///
+/// Without a base (the board was added on both sides), every difference is
+/// a conflict. Conflicting fields keep our value and are reported.
+pub fn merge_boards(base: Option<&Board>, ours: &Board, theirs: &Board) -> MergeOutcome {
+    let mut conflicts = Vec::new();
+    let mut merged = merge_board_fields(base, ours, theirs, &mut conflicts);
+    merged.next_card_number = ours.next_card_number.max(theirs.next_card_number);
+
+    let mut theirs = theirs.clone();
+    let renumbered = renumber_collisions(base, ours, &mut theirs, &mut merged);
+
+    let empty = Vec::new();
+    let base_cards = base.map_or(&empty, |b| &b.cards);
+    merged.cards = merge_cards(base_cards, &ours.cards, &theirs.cards, &mut conflicts);
+    merged.columns = merge_columns(base, ours, &theirs, &merged.cards, &mut conflicts);
+    merged.labels = merge_labels(base, ours, &theirs, &mut conflicts);
+    merged.activity = ours.activity.clone();
+    let known: HashSet<String> = merged.activity.iter().map(activity_key).collect();
+    merged.activity.extend(
+        theirs
+            .activity
+            .iter()
+            .filter(|a| !known.contains(&activity_key(a)))
+            .cloned(),
+    );
+    merged.activity.sort_by_key(|a| a.timestamp);
+
+    // A new revision, so copies of either side are detected as outdated.
+    merged.revision = ours.revision.max(theirs.revision) + 1;
+    merged.updated_at = ours.updated_at.max(theirs.updated_at);
+
+    MergeOutcome {
+        board: merged,
+        renumbered,
+        conflicts,
+    }
+}
+
+/// Merges the plain board settings such as name and ID prefix.
+fn merge_board_fields(
+    base: Option<&Board>,
+    ours: &Board,
+    theirs: &Board,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Board {
+    let (Some(mut merged), Some(theirs_map)) = (to_map(ours), to_map(theirs)) else {
+        return ours.clone();
+    };
+    let base_map = base.and_then(to_map);
+
+    let keys: Vec<String> = field_names(&merged, &theirs_map)
+        .into_iter()
+        .filter(|k| !STRUCTURED_BOARD_FIELDS.contains(&k.as_str()))
+        .collect();
+    for key in keys {
+        let base_value = base_map.as_ref().and_then(|m| m.get(&key));
+        let value = merge_value(
+            base_value,
+            merged.get(&key),
+            theirs_map.get(&key),
+            "board",
+            &key,
+            conflicts,
+        );
+        if let Some(value) = value {
+            merged.insert(key, value);
+        }
+    }
+
+    serde_json::from_value(Value::Object(merged)).unwrap_or_else(|_| ours.clone())
+}
+
+/// Gives their cards new IDs where both sides created a different card
+/// with the same ID.
+fn renumber_collisions(
+    base: Option<&Board>,
+    ours: &Board,
+    theirs: &mut Board,
+    merged: &mut Board,
+) -> Vec<Renumbering> {
+    let colliding: Vec<String> = theirs
+        .cards
+        .iter()
+        .filter(|card| base.is_none_or(|b| b.get_card(&card.id).is_none()))
+        .filter(|card| ours.get_card(&card.id).is_some_and(|our| our != *card))
+        .map(|card| card.id.clone())
+        .collect();
+
+    let base_activity: HashSet<String> = base
+        .map(|b| b.activity.iter().map(activity_key).collect())
+        .unwrap_or_default();
+    let mut renumbered = Vec::new();
+    for from in colliding {
+        // Also skip IDs used only by their cards.
+        let to = loop {
+            let id = merged.generate_card_id();
+            if theirs.get_card(&id).is_none() {
+                break id;
+            }
+        };
+
+        if let Some(card) = theirs.get_card_mut(&from) {
+            card.id = to.clone();
+        }
+        for column in &mut theirs.columns {
+            for id in column.cards.iter_mut().filter(|id| **id == from) {
+                *id = to.clone();
+            }
+        }
+        for card in &mut theirs.cards {
+            if card.blocked_by.remove(&from) {
+                card.blocked_by.insert(to.clone());
+            }
+            if card.parent.as_ref() == Some(&from) {
+                card.parent = Some(to.clone());
+            }
+        }
+        for activity in &mut theirs.activity {
+            if activity.card_id == from && !base_activity.contains(&activity_key(activity)) {
+                activity.card_id = to.clone();
+            }
+        }
+        renumbered.push(Renumbering { from, to });
+    }
+    renumbered
+}
+
+/// Merges the cards of both sides, in our order followed by their new cards.
+fn merge_cards(
+    base: &[Card],
+    ours: &[Card],
+    theirs: &[Card],
+    conflicts: &mut Vec<MergeConflict>,
+) -> Vec<Card> {
+    let base_cards: HashMap<&str, &Card> = base.iter().map(|c| (c.id.as_str(), c)).collect();
+    let their_cards: HashMap<&str, &Card> = theirs.iter().map(|c| (c.id.as_str(), c)).collect();
+    let our_ids: HashSet<&str> = ours.iter().map(|c| c.id.as_str()).collect();
+
+    let mut merged = Vec::new();
+    for our in ours {
+        let scope = format!("card {}", our.id);
+        match (
+            base_cards.get(our.id.as_str()),
+            their_cards.get(our.id.as_str()),
+        ) {
+            (base, Some(their)) => merged.push(merge_card(base.copied(), our, their, conflicts)),
+            (Some(base), None) if our == *base => {}
+            (Some(_), None) => {
+                conflicts.push(MergeConflict::new(&scope, "card", "modified", "deleted"));
+                merged.push(our.clone());
+            }
+            (None, None) => merged.push(our.clone()),
+        }
+    }
+
+    for their in theirs.iter().filter(|c| !our_ids.contains(c.id.as_str())) {
+        let scope = format!("card {}", their.id);
+        match base_cards.get(their.id.as_str()) {
+            Some(base) if their == *base => {}
+            Some(_) => {
+                conflicts.push(MergeConflict::new(&scope, "card", "deleted", "modified"));
+                merged.push(their.clone());
+            }
+            None => merged.push(their.clone()),
+        }
+    }
+    merged
+}
+
+/// Merges the changes both sides made to one card.
+fn merge_card(
+    base: Option<&Card>,
+    ours: &Card,
+    theirs: &Card,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Card {
+    let (Some(mut merged), Some(theirs_map)) = (to_map(ours), to_map(theirs)) else {
+        return ours.clone();
+    };
+    let base_map = base.and_then(to_map);
+    let scope = format!("card {}", ours.id);
+
+    for key in field_names(&merged, &theirs_map) {
+        let base_value = base_map.as_ref().and_then(|m| m.get(&key));
+        let (our_value, their_value) = (merged.get(&key), theirs_map.get(&key));
+        let value = match key.as_str() {
+            "labels" | "blocked_by" | "comments" => {
+                Some(merge_collection(base_value, our_value, their_value))
+            }
+            "updated_at" => continue,
+            _ => merge_value(base_value, our_value, their_value, &scope, &key, conflicts),
+        };
+        if let Some(value) = value {
+            merged.insert(key, value);
+        }
+    }
+
+    let mut card: Card =
+        serde_json::from_value(Value::Object(merged)).unwrap_or_else(|_| ours.clone());
+    card.comments.sort_by_key(|c| c.created_at);
+    card.updated_at = ours.updated_at.max(theirs.updated_at);
+    card
+}
+
+/// Merges the column definitions and rebuilds each column's card list.
+fn merge_columns(
+    base: Option<&Board>,
+    ours: &Board,
+    theirs: &Board,
+    cards: &[Card],
+    conflicts: &mut Vec<MergeConflict>,
+) -> Vec<Column> {
+    let ids =
+        |board: &Board| -> Vec<String> { board.columns.iter().map(|c| c.id.clone()).collect() };
+    let find = |board: Option<&Board>, id: &str| -> Option<Column> {
+        board.and_then(|b| b.columns.iter().find(|c| c.id == id).cloned())
+    };
+
+    // Follow their column order if we did not reorder.
+    let (primary, secondary) = if base.is_some_and(|b| ids(b) == ids(ours)) {
+        (theirs, ours)
+    } else {
+        (ours, theirs)
+    };
+
+    let mut merged: Vec<Column> = Vec::new();
+    for (index, column) in primary.columns.iter().enumerate() {
+        let base_column = find(base, &column.id);
+        let other = find(Some(secondary), &column.id);
+        if let Some(column) = merge_column(base_column.as_ref(), column, other.as_ref(), conflicts)
+        {
+            merged.insert(index.min(merged.len()), column);
+        }
+    }
+    for (index, column) in secondary.columns.iter().enumerate() {
+        if find(Some(primary), &column.id).is_none() {
+            let base_column = find(base, &column.id);
+            if let Some(column) = merge_column(base_column.as_ref(), column, None, conflicts) {
+                merged.insert(index.min(merged.len()), column);
+            }
+        }
+    }
+
+    // Keep removed columns that still hold cards.
+    for card in cards {
+        if !merged.iter().any(|c| c.id == card.column_id) {
+            if let Some(column) =
+                find(Some(ours), &card.column_id).or_else(|| find(Some(theirs), &card.column_id))
+            {
+                let scope = format!("column {}", column.id);
+                conflicts.push(MergeConflict::new(&scope, "column", "has cards", "deleted"));
+                merged.push(column);
+            }
+        }
+    }
+
+    for (order, column) in merged.iter_mut().enumerate() {
+        column.order = order as u32;
+        let mut members: Vec<String> = Vec::new();
+        let candidates = find(Some(ours), &column.id)
+            .into_iter()
+            .chain(find(Some(theirs), &column.id))
+            .flat_map(|c| c.cards)
+            .chain(cards.iter().map(|c| c.id.clone()));
+        for id in candidates {
+            let belongs = cards.iter().any(|c| c.id == id && c.column_id == column.id);
+            if belongs && !members.contains(&id) {
+                members.push(id);
+            }
+        }
+        column.cards = members;
+    }
+    merged
+}
+
+/// Merges one column present on one or both sides.
+///
+/// Returns `None` if the column was removed on one side and left
+/// unchanged on the other.
+fn merge_column(
+    base: Option<&Column>,
+    column: &Column,
+    other: Option<&Column>,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Option<Column> {
+    let scope = format!("column {}", column.id);
+    match (base, other) {
+        (_, Some(other)) => {
+            let name = merge_value(
+                base.map(|b| Value::from(b.name.clone())).as_ref(),
+                Some(&Value::from(column.name.clone())),
+                Some(&Value::from(other.name.clone())),
+                &scope,
+                "name",
+                conflicts,
+            );
+            let wip_limit = merge_value(
+                base.map(wip_limit_value).as_ref(),
+                Some(&wip_limit_value(column)),
+                Some(&wip_limit_value(other)),
+                &scope,
+                "wip_limit",
+                conflicts,
+            );
+            let mut merged = column.clone();
+            if let Some(Value::String(name)) = name {
+                merged.name = name;
+            }
+            merged.wip_limit = wip_limit
+                .and_then(|v| v.as_u64())
+                .and_then(|v| u32::try_from(v).ok());
+            Some(merged)
+        }
+        (Some(base), None) if base.name == column.name && base.wip_limit == column.wip_limit => {
+            None
+        }
+        (Some(_), None) => {
+            conflicts.push(MergeConflict::new(&scope, "column", "modified", "deleted"));
+            Some(column.clone())
+        }
+        (None, None) => Some(column.clone()),
+    }
+}
+
+/// Merges the label registries by name.
+fn merge_labels(
+    base: Option<&Board>,
+    ours: &Board,
+    theirs: &Board,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Vec<Label> {
+    let base_label = |name: &str| base.and_then(|b| b.get_label(name));
+    let mut merged = Vec::new();
+
+    for our in &ours.labels {
+        let scope = format!("label {}", our.name);
+        match (base_label(&our.name), theirs.get_label(&our.name)) {
+            (base, Some(their)) => {
+                let color = merge_value(
+                    base.map(color_value).as_ref(),
+                    Some(&color_value(our)),
+                    Some(&color_value(their)),
+                    &scope,
+                    "color",
+                    conflicts,
+                );
+                let mut label = our.clone();
+                label.color = color.and_then(|c| c.as_str().map(str::to_string));
+                merged.push(label);
+            }
+            (Some(base), None) if base == our => {}
+            (Some(_), None) => {
+                conflicts.push(MergeConflict::new(&scope, "label", "modified", "deleted"));
+                merged.push(our.clone());
+            }
+            (None, None) => merged.push(our.clone()),
+        }
+    }
+    for their in &theirs.labels {
+        if ours.get_label(&their.name).is_none() {
+            match base_label(&their.name) {
+                Some(base) if base == their => {}
+                Some(_) => {
+                    let scope = format!("label {}", their.name);
+                    conflicts.push(MergeConflict::new(&scope, "label", "deleted", "modified"));
+                    merged.push(their.clone());
+                }
+                None => merged.push(their.clone()),
+            }
+        }
+    }
+    merged
+}
+
+fn color_value(label: &Label) -> Value {
+    label.color.clone().map_or(Value::Null, Value::from)
+}
+
+/// Gets a column's WIP limit as a JSON value for merging.
+fn wip_limit_value(column: &Column) -> Value {
+    column.wip_limit.map_or(Value::Null, Value::from)
+}
+
+/// Merges a single value changed on one or both sides.
+///
+/// Returns the merged value, or `None` if the field is absent. A value
+/// changed differently on both sides is reported and keeps our version.
+fn merge_value(
+    base: Option<&Value>,
+    ours: Option<&Value>,
+    theirs: Option<&Value>,
+    scope: &str,
+    field: &str,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Option<Value> {
+    if ours == theirs || theirs == base {
+        ours.cloned()
+    } else if ours == base {
+        theirs.cloned()
+    } else {
+        conflicts.push(MergeConflict::new(
+            scope,
+            field,
+            describe(ours),
+            describe(theirs),
+        ));
+        ours.cloned()
+    }
+}
+
+/// Merges a list of values as a set: items added on either side are kept,
+/// items removed on either side are dropped.
+fn merge_collection(base: Option<&Value>, ours: Option<&Value>, theirs: Option<&Value>) -> Value {
+    let items = |value: Option<&Value>| -> Vec<Value> {
+        value.and_then(Value::as_array).cloned().unwrap_or_default()
+    };
+    let (base, ours, theirs) = (items(base), items(ours), items(theirs));
+
+    let kept = |item: &Value, other: &[Value]| other.contains(item) || !base.contains(item);
+    let mut merged: Vec<Value> = ours
+        .iter()
+        .filter(|item| kept(item, &theirs))
+        .cloned()
+        .collect();
+    for item in theirs.iter().filter(|item| kept(item, &ours)) {
+        if !merged.contains(item) {
+            merged.push(item.clone());
+        }
+    }
+    Value::Array(merged)
+}
+
+/// Describes a value for a conflict report.
+fn describe(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => "(none)".to_string(),
+        Some(Value::String(s)) => format!("\"{}\"", s),
+        Some(other) => other.to_string(),
+    }
+}
+
+/// Names of the fields present on either side.
+fn field_names(ours: &Map<String, Value>, theirs: &Map<String, Value>) -> Vec<String> {
+    let mut names: Vec<String> = ours.keys().cloned().collect();
+    names.extend(theirs.keys().filter(|k| !ours.contains_key(*k)).cloned());
+    names
+}
+
+fn to_map<T: Serialize>(value: &T) -> Option<Map<String, Value>> {
+    match serde_json::to_value(value) {
+        Ok(Value::Object(map)) => Some(map),
+        _ => None,
+    }
+}
+
+/// Identifies an activity entry when combining logs.
+fn activity_key(activity: &Activity) -> String {
+    serde_json::to_string(activity).unwrap_or_default()
+}
+
+#[cfg(test)]
+#[path = "./merge_tests.rs"]
+mod merge_tests;
@@ -0,0 +1,268 @@
+use super::*;
+use crate::domain::{ActivityKind, Priority};
+
+fn base_board() -> Board {
+    let mut board = Board::new("test".to_string(), "Test Board".to_string());
+    board.create_card("First".to_string(), None, None, None);
+    board.create_card("Second".to_string(), None, None, None);
+    board
+}
+
+fn card_ids(board: &Board) -> Vec<&str> {
+    board.cards.iter().map(|c| c.id.as_str()).collect()
+}
+
+#[test]
+fn test_merge_keeps_cards_added_on_both_sides() {
+    // Arrange
+    let base = base_board();
+    let mut ours = base.clone();
+    ours.create_card("Ours".to_string(), None, None, None);
+    let mut theirs = base.clone();
+    theirs.create_card("Theirs".to_string(), None, None, None);
+
+    // Act
+    let outcome = merge_boards(Some(&base), &ours, &theirs);
+
+    // Assert
+    let board = &outcome.board;
+    assert!(outcome.conflicts.is_empty());
+    assert_eq!(
+        outcome.renumbered,
+        vec![Renumbering {
+            from: "TES-003".to_string(),
+            to: "TES-004".to_string()
+        }]
+    );
+    assert_eq!(
+        card_ids(board),
+        vec!["TES-001", "TES-002", "TES-003", "TES-004"]
+    );
+    assert_eq!(board.get_card("TES-003").unwrap().title, "Ours");
+    assert_eq!(board.get_card("TES-004").unwrap().title, "Theirs");
+    assert_eq!(
+        board.columns[0].cards,
+        vec!["TES-001", "TES-002", "TES-003", "TES-004"]
+    );
+    assert_eq!(board.next_card_number, 5);
+}
+
+#[test]
+fn test_merge_combines_field_edits_to_the_same_card() {
+    // Arrange
+    let base = base_board();
+    let mut ours = base.clone();
+    ours.get_card_mut("TES-001")
+        .unwrap()
+        .set_title("Renamed".to_string());
+    ours.get_card_mut("TES-001")
+        .unwrap()
+        .add_label("bug".to_string());
+    let mut theirs = base.clone();
+    theirs
+        .get_card_mut("TES-001")
+        .unwrap()
+        .set_priority(Some(Priority::High));
+    theirs
+        .get_card_mut("TES-001")
+        .unwrap()
+        .add_label("docs".to_string());
+
+    // Act
+    let outcome = merge_boards(Some(&base), &ours, &theirs);
+
+    // Assert
+    let card = outcome.board.get_card("TES-001").unwrap();
+    assert!(outcome.conflicts.is_empty());
+    assert_eq!(card.title, "Renamed");
+    assert_eq!(card.priority, Some(Priority::High));
+    assert!(card.has_label("bug"));
+    assert!(card.has_label("docs"));
+}
+
+#[test]
+fn test_merge_reports_conflicting_field_edits() {
+    // Arrange
+    let base = base_board();
+    let mut ours = base.clone();
+    ours.get_card_mut("TES-002")
+        .unwrap()
+        .set_title("Ours".to_string());
+    let mut theirs = base.clone();
+    theirs
+        .get_card_mut("TES-002")
+        .unwrap()
+        .set_title("Theirs".to_string());
+
+    // Act
+    let outcome = merge_boards(Some(&base), &ours, &theirs);
+
+    // Assert
+    assert_eq!(outcome.conflicts.len(), 1);
+    assert_eq!(
+        outcome.conflicts[0].to_string(),
+        "card TES-002: title: ours \"Ours\", theirs \"Theirs\""
+    );
+    assert_eq!(outcome.board.get_card("TES-002").unwrap().title, "Ours");
+}
+
+#[test]
+fn test_merge_reconciles_column_membership() {
+    // Arrange
+    let base = base_board();
+    let mut ours = base.clone();
+    ours.move_card("TES-001", "in_progress");
+    let mut theirs = base.clone();
+    theirs.move_card("TES-002", "done");
+
+    // Act
+    let outcome = merge_boards(Some(&base), &ours, &theirs);
+
+    // Assert
+    let board = &outcome.board;
+    assert!(outcome.conflicts.is_empty());
+    assert!(board.columns[0].cards.is_empty());
+    assert_eq!(board.columns[1].cards, vec!["TES-001"]);
+    assert_eq!(board.columns[2].cards, vec!["TES-002"]);
+}
+
+#[test]
+fn test_merge_applies_deletions_of_unchanged_cards() {
+    // Arrange
+    let base = base_board();
+    let mut ours = base.clone();
+    ours.delete_card("TES-001");
+    let mut theirs = base.clone();
+    theirs
+        .get_card_mut("TES-002")
+        .unwrap()
+        .set_title("Edited".to_string());
+
+    // Act
+    let outcome = merge_boards(Some(&base), &ours, &theirs);
+
+    // Assert
+    assert!(outcome.conflicts.is_empty());
+    assert_eq!(card_ids(&outcome.board), vec!["TES-002"]);
+    assert_eq!(outcome.board.columns[0].cards, vec!["TES-002"]);
+}
+
+#[test]
+fn test_merge_reports_deletion_of_modified_card() {
+    // Arrange
+    let base = base_board();
+    let mut ours = base.clone();
+    ours.delete_card("TES-001");
+    let mut theirs = base.clone();
+    theirs
+        .get_card_mut("TES-001")
+        .unwrap()
+        .set_title("Edited".to_string());
+
+    // Act
+    let outcome = merge_boards(Some(&base), &ours, &theirs);
+
+    // Assert
+    assert_eq!(
+        outcome.conflicts,
+        vec![MergeConflict::new(
+            "card TES-001",
+            "card",
+            "deleted",
+            "modified"
+        )]
+    );
+    assert_eq!(outcome.board.get_card("TES-001").unwrap().title, "Edited");
+}
+
+#[test]
+fn test_merge_combines_activity_and_bumps_revision() {
+    // Arrange
+    let mut base = base_board();
+    base.revision = 3;
+    let mut ours = base.clone();
+    ours.revision = 4;
+    ours.record_activity(Activity::new(
+        "TES-001".to_string(),
+        "alice".to_string(),
+        ActivityKind::Commented,
+    ));
+    let mut theirs = base.clone();
+    theirs.revision = 5;
+    theirs.record_activity(Activity::new(
+        "TES-002".to_string(),
+        "bob".to_string(),
+        ActivityKind::Commented,
+    ));
+
+    // Act
+    let outcome = merge_boards(Some(&base), &ours, &theirs);
+
+    // Assert
+    assert_eq!(outcome.board.activity.len(), 2);
+    assert_eq!(outcome.board.revision, 6);
+}
+
+#[test]
+fn test_merge_takes_wip_limit_changed_on_their_side() {
+    // Arrange
+    let base = base_board();
+    let mut ours = base.clone();
+    ours.rename_column("todo", "Backlog".to_string());
+    let mut theirs = base.clone();
+    theirs.set_wip_limit("in_progress", Some(3));
+
+    // Act
+    let outcome = merge_boards(Some(&base), &ours, &theirs);
+
+    // Assert
+    assert!(outcome.conflicts.is_empty());
+    assert_eq!(outcome.board.columns[0].name, "Backlog");
+    assert_eq!(outcome.board.columns[1].wip_limit, Some(3));
+}
+
+#[test]
+fn test_merge_combines_blockers_and_follows_renumbering() {
+    // Arrange
+    let base = base_board();
+    let mut ours = base.clone();
+    ours.create_card("Ours".to_string(), None, None, None);
+    ours.add_blocker("TES-001", "TES-002").unwrap();
+    let mut theirs = base.clone();
+    theirs.create_card("Theirs".to_string(), None, None, None);
+    theirs.add_blocker("TES-001", "TES-003").unwrap();
+
+    // Act
+    let outcome = merge_boards(Some(&base), &ours, &theirs);
+
+    // Assert
+    let card = outcome.board.get_card("TES-001").unwrap();
+    assert!(outcome.conflicts.is_empty());
+    assert_eq!(
+        card.blocked_by.iter().collect::<Vec<_>>(),
+        vec!["TES-002", "TES-004"]
+    );
+}
+
+#[test]
+fn test_merge_follows_renumbering_of_parent() {
+    // Arrange
+    let base = base_board();
+    let mut ours = base.clone();
+    ours.create_card("Ours".to_string(), None, None, None);
+    let mut theirs = base.clone();
+    theirs.create_card("Their epic".to_string(), None, None, None);
+    theirs.set_parent("TES-002", Some("TES-003")).unwrap();
+
+    // Act
+    let outcome = merge_boards(Some(&base), &ours, &theirs);
+
+    // Assert
+    let card = outcome.board.get_card("TES-002").unwrap();
+    assert!(outcome.conflicts.is_empty());
+    assert_eq!(card.parent.as_deref(), Some("TES-004"));
+    assert_eq!(
+        outcome.board.get_card("TES-004").unwrap().title,
+        "Their epic"
+    );
+}
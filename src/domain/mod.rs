@@ -4,11 +4,60 @@
 //! - Cards: Individual tasks with properties
 //! - Columns: Status categories that contain cards
 //! - Boards: Collections of columns representing a project
+//! - Priorities: Relative importance of cards
+//! - Labels: Tags used to categorize and query cards
+//! - Due dates: Deadlines, parsed from absolute or relative input
+//! - Claims: Leases on cards held by whoever works on them
+//! - Checklists: Ordered steps inside a card
+//! - Hierarchy: Parent and child cards, such as epics and their stories
+//! - Comments: Notes left on cards by people and agents
+//! - Activity: Audit log of changes made to cards
+//! - Diffs: Changes to cards between two versions of a board
+//! - Filters: Criteria for selecting cards
+//! - Dependencies: Cards that must be done before others can start
+//! - Next card: Picking the card to work on next
+//! - Consistency: Checks and repairs of a board's internal references
+//! - Merging: Three-way merge of diverged boards
+//! - Journal: Card operations that can be undone and redone
+//! - WIP limits: How many cards a column may hold
 
+pub mod activity;
 pub mod board;
+pub mod board_diff;
 pub mod card;
+pub mod card_filter;
+pub mod checklist;
+pub mod claim;
 pub mod column;
+pub mod comment;
+pub mod consistency;
+pub mod dependency;
+pub mod due_date;
+pub mod hierarchy;
+pub mod journal;
+pub mod label;
+pub mod merge;
+pub mod next;
+pub mod priority;
+pub mod wip;
 
-pub use board::Board;
+pub use activity::{
+    parse_duration, parse_since, Activity, ActivityFilter, ActivityKind, FieldChange,
+};
+pub use board::{Board, CardIdScheme};
+pub use board_diff::BoardDiff;
 pub use card::Card;
-pub use column::Column;
+pub use card_filter::{CardFilter, LabelMatch};
+pub use checklist::ChecklistItem;
+pub use claim::Claim;
+pub use column::{CardPosition, Column};
+pub use comment::Comment;
+pub use consistency::Issue;
+pub use dependency::LinkError;
+pub use due_date::parse_due_date;
+pub use hierarchy::{ChildPolicy, ParentError};
+pub use journal::{CardState, Journal, Operation};
+pub use label::Label;
+pub use merge::{merge_boards, MergeOutcome};
+pub use priority::Priority;
+pub use wip::{WipLimitExceeded, WipPolicy};
@@ -0,0 +1,38 @@
+use std::cmp::Reverse;
+
+use chrono::{DateTime, Utc};
+
+use super::{Board, Card, CardFilter};
+
+impl Board {
+    /// Picks the card to work on next from the given columns.
+    ///
+    /// Candidates are the ready cards of the columns that match `filter`
+    /// and, unless the filter asks for an assignee, are unassigned. Cards
+    /// claimed by someone other than `actor` at `now` are skipped. The most
+    /// important candidate wins; ties go to the card ranked higher in its
+    /// column, then to the older card.
+    pub fn next_card(
+        &self,
+        column_ids: &[String],
+        filter: &CardFilter,
+        actor: &str,
+        now: DateTime<Utc>,
+    ) -> Option<&Card> {
+        column_ids
+            .iter()
+            .flat_map(|column_id| self.get_cards_in_column(column_id).into_iter().enumerate())
+            .filter(|(_, card)| filter.assignee.is_some() || card.assignee.is_none())
+            .filter(|(_, card)| {
+                card.active_claim(now)
+                    .is_none_or(|claim| claim.claimant == actor)
+            })
+            .filter(|(_, card)| filter.matches(card) && self.is_ready(card))
+            .min_by_key(|(rank, card)| (Reverse(card.priority), *rank, card.created_at))
+            .map(|(_, card)| card)
+    }
+}
+
+#[cfg(test)]
+#[path = "./next_tests.rs"]
+mod next_tests;
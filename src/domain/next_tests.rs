@@ -0,0 +1,131 @@
+use super::*;
+use crate::domain::Priority;
+use chrono::Duration;
+
+fn todo() -> Vec<String> {
+    vec!["todo".to_string()]
+}
+
+fn board_with_cards(count: usize) -> Board {
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    for i in 0..count {
+        board.create_card(format!("Task {}", i), None, None, None);
+    }
+    board
+}
+
+#[test]
+fn test_next_card_prefers_priority_then_rank() {
+    // Arrange
+    let mut board = board_with_cards(3);
+    board
+        .get_card_mut("TES-003")
+        .unwrap()
+        .set_priority(Some(Priority::High));
+
+    // Act & Assert
+    assert_eq!(
+        board
+            .next_card(&todo(), &CardFilter::default(), "alice", Utc::now())
+            .unwrap()
+            .id,
+        "TES-003"
+    );
+
+    // Act
+    board.get_card_mut("TES-003").unwrap().set_priority(None);
+    board.rank_card("TES-002", &crate::domain::CardPosition::Top);
+
+    // Assert
+    assert_eq!(
+        board
+            .next_card(&todo(), &CardFilter::default(), "alice", Utc::now())
+            .unwrap()
+            .id,
+        "TES-002"
+    );
+}
+
+#[test]
+fn test_next_card_skips_assigned_and_blocked_cards() {
+    // Arrange
+    let mut board = board_with_cards(3);
+    board
+        .get_card_mut("TES-001")
+        .unwrap()
+        .set_assignee(Some("alice".to_string()));
+    board.add_blocker("TES-002", "TES-001").unwrap();
+
+    // Act
+    let next = board.next_card(&todo(), &CardFilter::default(), "alice", Utc::now());
+
+    // Assert
+    assert_eq!(next.unwrap().id, "TES-003");
+}
+
+#[test]
+fn test_next_card_with_assignee_picks_their_cards() {
+    // Arrange
+    let mut board = board_with_cards(2);
+    board
+        .get_card_mut("TES-002")
+        .unwrap()
+        .set_assignee(Some("alice".to_string()));
+    let filter = CardFilter {
+        assignee: Some("alice".to_string()),
+        ..Default::default()
+    };
+
+    // Act
+    let next = board.next_card(&todo(), &filter, "alice", Utc::now());
+
+    // Assert
+    assert_eq!(next.unwrap().id, "TES-002");
+}
+
+#[test]
+fn test_next_card_only_considers_given_columns() {
+    // Arrange
+    let mut board = board_with_cards(2);
+    board.move_card("TES-001", "in_progress");
+    board.move_card("TES-002", "done");
+
+    // Act & Assert
+    assert!(board
+        .next_card(&todo(), &CardFilter::default(), "alice", Utc::now())
+        .is_none());
+    assert_eq!(
+        board
+            .next_card(
+                &["in_progress".to_string()],
+                &CardFilter::default(),
+                "alice",
+                Utc::now()
+            )
+            .unwrap()
+            .id,
+        "TES-001"
+    );
+}
+
+#[test]
+fn test_next_card_skips_cards_claimed_by_others() {
+    // Arrange
+    let mut board = board_with_cards(2);
+    let now = Utc::now();
+    board.claim_card("TES-001", "bob", Duration::minutes(30), now);
+    let filter = CardFilter {
+        assignee: Some("bob".to_string()),
+        ..Default::default()
+    };
+
+    // Act
+    let for_alice = board.next_card(&todo(), &filter, "alice", now);
+    let for_bob = board.next_card(&todo(), &filter, "bob", now);
+    let after_expiry = board.next_card(&todo(), &filter, "alice", now + Duration::hours(1));
+
+    // Assert
+    assert!(for_alice.is_none());
+    assert_eq!(for_bob.unwrap().id, "TES-001");
+    assert_eq!(after_expiry.unwrap().id, "TES-001");
+}
@@ -0,0 +1,57 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Importance of a card.
+///
+/// Variants are ordered from least to most important, so comparisons
+/// read naturally (`Priority::Critical > Priority::Low`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl Priority {
+    /// Returns the lowercase name of the priority.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Priority::Low => "low",
+            Priority::Medium => "medium",
+            Priority::High => "high",
+            Priority::Critical => "critical",
+        }
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Priority {
+    type Err = String;
+
+    /// Parses a priority name (case-insensitive) or its first letter.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "critical" | "c" => Ok(Priority::Critical),
+            "high" | "h" => Ok(Priority::High),
+            "medium" | "m" => Ok(Priority::Medium),
+            "low" | "l" => Ok(Priority::Low),
+            _ => Err(format!(
+                "Invalid priority '{}': expected critical, high, medium or low",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./priority_tests.rs"]
+mod priority_tests;
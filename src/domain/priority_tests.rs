@@ -0,0 +1,26 @@
+use super::*;
+
+#[test]
+fn test_priority_ordering() {
+    assert!(Priority::Critical > Priority::High);
+    assert!(Priority::High > Priority::Medium);
+    assert!(Priority::Medium > Priority::Low);
+}
+
+#[test]
+fn test_priority_from_str() {
+    assert_eq!("critical".parse::<Priority>(), Ok(Priority::Critical));
+    assert_eq!("HIGH".parse::<Priority>(), Ok(Priority::High));
+    assert_eq!(" medium ".parse::<Priority>(), Ok(Priority::Medium));
+    assert_eq!("l".parse::<Priority>(), Ok(Priority::Low));
+    assert!("urgent".parse::<Priority>().is_err());
+}
+
+#[test]
+fn test_priority_serialization() {
+    let json = serde_json::to_string(&Priority::High).unwrap();
+    assert_eq!(json, "\"high\"");
+
+    let parsed: Priority = serde_json::from_str("\"critical\"").unwrap();
+    assert_eq!(parsed, Priority::Critical);
+}
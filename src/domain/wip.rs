@@ -0,0 +1,103 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::Board;
+
+/// What happens when a change takes a column over its WIP limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WipPolicy {
+    /// Allow the change and warn about the full column
+    #[default]
+    Warn,
+    /// Reject the change unless it is forced
+    Block,
+}
+
+impl WipPolicy {
+    /// Returns the lowercase name of the policy.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WipPolicy::Warn => "warn",
+            WipPolicy::Block => "block",
+        }
+    }
+}
+
+impl fmt::Display for WipPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WipPolicy {
+    type Err = String;
+
+    /// Parses a policy name (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "warn" => Ok(WipPolicy::Warn),
+            "block" => Ok(WipPolicy::Block),
+            _ => Err(format!(
+                "Invalid WIP policy '{}': expected warn or block",
+                s
+            )),
+        }
+    }
+}
+
+/// A column holding more cards than its WIP limit allows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, thiserror::Error)]
+#[error("Column {column_id} is over its WIP limit ({count}/{limit})")]
+pub struct WipLimitExceeded {
+    pub column_id: String,
+    pub limit: u32,
+    /// Cards shown in the column
+    pub count: usize,
+}
+
+impl Board {
+    /// Sets or clears the WIP limit of a column.
+    ///
+    /// Returns true if the column was found.
+    pub fn set_wip_limit(&mut self, column_id: &str, limit: Option<u32>) -> bool {
+        if let Some(column) = self.columns.iter_mut().find(|c| c.id == column_id) {
+            column.wip_limit = limit;
+            self.updated_at = Utc::now();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Checks that a column holds no more cards than its WIP limit.
+    ///
+    /// Archived cards do not count. Columns without a limit always pass.
+    pub fn check_wip_limit(&self, column_id: &str) -> Result<(), WipLimitExceeded> {
+        let Some(limit) = self
+            .columns
+            .iter()
+            .find(|c| c.id == column_id)
+            .and_then(|c| c.wip_limit)
+        else {
+            return Ok(());
+        };
+
+        let count = self.get_cards_in_column(column_id).len();
+        if count > limit as usize {
+            return Err(WipLimitExceeded {
+                column_id: column_id.to_string(),
+                limit,
+                count,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "./wip_tests.rs"]
+mod wip_tests;
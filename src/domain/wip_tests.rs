@@ -0,0 +1,70 @@
+use super::*;
+
+fn board_with_cards(count: usize) -> Board {
+    let mut board = Board::new("test".to_string(), "Test".to_string());
+    for i in 0..count {
+        board.create_card(format!("Task {}", i), None, None, None);
+    }
+    board
+}
+
+#[test]
+fn test_column_without_limit_passes() {
+    // Arrange
+    let board = board_with_cards(5);
+
+    // Act & Assert
+    assert_eq!(board.check_wip_limit("todo"), Ok(()));
+}
+
+#[test]
+fn test_column_over_limit_fails() {
+    // Arrange
+    let mut board = board_with_cards(3);
+    board.set_wip_limit("todo", Some(3));
+    assert_eq!(board.check_wip_limit("todo"), Ok(()));
+
+    // Act
+    board.create_card("One too many".to_string(), None, None, None);
+
+    // Assert
+    assert_eq!(
+        board.check_wip_limit("todo"),
+        Err(WipLimitExceeded {
+            column_id: "todo".to_string(),
+            limit: 3,
+            count: 4,
+        })
+    );
+}
+
+#[test]
+fn test_archived_cards_do_not_count() {
+    // Arrange
+    let mut board = board_with_cards(3);
+    board.set_wip_limit("todo", Some(2));
+
+    // Act
+    board.get_card_mut("TES-001").unwrap().archive();
+
+    // Assert
+    assert_eq!(board.check_wip_limit("todo"), Ok(()));
+}
+
+#[test]
+fn test_set_wip_limit_of_unknown_column() {
+    // Arrange
+    let mut board = board_with_cards(0);
+
+    // Act & Assert
+    assert!(!board.set_wip_limit("review", Some(2)));
+    assert!(board.set_wip_limit("todo", None));
+}
+
+#[test]
+fn test_wip_policy_from_str() {
+    assert_eq!("warn".parse::<WipPolicy>(), Ok(WipPolicy::Warn));
+    assert_eq!(" BLOCK ".parse::<WipPolicy>(), Ok(WipPolicy::Block));
+    assert!("strict".parse::<WipPolicy>().is_err());
+    assert_eq!(WipPolicy::default(), WipPolicy::Warn);
+}
@@ -0,0 +1,166 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::storage::{BoardStorage, StorageError};
+use crate::domain::CardIdScheme;
+
+/// Storage backends a board can be kept in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageBackend {
+    /// A single `board.json` file
+    #[default]
+    Json,
+    /// A SQLite database, `board.db`
+    Sqlite,
+    /// One file per card under `cards/`, for boards kept in git
+    Directory,
+}
+
+impl StorageBackend {
+    /// Returns the lowercase name of the backend.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StorageBackend::Json => "json",
+            StorageBackend::Sqlite => "sqlite",
+            StorageBackend::Directory => "directory",
+        }
+    }
+
+    /// Returns the card ID scheme boards in this backend should use.
+    ///
+    /// Directory boards are meant to be merged across branches, where
+    /// sequential IDs would collide.
+    pub fn card_id_scheme(self) -> CardIdScheme {
+        match self {
+            StorageBackend::Directory => CardIdScheme::Random,
+            StorageBackend::Json | StorageBackend::Sqlite => CardIdScheme::Sequential,
+        }
+    }
+}
+
+impl fmt::Display for StorageBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for StorageBackend {
+    type Err = String;
+
+    /// Parses a backend name (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "json" => Ok(StorageBackend::Json),
+            "sqlite" => Ok(StorageBackend::Sqlite),
+            "directory" => Ok(StorageBackend::Directory),
+            _ => Err(format!(
+                "Invalid storage backend '{}': expected json, sqlite or directory",
+                s
+            )),
+        }
+    }
+}
+
+/// Number of snapshots kept when the config does not say otherwise.
+pub const DEFAULT_SNAPSHOT_RETENTION: usize = 20;
+
+/// Minutes between snapshots of ordinary saves when the config does not say
+/// otherwise.
+pub const DEFAULT_SNAPSHOT_INTERVAL_MINUTES: u64 = 10;
+
+/// Per-board settings stored in `.clicky/config.json`.
+///
+/// Every field has a default, so boards without a config file and configs
+/// written by older versions remain valid.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardConfig {
+    /// Backend holding the board data
+    #[serde(default)]
+    pub storage: StorageBackend,
+    /// Number of snapshots kept in `.clicky/backups/`; `0` disables them.
+    ///
+    /// Copying the board is as expensive as saving it, so ordinary saves
+    /// take a snapshot only once `snapshot_interval_minutes` have passed
+    /// since the newest one. Destructive operations (deleting cards,
+    /// restoring a snapshot, repairing or migrating the board) always take
+    /// one of the board they are about to change. Merges are not covered:
+    /// both sides stay in git.
+    #[serde(default = "default_snapshot_retention")]
+    pub snapshot_retention: usize,
+    /// Minimum age of the newest snapshot before a save takes another;
+    /// `0` takes one on every save
+    #[serde(default = "default_snapshot_interval_minutes")]
+    pub snapshot_interval_minutes: u64,
+    /// Days a card stays in the done column before it is archived
+    /// automatically; unset disables automatic archiving
+    #[serde(default)]
+    pub auto_archive_days: Option<u32>,
+    /// Columns `clicky next` picks cards from; empty means the first column
+    #[serde(default)]
+    pub next_columns: Vec<String>,
+    /// Column `clicky next --claim` moves cards to; unset means the column
+    /// after the first
+    #[serde(default)]
+    pub claim_column: Option<String>,
+    /// Whether cards can only be moved to the done column once all their
+    /// checklist items are done
+    #[serde(default)]
+    pub require_checklist_done: bool,
+}
+
+impl Default for BoardConfig {
+    fn default() -> Self {
+        Self {
+            storage: StorageBackend::default(),
+            snapshot_retention: DEFAULT_SNAPSHOT_RETENTION,
+            snapshot_interval_minutes: DEFAULT_SNAPSHOT_INTERVAL_MINUTES,
+            auto_archive_days: None,
+            next_columns: Vec::new(),
+            claim_column: None,
+            require_checklist_done: false,
+        }
+    }
+}
+
+fn default_snapshot_retention() -> usize {
+    DEFAULT_SNAPSHOT_RETENTION
+}
+
+fn default_snapshot_interval_minutes() -> u64 {
+    DEFAULT_SNAPSHOT_INTERVAL_MINUTES
+}
+
+impl BoardConfig {
+    /// Loads the config of the board in `base_path`.
+    ///
+    /// Returns the default config if the board has no config file.
+    pub fn load(base_path: &Path) -> Result<Self, StorageError> {
+        let path = BoardStorage::config_path(base_path);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Saves the config of the board in `base_path`.
+    pub fn save(&self, base_path: &Path) -> Result<(), StorageError> {
+        let path = BoardStorage::config_path(base_path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "./config_tests.rs"]
+mod config_tests;
@@ -0,0 +1,77 @@
+use super::*;
+use tempfile::TempDir;
+
+#[test]
+fn test_missing_config_is_default() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+
+    // Act
+    let config = BoardConfig::load(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(config.storage, StorageBackend::Json);
+    assert_eq!(config.snapshot_retention, DEFAULT_SNAPSHOT_RETENTION);
+    assert_eq!(
+        config.snapshot_interval_minutes,
+        DEFAULT_SNAPSHOT_INTERVAL_MINUTES
+    );
+    assert_eq!(config.auto_archive_days, None);
+    assert!(config.next_columns.is_empty());
+    assert_eq!(config.claim_column, None);
+    assert!(!config.require_checklist_done);
+}
+
+#[test]
+fn test_config_save_and_load() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let config = BoardConfig {
+        storage: StorageBackend::Json,
+        snapshot_retention: 5,
+        snapshot_interval_minutes: 30,
+        auto_archive_days: Some(14),
+        next_columns: vec!["todo".to_string(), "review".to_string()],
+        claim_column: Some("doing".to_string()),
+        require_checklist_done: true,
+    };
+
+    // Act
+    config.save(temp_dir.path()).unwrap();
+    let loaded = BoardConfig::load(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(loaded, config);
+    assert!(temp_dir.path().join(".clicky").join("config.json").exists());
+}
+
+#[test]
+fn test_config_with_unknown_backend_fails() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let clicky_dir = temp_dir.path().join(".clicky");
+    fs::create_dir(&clicky_dir).unwrap();
+    fs::write(clicky_dir.join("config.json"), r#"{"storage": "floppy"}"#).unwrap();
+
+    // Act
+    let result = BoardConfig::load(temp_dir.path());
+
+    // Assert
+    assert!(matches!(result, Err(StorageError::Serialization(_))));
+}
+
+#[test]
+fn test_storage_backend_round_trips_through_names() {
+    for backend in [
+        StorageBackend::Json,
+        StorageBackend::Sqlite,
+        StorageBackend::Directory,
+    ] {
+        assert_eq!(backend.to_string().parse::<StorageBackend>(), Ok(backend));
+    }
+    assert_eq!(
+        "SQLite".parse::<StorageBackend>(),
+        Ok(StorageBackend::Sqlite)
+    );
+    assert!("floppy".parse::<StorageBackend>().is_err());
+}
@@ -0,0 +1,526 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde_json::{Map, Value};
+
+use super::lock::BoardLock;
+use super::migration::{self, CURRENT_SCHEMA_VERSION};
+use super::storage::{
+    write_atomic, BoardRepository, BoardStorage, MigrationReport, StorageError, StoredHeader,
+};
+use crate::domain::Board;
+
+/// Key holding a card's rank within its column in a card file.
+///
+/// Cards are ordered by rank. Ranks are sparse, so a card placed between
+/// two others gets a rank between theirs and no other card file changes.
+/// Files written by older versions hold dense positions, which order the
+/// same way.
+const POSITION_KEY: &str = "column_position";
+
+/// Gap between the ranks of cards appended to a column, leaving room for
+/// cards placed between them later.
+const RANK_STEP: u64 = 1 << 20;
+
+/// Board fields kept in the local state file instead of `meta.json`.
+///
+/// They change on every save, so tracking them in git would make every
+/// pair of branches conflict.
+const STATE_FIELDS: [&str; 2] = ["revision", "updated_at"];
+
+/// Directory-based implementation of BoardRepository.
+///
+/// Spreads a board over small files, so that branches changing different
+/// cards merge without conflicts:
+///
+/// ```text
+/// .clicky/
+/// ├── meta.json             # Board settings, columns and labels
+/// ├── cards/<ID>.json       # One card, with its position in its column
+/// ├── activity/<ID>.jsonl   # Activity of one card, one entry per line
+/// └── state.json            # Revision and last update (ignored by git)
+/// ```
+///
+/// Column card lists are not stored; they are rebuilt from the cards on
+/// load. Saves only rewrite files whose content changed. Each file is
+/// replaced atomically, but a save touching several files is not.
+pub struct DirectoryBoardRepository;
+
+impl DirectoryBoardRepository {
+    /// Creates a new directory board repository.
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn cards_dir(base_path: &Path) -> PathBuf {
+        BoardStorage::clicky_dir(base_path).join(BoardStorage::CARDS_DIR)
+    }
+
+    fn activity_dir(base_path: &Path) -> PathBuf {
+        BoardStorage::clicky_dir(base_path).join(BoardStorage::ACTIVITY_DIR)
+    }
+
+    fn state_path(base_path: &Path) -> PathBuf {
+        BoardStorage::clicky_dir(base_path).join(BoardStorage::STATE_FILE)
+    }
+
+    /// Reads the stored files into a `board.json` document.
+    ///
+    /// Returns the document, its schema version and the column position of
+    /// each card.
+    fn read_document(
+        &self,
+        base_path: &Path,
+    ) -> Result<(Value, u32, HashMap<String, u64>), StorageError> {
+        let meta_path = BoardStorage::meta_path(base_path);
+        if !meta_path.exists() {
+            return Err(StorageError::BoardNotFound(meta_path));
+        }
+
+        let mut document: Value = serde_json::from_str(&fs::read_to_string(&meta_path)?)?;
+        let version = migration::schema_version(&document)?;
+        let board = as_object(&mut document, &meta_path)?;
+
+        if let Some(Value::Array(columns)) = board.get_mut("columns") {
+            for column in columns.iter_mut().filter_map(Value::as_object_mut) {
+                column.insert("cards".to_string(), Value::Array(Vec::new()));
+            }
+        }
+
+        let mut cards = Vec::new();
+        let mut positions = HashMap::new();
+        for path in files_with_extension(&Self::cards_dir(base_path), "json")? {
+            let mut card: Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+            let object = as_object(&mut card, &path)?;
+            let position = object.remove(POSITION_KEY).and_then(|p| p.as_u64());
+            let id = object
+                .get("id")
+                .and_then(Value::as_str)
+                .ok_or_else(|| {
+                    StorageError::InvalidData(format!("Card file {} has no id", path.display()))
+                })?
+                .to_string();
+            if let Some(position) = position {
+                positions.insert(id, position);
+            }
+            cards.push(card);
+        }
+        board.insert("cards".to_string(), Value::Array(cards));
+
+        let mut activity = Vec::new();
+        for path in files_with_extension(&Self::activity_dir(base_path), "jsonl")? {
+            for line in fs::read_to_string(&path)?.lines() {
+                if !line.trim().is_empty() {
+                    activity.push(serde_json::from_str(line)?);
+                }
+            }
+        }
+        board.insert("activity".to_string(), Value::Array(activity));
+
+        let state_path = Self::state_path(base_path);
+        let mut state = match fs::read_to_string(&state_path) {
+            Ok(content) => serde_json::from_str(&content)?,
+            Err(_) => Value::Object(Map::new()),
+        };
+        let state = as_object(&mut state, &state_path)?;
+        // Fresh clones have no state yet: start over at revision 0.
+        let created_at = board.get("created_at").cloned().unwrap_or(Value::Null);
+        board.insert(
+            "revision".to_string(),
+            state.remove("revision").unwrap_or_else(|| 0.into()),
+        );
+        board.insert(
+            "updated_at".to_string(),
+            state.remove("updated_at").unwrap_or(created_at),
+        );
+
+        Ok((document, version, positions))
+    }
+
+    /// Builds a board from a document read by [`Self::read_document`].
+    fn into_board(
+        document: Value,
+        positions: &HashMap<String, u64>,
+    ) -> Result<Board, StorageError> {
+        let mut board: Board = serde_json::from_value(document)?;
+
+        // Files are read in ID order; restore creation order.
+        board.cards.sort_by(|a, b| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        board.activity.sort_by_key(|a| a.timestamp);
+
+        for column in &mut board.columns {
+            let mut cards: Vec<_> = board
+                .cards
+                .iter()
+                .filter(|c| c.column_id == column.id)
+                .collect();
+            // Cards added to the same position on different branches keep
+            // their creation order.
+            cards.sort_by_key(|c| positions.get(&c.id).copied().unwrap_or(u64::MAX));
+            column.cards = cards.into_iter().map(|c| c.id.clone()).collect();
+        }
+        Ok(board)
+    }
+
+    /// Writes the board settings, without cards, activity and local state.
+    fn write_meta(&self, board: &Board, base_path: &Path) -> Result<(), StorageError> {
+        let meta_path = BoardStorage::meta_path(base_path);
+        let mut document = serde_json::to_value(board)?;
+        let object = as_object(&mut document, &meta_path)?;
+        for field in ["cards", "activity"].into_iter().chain(STATE_FIELDS) {
+            object.remove(field);
+        }
+        if let Some(Value::Array(columns)) = object.get_mut("columns") {
+            for column in columns.iter_mut().filter_map(Value::as_object_mut) {
+                column.remove("cards");
+            }
+        }
+        object.insert("schema_version".to_string(), CURRENT_SCHEMA_VERSION.into());
+
+        write_if_changed(&meta_path, &to_pretty_json(&document)?)
+    }
+
+    /// Writes one file per card and removes the files of deleted cards.
+    ///
+    /// Cards keep their stored rank while it still fits the column order;
+    /// only moved and new cards get a new one, see [`assign_ranks`].
+    fn write_cards(&self, board: &Board, base_path: &Path) -> Result<(), StorageError> {
+        let stored = Self::read_ranks(base_path)?;
+        let mut ranks: HashMap<&str, u64> = HashMap::new();
+        for column in &board.columns {
+            let current: Vec<Option<u64>> = column
+                .cards
+                .iter()
+                .map(|id| stored.get(id).copied())
+                .collect();
+            for (id, rank) in column.cards.iter().zip(assign_ranks(&current)) {
+                ranks.insert(id.as_str(), rank);
+            }
+        }
+
+        let mut files = BTreeMap::new();
+        for card in &board.cards {
+            let mut document = serde_json::to_value(card)?;
+            if let (Some(object), Some(rank)) =
+                (document.as_object_mut(), ranks.get(card.id.as_str()))
+            {
+                object.insert(POSITION_KEY.to_string(), (*rank).into());
+            }
+            files.insert(
+                card_file_name(&card.id, "json")?,
+                to_pretty_json(&document)?,
+            );
+        }
+
+        sync_directory(&Self::cards_dir(base_path), "json", &files)
+    }
+
+    /// Reads the stored rank of each card.
+    fn read_ranks(base_path: &Path) -> Result<HashMap<String, u64>, StorageError> {
+        let mut ranks = HashMap::new();
+        for path in files_with_extension(&Self::cards_dir(base_path), "json")? {
+            // Unreadable card files are replaced by the save.
+            let Ok(card) = serde_json::from_str::<Value>(&fs::read_to_string(&path)?) else {
+                continue;
+            };
+            if let (Some(id), Some(rank)) = (
+                card.get("id").and_then(Value::as_str),
+                card.get(POSITION_KEY).and_then(Value::as_u64),
+            ) {
+                ranks.insert(id.to_string(), rank);
+            }
+        }
+        Ok(ranks)
+    }
+
+    /// Writes the activity of each card to its own file.
+    fn write_activity(&self, board: &Board, base_path: &Path) -> Result<(), StorageError> {
+        let mut files: BTreeMap<String, String> = BTreeMap::new();
+        for activity in &board.activity {
+            let file = files
+                .entry(card_file_name(&activity.card_id, "jsonl")?)
+                .or_default();
+            file.push_str(&serde_json::to_string(activity)?);
+            file.push('\n');
+        }
+
+        sync_directory(&Self::activity_dir(base_path), "jsonl", &files)
+    }
+
+    /// Writes the fields that change on every save to the untracked state file.
+    fn write_state(&self, board: &Board, base_path: &Path) -> Result<(), StorageError> {
+        let state = serde_json::json!({
+            "revision": board.revision,
+            "updated_at": board.updated_at,
+        });
+        write_if_changed(&Self::state_path(base_path), &to_pretty_json(&state)?)
+    }
+}
+
+impl Default for DirectoryBoardRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BoardRepository for DirectoryBoardRepository {
+    fn load(&self, base_path: &Path) -> Result<Board, StorageError> {
+        let (mut document, _, positions) = self.read_document(base_path)?;
+        migration::upgrade(&mut document)?;
+        Self::into_board(document, &positions)
+    }
+
+    fn save(&self, board: &Board, base_path: &Path) -> Result<(), StorageError> {
+        fs::create_dir_all(BoardStorage::clicky_dir(base_path))?;
+        BoardStorage::write_gitignore(base_path)?;
+
+        // Unreadable settings carry no version information and are replaced.
+        let meta_path = BoardStorage::meta_path(base_path);
+        if let Ok(document) = fs::read_to_string(&meta_path)
+            .map_err(StorageError::from)
+            .and_then(|content| Ok(serde_json::from_str::<Value>(&content)?))
+        {
+            let version = migration::schema_version(&document)?;
+            if version > CURRENT_SCHEMA_VERSION {
+                return Err(StorageError::UnsupportedSchemaVersion {
+                    found: version,
+                    supported: CURRENT_SCHEMA_VERSION,
+                });
+            }
+        }
+
+        self.write_cards(board, base_path)?;
+        self.write_activity(board, base_path)?;
+        self.write_state(board, base_path)?;
+        // Written last: the board only exists once its settings do.
+        self.write_meta(board, base_path)
+    }
+
+    fn stored_revision(&self, base_path: &Path) -> Result<u64, StorageError> {
+        let meta_path = BoardStorage::meta_path(base_path);
+        if !meta_path.exists() {
+            return Err(StorageError::BoardNotFound(meta_path));
+        }
+
+        // Fresh clones have no state yet and are at revision 0, as on load.
+        match fs::read_to_string(Self::state_path(base_path)) {
+            Ok(content) => Ok(serde_json::from_str::<StoredHeader>(&content)?.revision),
+            Err(_) => Ok(0),
+        }
+    }
+
+    fn exists(&self, base_path: &Path) -> bool {
+        BoardStorage::meta_path(base_path).exists()
+    }
+
+    fn delete(&self, base_path: &Path) -> Result<(), StorageError> {
+        let meta_path = BoardStorage::meta_path(base_path);
+        if !meta_path.exists() {
+            return Err(StorageError::BoardNotFound(meta_path));
+        }
+
+        fs::remove_file(meta_path)?;
+        for dir in [Self::cards_dir(base_path), Self::activity_dir(base_path)] {
+            if dir.exists() {
+                fs::remove_dir_all(dir)?;
+            }
+        }
+        let state_path = Self::state_path(base_path);
+        if state_path.exists() {
+            fs::remove_file(state_path)?;
+        }
+        Ok(())
+    }
+
+    fn migrate(&self, base_path: &Path, dry_run: bool) -> Result<MigrationReport, StorageError> {
+        let (mut document, from_version, positions) = self.read_document(base_path)?;
+        let steps: Vec<String> = migration::upgrade(&mut document)?
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+
+        let report = MigrationReport {
+            from_version,
+            to_version: CURRENT_SCHEMA_VERSION,
+            steps,
+            // The files are small and meant to be kept in version control,
+            // which already holds the previous version.
+            backup: None,
+            dry_run,
+        };
+        if !dry_run && !report.steps.is_empty() {
+            self.save(&Self::into_board(document, &positions)?, base_path)?;
+        }
+        Ok(report)
+    }
+
+    fn lock(&self, base_path: &Path, timeout: Duration) -> Result<Option<BoardLock>, StorageError> {
+        BoardLock::acquire(&BoardStorage::lock_path(base_path), timeout).map(Some)
+    }
+}
+
+/// Assigns ranks to the cards of a column, in column order, given the
+/// ranks they are stored with.
+///
+/// The longest run of stored ranks that is already in order is kept. The
+/// other cards get ranks spread between their kept neighbours; only when
+/// there is no room left is the whole column renumbered.
+fn assign_ranks(stored: &[Option<u64>]) -> Vec<u64> {
+    let kept = longest_increasing(stored);
+    let mut ranks: Vec<Option<u64>> = (0..stored.len())
+        .map(|i| kept.contains(&i).then(|| stored[i]).flatten())
+        .collect();
+
+    let mut start = 0;
+    while start < ranks.len() {
+        if ranks[start].is_some() {
+            start += 1;
+            continue;
+        }
+        let end = (start..ranks.len())
+            .find(|&i| ranks[i].is_some())
+            .unwrap_or(ranks.len());
+        let low = start.checked_sub(1).and_then(|i| ranks[i]).unwrap_or(0);
+        let count = (end - start) as u64;
+        match ranks.get(end).copied().flatten() {
+            None => {
+                for (offset, rank) in ranks[start..end].iter_mut().enumerate() {
+                    *rank = Some(low + RANK_STEP * (offset as u64 + 1));
+                }
+            }
+            Some(high) if high - low > count => {
+                let gap = high - low;
+                for (offset, rank) in ranks[start..end].iter_mut().enumerate() {
+                    *rank = Some(low + gap * (offset as u64 + 1) / (count + 1));
+                }
+            }
+            Some(_) => {
+                return (1..=stored.len() as u64).map(|i| i * RANK_STEP).collect();
+            }
+        }
+        start = end;
+    }
+    ranks
+        .into_iter()
+        .map(|rank| rank.unwrap_or_default())
+        .collect()
+}
+
+/// Gets the indexes of a longest strictly increasing run of the stored
+/// ranks, skipping cards without one.
+fn longest_increasing(stored: &[Option<u64>]) -> BTreeSet<usize> {
+    // tails[k]: index of the smallest last rank of a run of length k + 1
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous: Vec<Option<usize>> = vec![None; stored.len()];
+    for (index, rank) in stored.iter().enumerate() {
+        let Some(rank) = *rank else {
+            continue;
+        };
+        let length = tails.partition_point(|&i| stored[i].is_some_and(|r| r < rank));
+        previous[index] = length.checked_sub(1).map(|k| tails[k]);
+        if length == tails.len() {
+            tails.push(index);
+        } else {
+            tails[length] = index;
+        }
+    }
+
+    let mut kept = BTreeSet::new();
+    let mut next = tails.last().copied();
+    while let Some(index) = next {
+        kept.insert(index);
+        next = previous[index];
+    }
+    kept
+}
+
+/// Gets the JSON object of a document read from `path`.
+fn as_object<'a>(
+    document: &'a mut Value,
+    path: &Path,
+) -> Result<&'a mut Map<String, Value>, StorageError> {
+    document.as_object_mut().ok_or_else(|| {
+        StorageError::InvalidData(format!("{} is not a JSON object", path.display()))
+    })
+}
+
+/// Serializes a document the way it is stored: pretty, with a final newline.
+fn to_pretty_json(document: &Value) -> Result<String, StorageError> {
+    Ok(serde_json::to_string_pretty(document)? + "\n")
+}
+
+/// Gets the name of the file holding data of a card.
+///
+/// # Errors
+/// Returns `StorageError::InvalidData` if the card ID cannot be used as a
+/// file name.
+fn card_file_name(card_id: &str, extension: &str) -> Result<String, StorageError> {
+    let usable =
+        !card_id.is_empty() && !card_id.starts_with('.') && !card_id.contains(['/', '\\', ':']);
+    if !usable {
+        return Err(StorageError::InvalidData(format!(
+            "Card ID '{}' cannot be stored as a file",
+            card_id
+        )));
+    }
+    Ok(format!("{}.{}", card_id, extension))
+}
+
+/// Lists the files with an extension in a directory, sorted by name.
+///
+/// A missing directory has no files.
+fn files_with_extension(dir: &Path, extension: &str) -> Result<Vec<PathBuf>, StorageError> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|e| e == extension) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Makes the files with an extension in a directory match `files`.
+///
+/// Changed files are rewritten and files not in `files` are removed.
+fn sync_directory(
+    dir: &Path,
+    extension: &str,
+    files: &BTreeMap<String, String>,
+) -> Result<(), StorageError> {
+    fs::create_dir_all(dir)?;
+
+    let mut stale: BTreeSet<PathBuf> = files_with_extension(dir, extension)?.into_iter().collect();
+    for (name, content) in files {
+        let path = dir.join(name);
+        stale.remove(&path);
+        write_if_changed(&path, content)?;
+    }
+    for path in stale {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Writes a file unless it already has the given content.
+fn write_if_changed(path: &Path, content: &str) -> Result<(), StorageError> {
+    if fs::read(path).is_ok_and(|current| current == content.as_bytes()) {
+        return Ok(());
+    }
+    write_atomic(path, content.as_bytes())
+}
+
+#[cfg(test)]
+#[path = "./directory_tests.rs"]
+mod directory_tests;
@@ -0,0 +1,293 @@
+use super::*;
+use crate::domain::{Activity, ActivityKind, Priority};
+use tempfile::TempDir;
+
+fn sample_board() -> Board {
+    let mut board = Board::new("test".to_string(), "Test Board".to_string());
+    let first = board.create_card("First".to_string(), None, None, None);
+    let second = board.create_card("Second".to_string(), None, None, None);
+    board.create_card("Third".to_string(), None, None, None);
+    board.move_card(&first, "in_progress");
+    board.move_card(&second, "in_progress");
+
+    let card = board.get_card_mut(&second).unwrap();
+    card.priority = Some(Priority::High);
+    card.add_label("bug".to_string());
+    board.record_activity(Activity::new(
+        first.clone(),
+        "alice".to_string(),
+        ActivityKind::Commented,
+    ));
+    board.record_activity(Activity::new(
+        second,
+        "bob".to_string(),
+        ActivityKind::Commented,
+    ));
+    board.record_activity(Activity::new(
+        first,
+        "alice".to_string(),
+        ActivityKind::Commented,
+    ));
+    board.revision = 7;
+    board
+}
+
+fn file_names(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_directory_repository_save_and_load() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = DirectoryBoardRepository::new();
+    let board = sample_board();
+
+    // Act
+    repo.save(&board, temp_dir.path()).unwrap();
+    let loaded = repo.load(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(loaded, board);
+    let clicky_dir = temp_dir.path().join(".clicky");
+    assert_eq!(
+        file_names(&clicky_dir.join("cards")),
+        vec!["TES-001.json", "TES-002.json", "TES-003.json"]
+    );
+    assert_eq!(
+        file_names(&clicky_dir.join("activity")),
+        vec!["TES-001.jsonl", "TES-002.jsonl"]
+    );
+    assert!(!BoardStorage::board_path(temp_dir.path()).exists());
+}
+
+#[test]
+fn test_directory_repository_stored_revision() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = DirectoryBoardRepository::new();
+    repo.save(&sample_board(), temp_dir.path()).unwrap();
+
+    // Act
+    let revision = repo.stored_revision(temp_dir.path()).unwrap();
+    fs::remove_file(BoardStorage::clicky_dir(temp_dir.path()).join(BoardStorage::STATE_FILE))
+        .unwrap();
+    let fresh_clone = repo.stored_revision(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(revision, 7);
+    assert_eq!(fresh_clone, 0);
+}
+
+#[test]
+fn test_directory_repository_keeps_volatile_fields_out_of_meta() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = DirectoryBoardRepository::new();
+    let board = sample_board();
+
+    // Act
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Assert
+    let meta = fs::read_to_string(BoardStorage::meta_path(temp_dir.path())).unwrap();
+    assert!(!meta.contains("revision"));
+    assert!(!meta.contains("updated_at"));
+    assert!(!meta.contains("TES-001"));
+    let gitignore = fs::read_to_string(temp_dir.path().join(".clicky").join(".gitignore")).unwrap();
+    assert!(gitignore.lines().any(|line| line == "state.json"));
+}
+
+#[test]
+fn test_directory_repository_only_rewrites_changed_cards() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = DirectoryBoardRepository::new();
+    let mut board = sample_board();
+    repo.save(&board, temp_dir.path()).unwrap();
+    let cards_dir = temp_dir.path().join(".clicky").join("cards");
+    fs::write(cards_dir.join("notes.txt"), "not a card").unwrap();
+    let untouched = cards_dir.join("TES-003.json");
+    let modified = fs::metadata(&untouched).unwrap().modified().unwrap();
+
+    // Act
+    board.get_card_mut("TES-001").unwrap().title = "Renamed".to_string();
+    board.delete_card("TES-002");
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(
+        file_names(&cards_dir),
+        vec!["TES-001.json", "TES-003.json", "notes.txt"]
+    );
+    assert_eq!(
+        fs::metadata(&untouched).unwrap().modified().unwrap(),
+        modified
+    );
+    assert_eq!(repo.load(temp_dir.path()).unwrap(), board);
+}
+
+#[test]
+fn test_directory_repository_ranking_a_card_only_rewrites_its_file() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = DirectoryBoardRepository::new();
+    let mut board = Board::new("test".to_string(), "Test Board".to_string());
+    let ids: Vec<String> = (0..4)
+        .map(|i| board.create_card(format!("Card {}", i), None, None, None))
+        .collect();
+    repo.save(&board, temp_dir.path()).unwrap();
+    let cards_dir = temp_dir.path().join(".clicky").join("cards");
+    let read_all = || -> Vec<String> {
+        ids.iter()
+            .map(|id| fs::read_to_string(cards_dir.join(format!("{}.json", id))).unwrap())
+            .collect()
+    };
+    let before = read_all();
+
+    // Act
+    board.rank_card(&ids[3], &crate::domain::CardPosition::Top);
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Assert
+    let after = read_all();
+    assert_eq!(after[..3], before[..3]);
+    assert_ne!(after[3], before[3]);
+    assert_eq!(repo.load(temp_dir.path()).unwrap(), board);
+}
+
+#[test]
+fn test_assign_ranks_keeps_ordered_ranks() {
+    // Arrange
+    let step = RANK_STEP;
+
+    // Act & Assert: new cards go after the last rank or between neighbours
+    assert_eq!(assign_ranks(&[None, None]), vec![step, 2 * step]);
+    assert_eq!(assign_ranks(&[Some(10), None, Some(20)]), vec![10, 15, 20]);
+    assert_eq!(
+        assign_ranks(&[Some(30), Some(10), Some(20)]),
+        vec![5, 10, 20]
+    );
+    // Dense positions from older versions are kept while they fit
+    assert_eq!(assign_ranks(&[Some(0), Some(1), Some(2)]), vec![0, 1, 2]);
+    // No room between neighbours renumbers the column
+    assert_eq!(
+        assign_ranks(&[Some(1), None, Some(2)]),
+        vec![step, 2 * step, 3 * step]
+    );
+}
+
+#[test]
+fn test_directory_repository_merges_cards_added_on_branches() {
+    // Arrange: two branches each added a card at the end of "todo"
+    let temp_dir = TempDir::new().unwrap();
+    let repo = DirectoryBoardRepository::new();
+    let mut board = Board::new("test".to_string(), "Test Board".to_string());
+    board.card_id_scheme = crate::domain::CardIdScheme::Random;
+    let first = board.create_card("Ours".to_string(), None, None, None);
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    let mut theirs = Board::new("test".to_string(), "Test Board".to_string());
+    theirs.card_id_scheme = crate::domain::CardIdScheme::Random;
+    let second = theirs.create_card("Theirs".to_string(), None, None, None);
+    let other = TempDir::new().unwrap();
+    repo.save(&theirs, other.path()).unwrap();
+    let file_name = format!("{}.json", second);
+    fs::copy(
+        other.path().join(".clicky").join("cards").join(&file_name),
+        temp_dir
+            .path()
+            .join(".clicky")
+            .join("cards")
+            .join(&file_name),
+    )
+    .unwrap();
+
+    // Act
+    let merged = repo.load(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_ne!(first, second);
+    assert_eq!(merged.columns[0].cards, vec![first, second]);
+}
+
+#[test]
+fn test_directory_repository_without_state_starts_at_revision_zero() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = DirectoryBoardRepository::new();
+    let board = sample_board();
+    repo.save(&board, temp_dir.path()).unwrap();
+    fs::remove_file(temp_dir.path().join(".clicky").join("state.json")).unwrap();
+
+    // Act
+    let loaded = repo.load(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(loaded.revision, 0);
+    assert_eq!(loaded.updated_at, board.created_at);
+    assert_eq!(loaded.cards, board.cards);
+}
+
+#[test]
+fn test_directory_repository_rejects_unsafe_card_ids() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = DirectoryBoardRepository::new();
+    let mut board = sample_board();
+    board.get_card_mut("TES-001").unwrap().id = "../escape".to_string();
+
+    // Act
+    let result = repo.save(&board, temp_dir.path());
+
+    // Assert
+    assert!(matches!(result, Err(StorageError::InvalidData(_))));
+}
+
+#[test]
+fn test_directory_repository_delete() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = DirectoryBoardRepository::new();
+    repo.save(&sample_board(), temp_dir.path()).unwrap();
+
+    // Act
+    repo.delete(temp_dir.path()).unwrap();
+
+    // Assert
+    assert!(!repo.exists(temp_dir.path()));
+    assert_eq!(
+        file_names(&temp_dir.path().join(".clicky")),
+        vec![".gitignore"]
+    );
+}
+
+#[test]
+fn test_directory_repository_migrate_older_board() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = DirectoryBoardRepository::new();
+    let board = sample_board();
+    repo.save(&board, temp_dir.path()).unwrap();
+    let meta_path = BoardStorage::meta_path(temp_dir.path());
+    let mut meta: Value = serde_json::from_str(&fs::read_to_string(&meta_path).unwrap()).unwrap();
+    meta["schema_version"] = 2.into();
+    meta.as_object_mut().unwrap().remove("wip_policy");
+    fs::write(&meta_path, meta.to_string()).unwrap();
+
+    // Act
+    let report = repo.migrate(temp_dir.path(), false).unwrap();
+
+    // Assert
+    assert_eq!(report.from_version, 2);
+    assert_eq!(report.steps.len(), (CURRENT_SCHEMA_VERSION - 2) as usize);
+    let meta: Value = serde_json::from_str(&fs::read_to_string(&meta_path).unwrap()).unwrap();
+    assert_eq!(meta["schema_version"], CURRENT_SCHEMA_VERSION);
+    assert_eq!(meta["wip_policy"], "warn");
+    assert_eq!(repo.load(temp_dir.path()).unwrap(), board);
+}
@@ -0,0 +1,98 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Serialize;
+use thiserror::Error;
+
+use super::storage::BoardStorage;
+
+/// Name of the merge driver in the git config.
+pub const MERGE_DRIVER_NAME: &str = "clicky";
+
+/// Command git runs to merge the board file.
+///
+/// `%O`, `%A` and `%B` are replaced by git with the base, our and their
+/// version of the file.
+pub const MERGE_DRIVER_COMMAND: &str = "clicky merge-driver %O %A %B";
+
+/// Errors that can occur while configuring git.
+#[derive(Error, Debug)]
+pub enum GitError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("git {command} failed: {message}")]
+    CommandFailed { command: String, message: String },
+}
+
+impl GitError {
+    /// Returns a stable, machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            GitError::Io(_) => "io_error",
+            GitError::CommandFailed { .. } => "git_error",
+        }
+    }
+}
+
+/// Result of installing the merge driver.
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeDriverInstallation {
+    /// The `.gitattributes` file assigning the driver to the board file
+    pub attributes: PathBuf,
+    /// Name of the driver in the git config
+    pub driver: String,
+    /// Command git runs to merge the board file
+    pub command: String,
+}
+
+/// Registers `clicky merge-driver` for the board file of the repository
+/// containing `base_path`.
+///
+/// Adds `board.json merge=clicky` to `.clicky/.gitattributes`, which is
+/// committed with the board, and the driver itself to the repository's
+/// local git config, which every clone has to install on its own.
+pub fn install_merge_driver(base_path: &Path) -> Result<MergeDriverInstallation, GitError> {
+    git_config(base_path, "name", "clicky board merge")?;
+    git_config(base_path, "driver", MERGE_DRIVER_COMMAND)?;
+
+    let attributes = BoardStorage::clicky_dir(base_path).join(".gitattributes");
+    let entry = format!("{} merge={}", BoardStorage::BOARD_FILE, MERGE_DRIVER_NAME);
+    let mut content = fs::read_to_string(&attributes).unwrap_or_default();
+    if !content.lines().any(|line| line.trim() == entry) {
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push_str(&entry);
+        content.push('\n');
+        fs::create_dir_all(BoardStorage::clicky_dir(base_path))?;
+        fs::write(&attributes, content)?;
+    }
+
+    Ok(MergeDriverInstallation {
+        attributes,
+        driver: MERGE_DRIVER_NAME.to_string(),
+        command: MERGE_DRIVER_COMMAND.to_string(),
+    })
+}
+
+/// Sets `merge.clicky.<key>` in the local git config.
+fn git_config(base_path: &Path, key: &str, value: &str) -> Result<(), GitError> {
+    let name = format!("merge.{}.{}", MERGE_DRIVER_NAME, key);
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(base_path)
+        .args(["config", "--local", &name, value])
+        .output()?;
+    if !output.status.success() {
+        return Err(GitError::CommandFailed {
+            command: format!("config {}", name),
+            message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "./git_tests.rs"]
+mod git_tests;
@@ -0,0 +1,62 @@
+use super::*;
+use tempfile::TempDir;
+
+fn git_repository() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let status = Command::new("git")
+        .arg("init")
+        .arg("--quiet")
+        .arg(temp_dir.path())
+        .status()
+        .unwrap();
+    assert!(status.success());
+    temp_dir
+}
+
+fn git_config_value(base_path: &Path, name: &str) -> String {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(base_path)
+        .args(["config", "--local", name])
+        .output()
+        .unwrap();
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+#[test]
+fn test_install_merge_driver() {
+    // Arrange
+    let temp_dir = git_repository();
+
+    // Act
+    let installation = install_merge_driver(temp_dir.path()).unwrap();
+    install_merge_driver(temp_dir.path()).unwrap();
+
+    // Assert
+    let attributes = fs::read_to_string(&installation.attributes).unwrap();
+    assert_eq!(attributes, "board.json merge=clicky\n");
+    assert_eq!(
+        git_config_value(temp_dir.path(), "merge.clicky.driver"),
+        MERGE_DRIVER_COMMAND
+    );
+    assert_eq!(
+        git_config_value(temp_dir.path(), "merge.clicky.name"),
+        "clicky board merge"
+    );
+}
+
+#[test]
+fn test_install_merge_driver_keeps_existing_attributes() {
+    // Arrange
+    let temp_dir = git_repository();
+    let clicky_dir = BoardStorage::clicky_dir(temp_dir.path());
+    fs::create_dir_all(&clicky_dir).unwrap();
+    fs::write(clicky_dir.join(".gitattributes"), "*.json text").unwrap();
+
+    // Act
+    let installation = install_merge_driver(temp_dir.path()).unwrap();
+
+    // Assert
+    let attributes = fs::read_to_string(installation.attributes).unwrap();
+    assert_eq!(attributes, "*.json text\nboard.json merge=clicky\n");
+}
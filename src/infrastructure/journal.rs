@@ -0,0 +1,21 @@
+use std::fs;
+use std::path::Path;
+
+use super::storage::{write_atomic, BoardStorage, StorageError};
+use crate::domain::Journal;
+
+/// Loads the journal of the board in `base_path`, empty if none was saved.
+pub fn load(base_path: &Path) -> Result<Journal, StorageError> {
+    let path = BoardStorage::journal_path(base_path);
+    if !path.exists() {
+        return Ok(Journal::default());
+    }
+    let content = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Saves the journal of the board in `base_path`.
+pub fn save(journal: &Journal, base_path: &Path) -> Result<(), StorageError> {
+    let content = serde_json::to_string_pretty(journal)?;
+    write_atomic(&BoardStorage::journal_path(base_path), content.as_bytes())
+}
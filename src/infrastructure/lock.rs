@@ -0,0 +1,82 @@
+use std::env;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::storage::StorageError;
+
+/// Environment variable holding the lock timeout in seconds.
+pub const LOCK_TIMEOUT_ENV: &str = "CLICKY_LOCK_TIMEOUT";
+
+/// How long to wait for the board lock when no timeout is configured.
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Delay between attempts to acquire a held lock.
+const RETRY_INTERVAL: Duration = Duration::from_millis(25);
+
+/// An exclusive advisory lock on a board.
+///
+/// The lock is held until the value is dropped. Other clicky processes
+/// wait for it before starting their own load-modify-save cycle.
+#[derive(Debug)]
+pub struct BoardLock {
+    _file: File,
+}
+
+impl BoardLock {
+    /// Acquires the lock file at `path`, waiting up to `timeout`.
+    ///
+    /// # Errors
+    /// Returns `StorageError::LockTimeout` if another process still holds
+    /// the lock when the timeout expires.
+    pub fn acquire(path: &Path, timeout: Duration) -> Result<Self, StorageError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path)?;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            match file.try_lock() {
+                Ok(()) => return Ok(Self { _file: file }),
+                Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                    thread::sleep(RETRY_INTERVAL);
+                }
+                Err(TryLockError::WouldBlock) => {
+                    return Err(StorageError::LockTimeout {
+                        path: path.to_path_buf(),
+                        timeout,
+                    });
+                }
+                Err(TryLockError::Error(e)) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+/// Reads the lock timeout from `CLICKY_LOCK_TIMEOUT`.
+///
+/// The value is a number of seconds and may be fractional; `0` fails
+/// immediately when the board is locked. Missing or invalid values fall
+/// back to [`DEFAULT_LOCK_TIMEOUT`].
+pub fn lock_timeout_from_env() -> Duration {
+    env::var(LOCK_TIMEOUT_ENV)
+        .ok()
+        .and_then(|value| parse_lock_timeout(&value))
+        .unwrap_or(DEFAULT_LOCK_TIMEOUT)
+}
+
+/// Parses a lock timeout given in seconds.
+fn parse_lock_timeout(value: &str) -> Option<Duration> {
+    let seconds: f64 = value.trim().parse().ok()?;
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+#[cfg(test)]
+#[path = "./lock_tests.rs"]
+mod lock_tests;
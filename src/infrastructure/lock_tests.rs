@@ -0,0 +1,63 @@
+use super::*;
+use tempfile::TempDir;
+
+#[test]
+fn test_acquire_lock() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let lock_path = temp_dir.path().join(".clicky").join("board.lock");
+
+    // Act
+    let lock = BoardLock::acquire(&lock_path, Duration::ZERO);
+
+    // Assert
+    assert!(lock.is_ok());
+    assert!(lock_path.exists());
+}
+
+#[test]
+fn test_acquire_held_lock_times_out() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let lock_path = temp_dir.path().join("board.lock");
+    let _held = BoardLock::acquire(&lock_path, Duration::ZERO).unwrap();
+
+    // Act
+    let result = BoardLock::acquire(&lock_path, Duration::from_millis(50));
+
+    // Assert
+    match result {
+        Err(StorageError::LockTimeout { path, timeout }) => {
+            assert_eq!(path, lock_path);
+            assert_eq!(timeout, Duration::from_millis(50));
+        }
+        other => panic!("Expected a lock timeout, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_lock_released_on_drop() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let lock_path = temp_dir.path().join("board.lock");
+    let held = BoardLock::acquire(&lock_path, Duration::ZERO).unwrap();
+
+    // Act
+    drop(held);
+    let result = BoardLock::acquire(&lock_path, Duration::ZERO);
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_parse_lock_timeout() {
+    assert_eq!(parse_lock_timeout("5"), Some(Duration::from_secs(5)));
+    assert_eq!(
+        parse_lock_timeout(" 0.5 "),
+        Some(Duration::from_millis(500))
+    );
+    assert_eq!(parse_lock_timeout("0"), Some(Duration::ZERO));
+    assert_eq!(parse_lock_timeout("-1"), None);
+    assert_eq!(parse_lock_timeout("soon"), None);
+}
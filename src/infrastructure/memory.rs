@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use super::lock::BoardLock;
+use super::migration::CURRENT_SCHEMA_VERSION;
+use super::storage::{BoardRepository, MigrationReport, StorageError};
+use crate::domain::{Board, Journal};
+
+/// In-memory implementation of BoardRepository.
+///
+/// Keeps boards in a map keyed by project directory, without touching the
+/// filesystem. Clones share the same boards, so several services can work
+/// on one board. Intended for tests and for embedding clicky.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryBoardRepository {
+    boards: Arc<Mutex<HashMap<PathBuf, Board>>>,
+    journals: Arc<Mutex<HashMap<PathBuf, Journal>>>,
+}
+
+#[allow(dead_code)]
+impl InMemoryBoardRepository {
+    /// Creates a new, empty in-memory repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn boards(&self) -> MutexGuard<'_, HashMap<PathBuf, Board>> {
+        // A panic while holding the lock cannot leave a board half-written,
+        // so the data is still consistent.
+        self.boards
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn journals(&self) -> MutexGuard<'_, HashMap<PathBuf, Journal>> {
+        self.journals
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl BoardRepository for InMemoryBoardRepository {
+    fn load(&self, base_path: &Path) -> Result<Board, StorageError> {
+        self.boards()
+            .get(base_path)
+            .cloned()
+            .ok_or_else(|| StorageError::BoardNotFound(base_path.to_path_buf()))
+    }
+
+    fn save(&self, board: &Board, base_path: &Path) -> Result<(), StorageError> {
+        self.boards().insert(base_path.to_path_buf(), board.clone());
+        Ok(())
+    }
+
+    fn stored_revision(&self, base_path: &Path) -> Result<u64, StorageError> {
+        self.boards()
+            .get(base_path)
+            .map(|board| board.revision)
+            .ok_or_else(|| StorageError::BoardNotFound(base_path.to_path_buf()))
+    }
+
+    fn exists(&self, base_path: &Path) -> bool {
+        self.boards().contains_key(base_path)
+    }
+
+    fn delete(&self, base_path: &Path) -> Result<(), StorageError> {
+        self.journals().remove(base_path);
+        self.boards()
+            .remove(base_path)
+            .map(|_| ())
+            .ok_or_else(|| StorageError::BoardNotFound(base_path.to_path_buf()))
+    }
+
+    fn migrate(&self, base_path: &Path, dry_run: bool) -> Result<MigrationReport, StorageError> {
+        if !self.exists(base_path) {
+            return Err(StorageError::BoardNotFound(base_path.to_path_buf()));
+        }
+
+        Ok(MigrationReport {
+            from_version: CURRENT_SCHEMA_VERSION,
+            to_version: CURRENT_SCHEMA_VERSION,
+            steps: Vec::new(),
+            backup: None,
+            dry_run,
+        })
+    }
+
+    fn lock(
+        &self,
+        _base_path: &Path,
+        _timeout: Duration,
+    ) -> Result<Option<BoardLock>, StorageError> {
+        Ok(None)
+    }
+
+    fn load_journal(&self, base_path: &Path) -> Result<Journal, StorageError> {
+        Ok(self.journals().get(base_path).cloned().unwrap_or_default())
+    }
+
+    fn save_journal(&self, journal: &Journal, base_path: &Path) -> Result<(), StorageError> {
+        self.journals()
+            .insert(base_path.to_path_buf(), journal.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "./memory_tests.rs"]
+mod memory_tests;
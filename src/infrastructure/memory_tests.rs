@@ -0,0 +1,63 @@
+use super::*;
+
+#[test]
+fn test_memory_repository_save_and_load() {
+    // Arrange
+    let repo = InMemoryBoardRepository::new();
+    let base_path = Path::new("/boards/one");
+    let board = Board::new("one".to_string(), "One".to_string());
+
+    // Act
+    repo.save(&board, base_path).unwrap();
+    let loaded = repo.load(base_path).unwrap();
+
+    // Assert
+    assert_eq!(loaded.id, "one");
+    assert!(repo.exists(base_path));
+    assert!(!repo.exists(Path::new("/boards/two")));
+    assert!(!base_path.exists());
+}
+
+#[test]
+fn test_memory_repository_clones_share_boards() {
+    // Arrange
+    let repo = InMemoryBoardRepository::new();
+    let clone = repo.clone();
+    let base_path = Path::new("/boards/one");
+
+    // Act
+    clone
+        .save(&Board::new("one".to_string(), "One".to_string()), base_path)
+        .unwrap();
+
+    // Assert
+    assert!(repo.exists(base_path));
+}
+
+#[test]
+fn test_memory_repository_delete() {
+    // Arrange
+    let repo = InMemoryBoardRepository::new();
+    let base_path = Path::new("/boards/one");
+    repo.save(&Board::new("one".to_string(), "One".to_string()), base_path)
+        .unwrap();
+
+    // Act
+    repo.delete(base_path).unwrap();
+
+    // Assert
+    assert!(matches!(
+        repo.load(base_path),
+        Err(StorageError::BoardNotFound(_))
+    ));
+    assert!(repo.delete(base_path).is_err());
+}
+
+#[test]
+fn test_memory_repository_needs_no_lock() {
+    let repo = InMemoryBoardRepository::new();
+
+    let lock = repo.lock(Path::new("/boards/one"), Duration::ZERO).unwrap();
+
+    assert!(lock.is_none());
+}
@@ -0,0 +1,203 @@
+use serde_json::{Map, Value};
+
+use super::storage::StorageError;
+
+/// Schema version written by this build of clicky.
+pub const CURRENT_SCHEMA_VERSION: u32 = 9;
+
+/// Schema version of board files written before versioning was introduced.
+pub(super) const UNVERSIONED: u32 = 1;
+
+/// A single upgrade step from one schema version to the next.
+struct Migration {
+    /// Version the step upgrades from; it produces `from + 1`
+    from: u32,
+    /// Human-readable summary of the step
+    description: &'static str,
+    /// Rewrites the document in place
+    apply: fn(&mut Map<String, Value>),
+}
+
+/// All migrations, ordered by the version they upgrade from.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from: 1,
+        description:
+            "Add label registry, activity log and revision to the board; labels and comments to cards",
+        apply: add_collaboration_fields,
+    },
+    Migration {
+        from: 2,
+        description: "Add card ID scheme to the board",
+        apply: add_card_id_scheme,
+    },
+    Migration {
+        from: 3,
+        description: "Add archive time to cards",
+        apply: add_archived_at,
+    },
+    Migration {
+        from: 4,
+        description: "Add WIP policy to the board and WIP limits to columns",
+        apply: add_wip_limits,
+    },
+    Migration {
+        from: 5,
+        description: "Add blocking links to cards",
+        apply: add_blocked_by,
+    },
+    Migration {
+        from: 6,
+        description: "Add claims to cards",
+        apply: add_claim,
+    },
+    Migration {
+        from: 7,
+        description: "Add checklists to cards",
+        apply: add_checklist,
+    },
+    Migration {
+        from: 8,
+        description: "Add parents to cards",
+        apply: add_parent,
+    },
+];
+
+/// Reads the schema version of a stored board document.
+///
+/// Documents without a `schema_version` predate versioning and are version 1.
+pub fn schema_version(document: &Value) -> Result<u32, StorageError> {
+    match document.get("schema_version") {
+        None => Ok(UNVERSIONED),
+        Some(value) => value
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| StorageError::InvalidData(format!("Invalid schema_version: {}", value))),
+    }
+}
+
+/// Upgrades a stored board document to the current schema version.
+///
+/// Applies each migration in turn and returns the descriptions of the
+/// applied steps, which is empty if the document was already current.
+///
+/// # Errors
+/// Returns `StorageError::UnsupportedSchemaVersion` if the document was
+/// written by a newer version of clicky.
+pub fn upgrade(document: &mut Value) -> Result<Vec<&'static str>, StorageError> {
+    let mut version = schema_version(document)?;
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(StorageError::UnsupportedSchemaVersion {
+            found: version,
+            supported: CURRENT_SCHEMA_VERSION,
+        });
+    }
+
+    let mut applied = Vec::new();
+    while version < CURRENT_SCHEMA_VERSION {
+        let migration = MIGRATIONS
+            .iter()
+            .find(|m| m.from == version)
+            .ok_or_else(|| {
+                StorageError::InvalidData(format!("No migration from schema version {}", version))
+            })?;
+        let object = document.as_object_mut().ok_or_else(|| {
+            StorageError::InvalidData("Board document is not a JSON object".to_string())
+        })?;
+
+        (migration.apply)(object);
+        version += 1;
+        object.insert("schema_version".to_string(), version.into());
+        applied.push(migration.description);
+    }
+
+    Ok(applied)
+}
+
+/// Version 1 → 2: spells out the fields added for labels, activity history
+/// and revisions instead of relying on their defaults.
+fn add_collaboration_fields(board: &mut Map<String, Value>) {
+    for field in ["labels", "activity"] {
+        board
+            .entry(field)
+            .or_insert_with(|| Value::Array(Vec::new()));
+    }
+    board.entry("revision").or_insert_with(|| 0.into());
+
+    for card in objects_in(board, "cards") {
+        for field in ["labels", "comments"] {
+            card.entry(field)
+                .or_insert_with(|| Value::Array(Vec::new()));
+        }
+    }
+}
+
+/// Version 2 → 3: boards written before random card IDs number their cards.
+fn add_card_id_scheme(board: &mut Map<String, Value>) {
+    board
+        .entry("card_id_scheme")
+        .or_insert_with(|| "sequential".into());
+}
+
+/// Version 3 → 4: no card was archived before archiving existed.
+fn add_archived_at(board: &mut Map<String, Value>) {
+    for card in objects_in(board, "cards") {
+        card.entry("archived_at").or_insert(Value::Null);
+    }
+}
+
+/// Version 4 → 5: columns had no WIP limit, which only warns when set.
+fn add_wip_limits(board: &mut Map<String, Value>) {
+    board.entry("wip_policy").or_insert_with(|| "warn".into());
+    for column in objects_in(board, "columns") {
+        column.entry("wip_limit").or_insert(Value::Null);
+    }
+}
+
+/// Version 5 → 6: cards were not blocked by other cards.
+fn add_blocked_by(board: &mut Map<String, Value>) {
+    for card in objects_in(board, "cards") {
+        card.entry("blocked_by")
+            .or_insert_with(|| Value::Array(Vec::new()));
+    }
+}
+
+/// Version 6 → 7: no card was claimed.
+fn add_claim(board: &mut Map<String, Value>) {
+    for card in objects_in(board, "cards") {
+        card.entry("claim").or_insert(Value::Null);
+    }
+}
+
+/// Version 7 → 8: cards had no checklist.
+fn add_checklist(board: &mut Map<String, Value>) {
+    for card in objects_in(board, "cards") {
+        card.entry("checklist")
+            .or_insert_with(|| Value::Array(Vec::new()));
+    }
+}
+
+/// Version 8 → 9: every card was a top-level card.
+fn add_parent(board: &mut Map<String, Value>) {
+    for card in objects_in(board, "cards") {
+        card.entry("parent").or_insert(Value::Null);
+    }
+}
+
+/// Gets the JSON objects in the array field `field` of the board, such as
+/// its cards or columns.
+fn objects_in<'a>(
+    board: &'a mut Map<String, Value>,
+    field: &str,
+) -> impl Iterator<Item = &'a mut Map<String, Value>> {
+    board
+        .get_mut(field)
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_object_mut)
+}
+
+#[cfg(test)]
+#[path = "./migration_tests.rs"]
+mod migration_tests;
@@ -0,0 +1,250 @@
+use super::*;
+use serde_json::json;
+
+fn legacy_document() -> Value {
+    json!({
+        "id": "legacy",
+        "name": "Legacy",
+        "card_id_prefix": "LEG",
+        "next_card_number": 2,
+        "columns": [
+            { "id": "todo", "name": "To Do", "order": 0, "cards": ["LEG-001"] }
+        ],
+        "cards": [
+            {
+                "id": "LEG-001",
+                "title": "Old card",
+                "description": null,
+                "column_id": "todo",
+                "assignee": null,
+                "created_at": "2024-01-15T10:00:00Z",
+                "updated_at": "2024-01-15T10:00:00Z"
+            }
+        ],
+        "created_at": "2024-01-15T10:00:00Z",
+        "updated_at": "2024-01-15T10:00:00Z"
+    })
+}
+
+#[test]
+fn test_unversioned_document_is_version_one() {
+    assert_eq!(schema_version(&legacy_document()).unwrap(), 1);
+    assert_eq!(schema_version(&json!({ "schema_version": 2 })).unwrap(), 2);
+    assert!(matches!(
+        schema_version(&json!({ "schema_version": "two" })),
+        Err(StorageError::InvalidData(_))
+    ));
+}
+
+#[test]
+fn test_upgrade_legacy_document() {
+    // Arrange
+    let mut document = legacy_document();
+
+    // Act
+    let applied = upgrade(&mut document).unwrap();
+
+    // Assert
+    assert_eq!(applied.len(), MIGRATIONS.len());
+    assert_eq!(document["schema_version"], CURRENT_SCHEMA_VERSION);
+    assert_eq!(document["labels"], json!([]));
+    assert_eq!(document["activity"], json!([]));
+    assert_eq!(document["revision"], 0);
+    assert_eq!(document["cards"][0]["labels"], json!([]));
+    assert_eq!(document["cards"][0]["comments"], json!([]));
+}
+
+/// Upgrades the legacy document as if it had been written at `version`
+/// and returns the applied steps.
+fn upgrade_from(document: &mut Value, version: u32) -> Vec<&'static str> {
+    document["schema_version"] = json!(version);
+    upgrade(document).unwrap()
+}
+
+#[test]
+fn test_upgrade_from_version_2_adds_card_id_scheme() {
+    // Arrange
+    let mut document = legacy_document();
+    let mut random = legacy_document();
+    random["card_id_scheme"] = json!("random");
+
+    // Act
+    let applied = upgrade_from(&mut document, 2);
+    upgrade_from(&mut random, 2);
+
+    // Assert
+    assert_eq!(applied[0], "Add card ID scheme to the board");
+    assert_eq!(document["card_id_scheme"], "sequential");
+    assert_eq!(random["card_id_scheme"], "random");
+}
+
+#[test]
+fn test_upgrade_from_version_3_adds_archived_at() {
+    // Arrange
+    let mut document = legacy_document();
+
+    // Act
+    let applied = upgrade_from(&mut document, 3);
+
+    // Assert
+    assert_eq!(applied[0], "Add archive time to cards");
+    assert!(document["cards"][0]
+        .as_object()
+        .unwrap()
+        .contains_key("archived_at"));
+    assert_eq!(document["cards"][0]["archived_at"], Value::Null);
+}
+
+#[test]
+fn test_upgrade_from_version_4_adds_wip_limits() {
+    // Arrange
+    let mut document = legacy_document();
+
+    // Act
+    let applied = upgrade_from(&mut document, 4);
+
+    // Assert
+    assert_eq!(
+        applied[0],
+        "Add WIP policy to the board and WIP limits to columns"
+    );
+    assert_eq!(document["wip_policy"], "warn");
+    assert!(document["columns"][0]
+        .as_object()
+        .unwrap()
+        .contains_key("wip_limit"));
+}
+
+#[test]
+fn test_upgrade_from_version_5_adds_blocked_by() {
+    // Arrange
+    let mut document = legacy_document();
+
+    // Act
+    let applied = upgrade_from(&mut document, 5);
+
+    // Assert
+    assert_eq!(applied[0], "Add blocking links to cards");
+    assert_eq!(document["cards"][0]["blocked_by"], json!([]));
+}
+
+#[test]
+fn test_upgrade_from_version_6_adds_claim() {
+    // Arrange
+    let mut document = legacy_document();
+
+    // Act
+    let applied = upgrade_from(&mut document, 6);
+
+    // Assert
+    assert_eq!(applied[0], "Add claims to cards");
+    assert!(document["cards"][0]
+        .as_object()
+        .unwrap()
+        .contains_key("claim"));
+}
+
+#[test]
+fn test_upgrade_from_version_7_adds_checklist() {
+    // Arrange
+    let mut document = legacy_document();
+
+    // Act
+    let applied = upgrade_from(&mut document, 7);
+
+    // Assert
+    assert_eq!(applied[0], "Add checklists to cards");
+    assert_eq!(document["cards"][0]["checklist"], json!([]));
+}
+
+#[test]
+fn test_upgrade_from_version_8_adds_parent() {
+    // Arrange
+    let mut document = legacy_document();
+
+    // Act
+    let applied = upgrade_from(&mut document, 8);
+
+    // Assert
+    assert_eq!(applied, vec!["Add parents to cards"]);
+    assert!(document["cards"][0]
+        .as_object()
+        .unwrap()
+        .contains_key("parent"));
+    assert_eq!(document["schema_version"], CURRENT_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_upgraded_legacy_document_is_a_board() {
+    // Arrange
+    let mut document = legacy_document();
+
+    // Act
+    upgrade(&mut document).unwrap();
+    let board: crate::domain::Board = serde_json::from_value(document).unwrap();
+
+    // Assert
+    assert_eq!(
+        board.card_id_scheme,
+        crate::domain::CardIdScheme::Sequential
+    );
+    assert_eq!(board.columns[0].wip_limit, None);
+    assert!(board.cards[0].blocked_by.is_empty());
+    assert_eq!(board.cards[0].parent, None);
+}
+
+#[test]
+fn test_upgrade_keeps_existing_data() {
+    // Arrange
+    let mut document = legacy_document();
+    document["revision"] = json!(7);
+    document["cards"][0]["labels"] = json!(["bug"]);
+
+    // Act
+    upgrade(&mut document).unwrap();
+
+    // Assert
+    assert_eq!(document["revision"], 7);
+    assert_eq!(document["cards"][0]["labels"], json!(["bug"]));
+}
+
+#[test]
+fn test_upgrade_current_document_is_noop() {
+    // Arrange
+    let mut document = json!({ "schema_version": CURRENT_SCHEMA_VERSION, "id": "b" });
+    let original = document.clone();
+
+    // Act
+    let applied = upgrade(&mut document).unwrap();
+
+    // Assert
+    assert!(applied.is_empty());
+    assert_eq!(document, original);
+}
+
+#[test]
+fn test_upgrade_newer_document_fails() {
+    // Arrange
+    let mut document = json!({ "schema_version": CURRENT_SCHEMA_VERSION + 1 });
+
+    // Act
+    let result = upgrade(&mut document);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(StorageError::UnsupportedSchemaVersion { found, supported })
+            if found == CURRENT_SCHEMA_VERSION + 1 && supported == CURRENT_SCHEMA_VERSION
+    ));
+}
+
+#[test]
+fn test_migrations_form_a_chain() {
+    for (index, migration) in MIGRATIONS.iter().enumerate() {
+        assert_eq!(migration.from, UNVERSIONED + index as u32);
+    }
+    assert_eq!(
+        UNVERSIONED + MIGRATIONS.len() as u32,
+        CURRENT_SCHEMA_VERSION
+    );
+}
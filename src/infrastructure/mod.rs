@@ -1,4 +1,13 @@
 //! Infrastructure layer for external concerns like storage and I/O.
 
+pub mod config;
+pub mod directory;
+pub mod git;
+pub mod journal;
+pub mod lock;
+pub mod memory;
+pub mod migration;
+pub mod snapshot;
+pub mod sqlite;
 pub mod storage;
 pub use storage::BoardRepository;
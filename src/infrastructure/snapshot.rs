@@ -0,0 +1,151 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::Serialize;
+
+use super::storage::{BoardStorage, JsonBoardRepository, StorageError};
+use crate::domain::Board;
+
+/// Prefix of snapshot files in the backups directory.
+const FILE_PREFIX: &str = "snapshot-";
+
+/// Format of the timestamp in snapshot names, which sorts chronologically.
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%S%3fZ";
+
+/// A copy of the board, taken when it was saved or before a destructive
+/// change.
+///
+/// Snapshots are stored as `backups/snapshot-<name>.json` in the clicky
+/// directory, in the `board.json` format whatever backend holds the board.
+/// Names combine the time and revision, e.g. `20240101T120000000Z-r42`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Snapshot {
+    /// Name identifying the snapshot
+    pub name: String,
+    /// When the snapshot was taken
+    pub taken_at: DateTime<Utc>,
+    /// Revision of the board in the snapshot
+    pub revision: u64,
+    /// Location of the snapshot file
+    #[serde(skip)]
+    pub path: PathBuf,
+}
+
+impl Snapshot {
+    /// Parses a snapshot from its file path, ignoring other files.
+    fn from_path(path: PathBuf) -> Option<Self> {
+        let name = path
+            .file_name()?
+            .to_str()?
+            .strip_prefix(FILE_PREFIX)?
+            .strip_suffix(".json")?
+            .to_string();
+        let (timestamp, revision) = name.split_once("-r")?;
+        let taken_at = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT)
+            .ok()?
+            .and_utc();
+        let revision = revision.parse().ok()?;
+        Some(Self {
+            name,
+            taken_at,
+            revision,
+            path,
+        })
+    }
+
+    /// Loads the board stored in the snapshot.
+    pub fn load(&self) -> Result<Board, StorageError> {
+        JsonBoardRepository::new().load_file(&self.path)
+    }
+}
+
+/// Gets the directory holding the snapshots of the board in `base_path`.
+fn snapshot_dir(base_path: &Path) -> PathBuf {
+    BoardStorage::clicky_dir(base_path).join(BoardStorage::BACKUP_DIR)
+}
+
+/// Saves a snapshot of `board` and removes the oldest snapshots beyond
+/// `retention`.
+///
+/// A retention of `0` disables snapshots.
+pub fn take(board: &Board, base_path: &Path, retention: usize) -> Result<(), StorageError> {
+    if retention == 0 {
+        return Ok(());
+    }
+
+    let dir = snapshot_dir(base_path);
+    fs::create_dir_all(&dir)?;
+    let name = format!(
+        "{}-r{}",
+        Utc::now().format(TIMESTAMP_FORMAT),
+        board.revision
+    );
+    let path = dir.join(format!("{}{}.json", FILE_PREFIX, name));
+    JsonBoardRepository::new().save_file(board, &path)?;
+
+    for snapshot in list(base_path)?.into_iter().skip(retention) {
+        fs::remove_file(snapshot.path)?;
+    }
+    Ok(())
+}
+
+/// Saves a snapshot of `board` unless the newest snapshot is younger than
+/// `interval`, so frequent saves do not copy the whole board each time.
+///
+/// Only the names of the existing snapshots are read.
+pub fn take_if_due(
+    board: &Board,
+    base_path: &Path,
+    retention: usize,
+    interval: Duration,
+) -> Result<(), StorageError> {
+    let newest = list(base_path)?.into_iter().next();
+    // Snapshots from the future, after a clock change, count as new.
+    if newest.is_some_and(|s| (Utc::now() - s.taken_at).to_std().unwrap_or_default() < interval) {
+        return Ok(());
+    }
+    take(board, base_path, retention)
+}
+
+/// Lists the snapshots of the board in `base_path`, newest first.
+pub fn list(base_path: &Path) -> Result<Vec<Snapshot>, StorageError> {
+    let dir = snapshot_dir(base_path);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut snapshots = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        if let Some(snapshot) = Snapshot::from_path(entry?.path()) {
+            snapshots.push(snapshot);
+        }
+    }
+    snapshots.sort_by_key(|s| std::cmp::Reverse((s.taken_at, s.revision)));
+    Ok(snapshots)
+}
+
+/// Finds a snapshot by its name or a unique prefix of it.
+///
+/// # Errors
+/// Returns `StorageError::SnapshotNotFound` if no snapshot matches, and
+/// `StorageError::AmbiguousSnapshot` if the prefix matches several.
+pub fn find(base_path: &Path, name: &str) -> Result<Snapshot, StorageError> {
+    let mut matching: Vec<Snapshot> = list(base_path)?
+        .into_iter()
+        .filter(|s| s.name.starts_with(name))
+        .collect();
+    if let Some(index) = matching.iter().position(|s| s.name == name) {
+        return Ok(matching.swap_remove(index));
+    }
+    match matching.len() {
+        0 => Err(StorageError::SnapshotNotFound(name.to_string())),
+        1 => Ok(matching.remove(0)),
+        _ => Err(StorageError::AmbiguousSnapshot(name.to_string())),
+    }
+}
+
+#[cfg(test)]
+#[path = "./snapshot_tests.rs"]
+mod snapshot_tests;
@@ -0,0 +1,100 @@
+use super::*;
+use tempfile::TempDir;
+
+fn board_at(revision: u64) -> Board {
+    let mut board = Board::new("test".to_string(), "Test Board".to_string());
+    board.revision = revision;
+    board
+}
+
+#[test]
+fn test_take_and_list_snapshots() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+
+    // Act
+    take(&board_at(1), temp_dir.path(), 10).unwrap();
+    take(&board_at(2), temp_dir.path(), 10).unwrap();
+    let snapshots = list(temp_dir.path()).unwrap();
+
+    // Assert
+    let revisions: Vec<u64> = snapshots.iter().map(|s| s.revision).collect();
+    assert_eq!(revisions, vec![2, 1]);
+    assert!(snapshots[0].name.ends_with("-r2"));
+    assert_eq!(snapshots[0].load().unwrap().revision, 2);
+}
+
+#[test]
+fn test_take_prunes_old_snapshots() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let backup_dir = snapshot_dir(temp_dir.path());
+    fs::create_dir_all(&backup_dir).unwrap();
+    fs::write(backup_dir.join("board.v1.json"), "{}").unwrap();
+
+    // Act
+    for revision in 1..=4 {
+        take(&board_at(revision), temp_dir.path(), 2).unwrap();
+    }
+
+    // Assert
+    let revisions: Vec<u64> = list(temp_dir.path())
+        .unwrap()
+        .iter()
+        .map(|s| s.revision)
+        .collect();
+    assert_eq!(revisions, vec![4, 3]);
+    assert!(backup_dir.join("board.v1.json").exists());
+}
+
+#[test]
+fn test_take_if_due_skips_recent_snapshots() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let interval = Duration::from_secs(600);
+
+    // Act
+    take_if_due(&board_at(1), temp_dir.path(), 10, interval).unwrap();
+    take_if_due(&board_at(2), temp_dir.path(), 10, interval).unwrap();
+    take_if_due(&board_at(3), temp_dir.path(), 10, Duration::ZERO).unwrap();
+
+    // Assert
+    let revisions: Vec<u64> = list(temp_dir.path())
+        .unwrap()
+        .iter()
+        .map(|s| s.revision)
+        .collect();
+    assert_eq!(revisions, vec![3, 1]);
+}
+
+#[test]
+fn test_take_with_zero_retention_keeps_nothing() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+
+    // Act
+    take(&board_at(1), temp_dir.path(), 0).unwrap();
+
+    // Assert
+    assert!(list(temp_dir.path()).unwrap().is_empty());
+}
+
+#[test]
+fn test_find_snapshot_by_prefix() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    take(&board_at(1), temp_dir.path(), 10).unwrap();
+    take(&board_at(2), temp_dir.path(), 10).unwrap();
+    let newest = list(temp_dir.path()).unwrap().remove(0);
+
+    // Act & Assert
+    assert_eq!(find(temp_dir.path(), &newest.name).unwrap(), newest);
+    assert!(matches!(
+        find(temp_dir.path(), "2"),
+        Err(StorageError::AmbiguousSnapshot(_))
+    ));
+    assert!(matches!(
+        find(temp_dir.path(), "19990101"),
+        Err(StorageError::SnapshotNotFound(_))
+    ));
+}
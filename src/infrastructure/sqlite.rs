@@ -0,0 +1,524 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Transaction};
+use serde_json::Value;
+
+use super::lock::{lock_timeout_from_env, BoardLock};
+use super::migration::{self, CURRENT_SCHEMA_VERSION};
+use super::storage::{BoardRepository, BoardStorage, MigrationReport, StorageError, StoredHeader};
+use crate::domain::{Activity, Board, Card, CardFilter, LabelMatch};
+
+/// Tables and indexes of a board database.
+///
+/// Cards and activities are stored as JSON documents, with the fields used
+/// by list filters copied into indexed columns. The rest of the board is a
+/// single JSON document in `meta`.
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS meta (
+        key TEXT PRIMARY KEY,
+        value TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS cards (
+        id TEXT PRIMARY KEY,
+        position INTEGER NOT NULL,
+        column_id TEXT NOT NULL,
+        assignee TEXT,
+        priority TEXT,
+        due TEXT,
+        updated_at TEXT,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS cards_column_id ON cards (column_id);
+    CREATE INDEX IF NOT EXISTS cards_assignee ON cards (assignee);
+    CREATE INDEX IF NOT EXISTS cards_priority ON cards (priority);
+    CREATE INDEX IF NOT EXISTS cards_due ON cards (due);
+    CREATE TABLE IF NOT EXISTS card_labels (
+        card_id TEXT NOT NULL REFERENCES cards (id) ON DELETE CASCADE,
+        label TEXT NOT NULL,
+        PRIMARY KEY (label, card_id)
+    );
+    CREATE INDEX IF NOT EXISTS card_labels_card_id ON card_labels (card_id);
+    CREATE TABLE IF NOT EXISTS activity (
+        seq INTEGER PRIMARY KEY,
+        card_id TEXT NOT NULL,
+        actor TEXT NOT NULL,
+        timestamp TEXT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS activity_card_id ON activity (card_id);
+    CREATE INDEX IF NOT EXISTS activity_actor ON activity (actor, timestamp);
+";
+
+/// Key of the board document in the `meta` table.
+const BOARD_KEY: &str = "board";
+
+/// SQLite-based implementation of BoardRepository.
+///
+/// Stores each board in a `board.db` database next to where `board.json`
+/// would be. SQLite is compiled into clicky, so no database server or
+/// system library is needed.
+///
+/// Saves run in a single transaction and only rewrite the cards that
+/// changed, so large boards stay cheap to modify. List filters are
+/// answered from indexes without reading every card.
+pub struct SqliteBoardRepository;
+
+impl SqliteBoardRepository {
+    /// Creates a new SQLite board repository.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Opens the board database, creating the file if needed.
+    fn open(&self, path: &Path) -> Result<Connection, StorageError> {
+        let connection = Connection::open(path)?;
+        // Readers do not take the board lock, so they may briefly find
+        // the database busy while a save commits.
+        connection.busy_timeout(lock_timeout_from_env())?;
+        connection.execute_batch("PRAGMA foreign_keys = ON;")?;
+        Ok(connection)
+    }
+
+    /// Opens an existing board database.
+    fn open_existing(&self, path: &Path) -> Result<Connection, StorageError> {
+        if !path.exists() {
+            return Err(StorageError::BoardNotFound(path.to_path_buf()));
+        }
+        self.open(path)
+    }
+
+    /// Reads the stored board as a `board.json` document and its schema
+    /// version, keeping only the cards selected by `cards`.
+    fn read_document(
+        &self,
+        transaction: &Transaction,
+        cards: &CardQuery,
+        with_activity: bool,
+    ) -> Result<(Value, u32), StorageError> {
+        let version = schema_version(transaction)?;
+        let board: Option<String> = transaction
+            .query_row(
+                "SELECT value FROM meta WHERE key = ?1",
+                [BOARD_KEY],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let board = board.ok_or_else(|| {
+            StorageError::InvalidData("Board database contains no board".to_string())
+        })?;
+
+        let mut document: Value = serde_json::from_str(&board)?;
+        let object = document.as_object_mut().ok_or_else(|| {
+            StorageError::InvalidData("Board document is not a JSON object".to_string())
+        })?;
+
+        let sql = format!(
+            "SELECT data FROM cards{} ORDER BY position",
+            cards.where_clause()
+        );
+        let card_documents = read_documents(transaction, &sql, &cards.params)?;
+        object.insert("cards".to_string(), Value::Array(card_documents));
+
+        let activity = if with_activity {
+            read_documents(transaction, "SELECT data FROM activity ORDER BY seq", &[])?
+        } else {
+            Vec::new()
+        };
+        object.insert("activity".to_string(), Value::Array(activity));
+        object.insert("schema_version".to_string(), version.into());
+
+        Ok((document, version))
+    }
+
+    /// Loads the board, upgrading documents written by older versions.
+    fn load_board(
+        &self,
+        base_path: &Path,
+        cards: &CardQuery,
+        with_activity: bool,
+    ) -> Result<Board, StorageError> {
+        let mut connection = self.open_existing(&BoardStorage::database_path(base_path))?;
+        let transaction = connection.transaction()?;
+        let (mut document, _) = self.read_document(&transaction, cards, with_activity)?;
+        migration::upgrade(&mut document)?;
+        Ok(serde_json::from_value(document)?)
+    }
+
+    /// Copies an outdated board database into the backups directory.
+    ///
+    /// An existing backup of the same version is kept.
+    fn backup_original(&self, base_path: &Path, version: u32) -> Result<PathBuf, StorageError> {
+        let backup_dir = BoardStorage::clicky_dir(base_path).join(BoardStorage::BACKUP_DIR);
+        fs::create_dir_all(&backup_dir)?;
+
+        let backup_path = backup_dir.join(format!("board.v{}.db", version));
+        if !backup_path.exists() {
+            fs::copy(BoardStorage::database_path(base_path), &backup_path)?;
+        }
+        Ok(backup_path)
+    }
+}
+
+impl Default for SqliteBoardRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BoardRepository for SqliteBoardRepository {
+    fn load(&self, base_path: &Path) -> Result<Board, StorageError> {
+        self.load_board(base_path, &CardQuery::default(), true)
+    }
+
+    fn load_matching(&self, base_path: &Path, filter: &CardFilter) -> Result<Board, StorageError> {
+        let mut board = self.load_board(base_path, &CardQuery::new(filter), false)?;
+        // The query only narrows down the candidates; the filter decides,
+        // so both backends select exactly the same cards.
+        board.cards.retain(|card| filter.matches(card));
+        Ok(board)
+    }
+
+    fn save(&self, board: &Board, base_path: &Path) -> Result<(), StorageError> {
+        fs::create_dir_all(BoardStorage::clicky_dir(base_path))?;
+        BoardStorage::write_gitignore(base_path)?;
+
+        let mut connection = self.open(&BoardStorage::database_path(base_path))?;
+        let version = schema_version(&connection)?;
+        if version > CURRENT_SCHEMA_VERSION {
+            return Err(StorageError::UnsupportedSchemaVersion {
+                found: version,
+                supported: CURRENT_SCHEMA_VERSION,
+            });
+        }
+        // A new database has no version; older ones are rewritten entirely.
+        let outdated = version != 0 && version < CURRENT_SCHEMA_VERSION;
+        if outdated {
+            self.backup_original(base_path, version)?;
+        }
+
+        let transaction = connection.transaction()?;
+        transaction.execute_batch(SCHEMA)?;
+        add_missing_columns(&transaction)?;
+        write_board(&transaction, board, outdated)?;
+        transaction.pragma_update(None, "user_version", CURRENT_SCHEMA_VERSION)?;
+        transaction.commit()?;
+        Ok(())
+    }
+
+    fn stored_revision(&self, base_path: &Path) -> Result<u64, StorageError> {
+        let connection = self.open_existing(&BoardStorage::database_path(base_path))?;
+        let version = schema_version(&connection)?;
+        if version > CURRENT_SCHEMA_VERSION {
+            return Err(StorageError::UnsupportedSchemaVersion {
+                found: version,
+                supported: CURRENT_SCHEMA_VERSION,
+            });
+        }
+        let board: Option<String> = connection
+            .query_row(
+                "SELECT value FROM meta WHERE key = ?1",
+                [BOARD_KEY],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let board = board.ok_or_else(|| {
+            StorageError::InvalidData("Board database contains no board".to_string())
+        })?;
+        Ok(serde_json::from_str::<StoredHeader>(&board)?.revision)
+    }
+
+    fn exists(&self, base_path: &Path) -> bool {
+        BoardStorage::database_path(base_path).exists()
+    }
+
+    fn delete(&self, base_path: &Path) -> Result<(), StorageError> {
+        let path = &BoardStorage::database_path(base_path);
+        if !path.exists() {
+            return Err(StorageError::BoardNotFound(path.to_path_buf()));
+        }
+
+        fs::remove_file(path)?;
+        Ok(())
+    }
+
+    fn migrate(&self, base_path: &Path, dry_run: bool) -> Result<MigrationReport, StorageError> {
+        let mut connection = self.open_existing(&BoardStorage::database_path(base_path))?;
+        let transaction = connection.transaction()?;
+        let (mut document, from_version) =
+            self.read_document(&transaction, &CardQuery::default(), true)?;
+        drop(transaction);
+        let steps: Vec<String> = migration::upgrade(&mut document)?
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+
+        let mut report = MigrationReport {
+            from_version,
+            to_version: CURRENT_SCHEMA_VERSION,
+            steps,
+            backup: None,
+            dry_run,
+        };
+        if dry_run || report.steps.is_empty() {
+            return Ok(report);
+        }
+
+        let board: Board = serde_json::from_value(document)?;
+        report.backup = Some(self.backup_original(base_path, from_version)?);
+        self.save(&board, base_path)?;
+        Ok(report)
+    }
+
+    fn lock(&self, base_path: &Path, timeout: Duration) -> Result<Option<BoardLock>, StorageError> {
+        BoardLock::acquire(&BoardStorage::lock_path(base_path), timeout).map(Some)
+    }
+}
+
+/// SQL conditions selecting the cards that may match a filter.
+#[derive(Debug, Default)]
+struct CardQuery {
+    conditions: Vec<String>,
+    params: Vec<String>,
+}
+
+impl CardQuery {
+    /// Translates the indexed criteria of a filter into SQL conditions.
+    fn new(filter: &CardFilter) -> Self {
+        let mut query = Self::default();
+        if let Some(column) = &filter.column {
+            query.add("column_id = ?", [column.clone()]);
+        }
+        if let Some(assignee) = &filter.assignee {
+            query.add("assignee = ?", [assignee.clone()]);
+        }
+        if let Some(priority) = filter.priority {
+            query.add("priority = ?", [priority.to_string()]);
+        }
+        for date in [filter.due_before, filter.overdue_on].into_iter().flatten() {
+            query.add("due < ?", [date.to_string()]);
+        }
+
+        let labels_query = "id IN (SELECT card_id FROM card_labels WHERE label";
+        match filter.label_match {
+            LabelMatch::All => {
+                for label in &filter.labels {
+                    query.add(&format!("{} = ?)", labels_query), [label.clone()]);
+                }
+            }
+            LabelMatch::Any if !filter.labels.is_empty() => {
+                let placeholders = vec!["?"; filter.labels.len()].join(", ");
+                query.add(
+                    &format!("{} IN ({}))", labels_query, placeholders),
+                    filter.labels.iter().cloned(),
+                );
+            }
+            LabelMatch::Any => {}
+        }
+        query
+    }
+
+    fn add(&mut self, condition: &str, params: impl IntoIterator<Item = String>) {
+        self.conditions.push(condition.to_string());
+        self.params.extend(params);
+    }
+
+    fn where_clause(&self) -> String {
+        if self.conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", self.conditions.join(" AND "))
+        }
+    }
+}
+
+/// Reads the schema version of a board database; `0` if it is new.
+fn schema_version(connection: &Connection) -> Result<u32, StorageError> {
+    Ok(connection.query_row("PRAGMA user_version", [], |row| row.get(0))?)
+}
+
+/// Adds the columns that databases created by earlier builds lack.
+///
+/// Their values start out empty, so the affected rows are rewritten by the
+/// next save.
+fn add_missing_columns(transaction: &Transaction) -> Result<(), StorageError> {
+    let has_updated_at: bool = transaction.query_row(
+        "SELECT COUNT(*) > 0 FROM pragma_table_info('cards') WHERE name = 'updated_at'",
+        [],
+        |row| row.get(0),
+    )?;
+    if !has_updated_at {
+        transaction.execute("ALTER TABLE cards ADD COLUMN updated_at TEXT", [])?;
+    }
+    Ok(())
+}
+
+/// Reads the JSON documents returned by a single-column query.
+fn read_documents(
+    transaction: &Transaction,
+    sql: &str,
+    params: &[String],
+) -> Result<Vec<Value>, StorageError> {
+    let mut statement = transaction.prepare(sql)?;
+    let rows = statement.query_map(params_from_iter(params), |row| row.get::<_, String>(0))?;
+
+    let mut documents = Vec::new();
+    for row in rows {
+        documents.push(serde_json::from_str(&row?)?);
+    }
+    Ok(documents)
+}
+
+/// Writes a board, touching only the rows that changed.
+///
+/// With `rewrite`, every card and activity is written anew.
+fn write_board(
+    transaction: &Transaction,
+    board: &Board,
+    rewrite: bool,
+) -> Result<(), StorageError> {
+    if rewrite {
+        transaction
+            .execute_batch("DELETE FROM card_labels; DELETE FROM cards; DELETE FROM activity;")?;
+    }
+
+    let mut document = serde_json::to_value(board)?;
+    if let Some(object) = document.as_object_mut() {
+        object.remove("cards");
+        object.remove("activity");
+    }
+    transaction.execute(
+        "INSERT INTO meta (key, value) VALUES (?1, ?2)
+         ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+        params![BOARD_KEY, document.to_string()],
+    )?;
+
+    write_cards(transaction, &board.cards)?;
+    write_activity(transaction, &board.activity)
+}
+
+/// Stores the cards in order, replacing changed cards and deleting
+/// cards that are no longer on the board.
+///
+/// A card changed if its `updated_at` differs from the stored one, so
+/// unchanged cards are neither read nor serialized; only their position
+/// is updated when it moved.
+fn write_cards(transaction: &Transaction, cards: &[Card]) -> Result<(), StorageError> {
+    let mut stored: HashMap<String, (i64, Option<String>)> = HashMap::new();
+    {
+        let mut statement = transaction.prepare("SELECT id, position, updated_at FROM cards")?;
+        let rows = statement.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, (row.get(1)?, row.get(2)?)))
+        })?;
+        for row in rows {
+            let (id, card) = row?;
+            stored.insert(id, card);
+        }
+    }
+
+    for (position, card) in (0_i64..).zip(cards) {
+        let updated_at = card.updated_at.to_rfc3339();
+        match stored.remove(&card.id) {
+            Some((stored_position, Some(stored_at))) if stored_at == updated_at => {
+                if stored_position != position {
+                    transaction.execute(
+                        "UPDATE cards SET position = ?1 WHERE id = ?2",
+                        params![position, card.id],
+                    )?;
+                }
+            }
+            _ => write_card(transaction, card, position, &updated_at)?,
+        }
+    }
+
+    for id in stored.keys() {
+        transaction.execute("DELETE FROM cards WHERE id = ?1", [id])?;
+    }
+    Ok(())
+}
+
+/// Inserts or replaces a single card and its labels.
+fn write_card(
+    transaction: &Transaction,
+    card: &Card,
+    position: i64,
+    updated_at: &str,
+) -> Result<(), StorageError> {
+    transaction.execute(
+        "INSERT INTO cards (id, position, column_id, assignee, priority, due, updated_at, data)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+         ON CONFLICT (id) DO UPDATE SET
+             position = excluded.position,
+             column_id = excluded.column_id,
+             assignee = excluded.assignee,
+             priority = excluded.priority,
+             due = excluded.due,
+             updated_at = excluded.updated_at,
+             data = excluded.data",
+        params![
+            card.id,
+            position,
+            card.column_id,
+            card.assignee,
+            card.priority.map(|p| p.to_string()),
+            card.due.map(|d| d.to_string()),
+            updated_at,
+            serde_json::to_string(card)?,
+        ],
+    )?;
+
+    transaction.execute("DELETE FROM card_labels WHERE card_id = ?1", [&card.id])?;
+    for label in &card.labels {
+        transaction.execute(
+            "INSERT INTO card_labels (card_id, label) VALUES (?1, ?2)",
+            params![card.id, label],
+        )?;
+    }
+    Ok(())
+}
+
+/// Appends new activities to the stored log.
+///
+/// The log only grows, so the stored activities are expected to be a
+/// prefix of the board's. If they are not, the log is rewritten.
+fn write_activity(transaction: &Transaction, activity: &[Activity]) -> Result<(), StorageError> {
+    let stored: i64 =
+        transaction.query_row("SELECT COUNT(*) FROM activity", [], |row| row.get(0))?;
+    let mut stored = usize::try_from(stored).unwrap_or(usize::MAX);
+
+    if stored > 0 {
+        let last: String = transaction.query_row(
+            "SELECT data FROM activity ORDER BY seq DESC LIMIT 1",
+            [],
+            |row| row.get(0),
+        )?;
+        let is_prefix = activity
+            .get(stored - 1)
+            .map(serde_json::to_string)
+            .transpose()?
+            .is_some_and(|expected| expected == last);
+        if !is_prefix {
+            transaction.execute("DELETE FROM activity", [])?;
+            stored = 0;
+        }
+    }
+
+    for entry in &activity[stored..] {
+        transaction.execute(
+            "INSERT INTO activity (card_id, actor, timestamp, data) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                entry.card_id,
+                entry.actor,
+                entry.timestamp.to_rfc3339(),
+                serde_json::to_string(entry)?,
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "./sqlite_tests.rs"]
+mod sqlite_tests;
@@ -0,0 +1,297 @@
+use super::*;
+use crate::domain::{ActivityKind, Priority};
+use chrono::NaiveDate;
+use tempfile::TempDir;
+
+fn sample_board() -> Board {
+    let mut board = Board::new("test".to_string(), "Test Board".to_string());
+    let first = board.create_card("First".to_string(), None, Some("alice".to_string()), None);
+    let second = board.create_card("Second".to_string(), None, None, None);
+    board.create_card("Third".to_string(), None, None, Some("done".to_string()));
+
+    let card = board.get_card_mut(&first).unwrap();
+    card.priority = Some(Priority::High);
+    card.add_label("bug".to_string());
+    card.add_comment("bob".to_string(), "Looks good".to_string());
+    let card = board.get_card_mut(&second).unwrap();
+    card.add_label("docs".to_string());
+    card.due = NaiveDate::from_ymd_opt(2024, 1, 1);
+
+    board.record_activity(Activity::new(
+        first,
+        "alice".to_string(),
+        ActivityKind::Commented,
+    ));
+    board
+}
+
+fn card_ids(board: &Board) -> Vec<&str> {
+    board.cards.iter().map(|c| c.id.as_str()).collect()
+}
+
+#[test]
+fn test_sqlite_repository_save_and_load() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = SqliteBoardRepository::new();
+    let board = sample_board();
+
+    // Act
+    repo.save(&board, temp_dir.path()).unwrap();
+    let loaded = repo.load(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(loaded, board);
+    assert!(BoardStorage::database_path(temp_dir.path()).exists());
+    assert!(!BoardStorage::board_path(temp_dir.path()).exists());
+}
+
+#[test]
+fn test_sqlite_repository_stored_revision() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = SqliteBoardRepository::new();
+    let mut board = sample_board();
+    board.revision = 9;
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Act
+    let revision = repo.stored_revision(temp_dir.path()).unwrap();
+    let missing = repo.stored_revision(TempDir::new().unwrap().path());
+
+    // Assert
+    assert_eq!(revision, 9);
+    assert!(matches!(missing, Err(StorageError::BoardNotFound(_))));
+}
+
+#[test]
+fn test_sqlite_repository_load_not_found() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = SqliteBoardRepository::new();
+
+    // Act
+    let result = repo.load(temp_dir.path());
+
+    // Assert
+    assert!(matches!(result, Err(StorageError::BoardNotFound(_))));
+    assert!(!repo.exists(temp_dir.path()));
+}
+
+#[test]
+fn test_sqlite_repository_saves_changes() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = SqliteBoardRepository::new();
+    let mut board = sample_board();
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Act
+    board.delete_card("TES-001");
+    board.move_card("TES-002", "in_progress");
+    board.get_card_mut("TES-002").unwrap().labels.clear();
+    board.create_card("Fourth".to_string(), None, None, None);
+    board.record_activity(Activity::new(
+        "TES-004".to_string(),
+        "bob".to_string(),
+        ActivityKind::Commented,
+    ));
+    repo.save(&board, temp_dir.path()).unwrap();
+    let loaded = repo.load(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(loaded, board);
+    assert_eq!(card_ids(&loaded), vec!["TES-002", "TES-003", "TES-004"]);
+}
+
+#[test]
+fn test_sqlite_repository_only_rewrites_changed_cards() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = SqliteBoardRepository::new();
+    let mut board = sample_board();
+    repo.save(&board, temp_dir.path()).unwrap();
+    let connection = Connection::open(BoardStorage::database_path(temp_dir.path())).unwrap();
+    connection
+        .execute(
+            "UPDATE cards SET data = json_set(data, '$.title', 'Untouched') WHERE id = 'TES-003'",
+            [],
+        )
+        .unwrap();
+
+    // Act
+    board
+        .get_card_mut("TES-001")
+        .unwrap()
+        .set_title("Renamed".to_string());
+    board.cards.swap(0, 1);
+    repo.save(&board, temp_dir.path()).unwrap();
+    let loaded = repo.load(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(loaded.get_card("TES-001").unwrap().title, "Renamed");
+    assert_eq!(loaded.get_card("TES-003").unwrap().title, "Untouched");
+    assert_eq!(card_ids(&loaded), vec!["TES-002", "TES-001", "TES-003"]);
+}
+
+#[test]
+fn test_sqlite_repository_adds_missing_columns() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = SqliteBoardRepository::new();
+    let mut board = sample_board();
+    repo.save(&board, temp_dir.path()).unwrap();
+    let connection = Connection::open(BoardStorage::database_path(temp_dir.path())).unwrap();
+    connection
+        .execute("ALTER TABLE cards DROP COLUMN updated_at", [])
+        .unwrap();
+
+    // Act
+    board.create_card("Fourth".to_string(), None, None, None);
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(repo.load(temp_dir.path()).unwrap(), board);
+    let missing: i64 = connection
+        .query_row(
+            "SELECT COUNT(*) FROM cards WHERE updated_at IS NULL",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(missing, 0);
+}
+
+#[test]
+fn test_sqlite_repository_rewrites_replaced_activity() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = SqliteBoardRepository::new();
+    let mut board = sample_board();
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Act
+    board.activity = vec![Activity::new(
+        "TES-002".to_string(),
+        "carol".to_string(),
+        ActivityKind::Commented,
+    )];
+    repo.save(&board, temp_dir.path()).unwrap();
+    let loaded = repo.load(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(loaded.activity, board.activity);
+}
+
+#[test]
+fn test_sqlite_repository_load_matching() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = SqliteBoardRepository::new();
+    repo.save(&sample_board(), temp_dir.path()).unwrap();
+    let any_label = CardFilter {
+        labels: vec!["bug".to_string(), "docs".to_string()],
+        label_match: LabelMatch::Any,
+        ..Default::default()
+    };
+    let all_labels = CardFilter {
+        label_match: LabelMatch::All,
+        ..any_label.clone()
+    };
+    let assigned_high = CardFilter {
+        assignee: Some("alice".to_string()),
+        priority: Some(Priority::High),
+        ..Default::default()
+    };
+    let overdue = CardFilter {
+        overdue_on: NaiveDate::from_ymd_opt(2024, 6, 1),
+        ..Default::default()
+    };
+    let in_done = CardFilter {
+        column: Some("done".to_string()),
+        ..Default::default()
+    };
+
+    // Act & Assert
+    let matching = |filter: &CardFilter| {
+        let board = repo.load_matching(temp_dir.path(), filter).unwrap();
+        card_ids(&board)
+            .into_iter()
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(matching(&any_label), vec!["TES-001", "TES-002"]);
+    assert!(matching(&all_labels).is_empty());
+    assert_eq!(matching(&assigned_high), vec!["TES-001"]);
+    assert_eq!(matching(&overdue), vec!["TES-002"]);
+    assert_eq!(matching(&in_done), vec!["TES-003"]);
+    assert_eq!(matching(&CardFilter::default()).len(), 3);
+}
+
+#[test]
+fn test_sqlite_repository_rejects_newer_database() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = SqliteBoardRepository::new();
+    let board = sample_board();
+    repo.save(&board, temp_dir.path()).unwrap();
+    let connection = Connection::open(BoardStorage::database_path(temp_dir.path())).unwrap();
+    connection
+        .pragma_update(None, "user_version", CURRENT_SCHEMA_VERSION + 1)
+        .unwrap();
+
+    // Act
+    let loaded = repo.load(temp_dir.path());
+    let saved = repo.save(&board, temp_dir.path());
+
+    // Assert
+    assert!(matches!(
+        loaded,
+        Err(StorageError::UnsupportedSchemaVersion { .. })
+    ));
+    assert!(matches!(
+        saved,
+        Err(StorageError::UnsupportedSchemaVersion { .. })
+    ));
+}
+
+#[test]
+fn test_sqlite_repository_migrate_current_database() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = SqliteBoardRepository::new();
+    repo.save(&sample_board(), temp_dir.path()).unwrap();
+
+    // Act
+    let report = repo.migrate(temp_dir.path(), false).unwrap();
+
+    // Assert
+    assert_eq!(report.from_version, CURRENT_SCHEMA_VERSION);
+    assert!(report.steps.is_empty());
+    assert!(report.backup.is_none());
+}
+
+#[test]
+fn test_sqlite_repository_migrate_older_database() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = SqliteBoardRepository::new();
+    let board = sample_board();
+    repo.save(&board, temp_dir.path()).unwrap();
+    let path = BoardStorage::database_path(temp_dir.path());
+    Connection::open(&path)
+        .unwrap()
+        .pragma_update(None, "user_version", 2)
+        .unwrap();
+
+    // Act
+    let report = repo.migrate(temp_dir.path(), false).unwrap();
+
+    // Assert
+    assert_eq!(report.from_version, 2);
+    assert_eq!(report.steps.len(), (CURRENT_SCHEMA_VERSION - 2) as usize);
+    assert!(report.backup.unwrap().ends_with("board.v2.db"));
+    let connection = Connection::open(&path).unwrap();
+    assert_eq!(schema_version(&connection).unwrap(), CURRENT_SCHEMA_VERSION);
+    assert_eq!(repo.load(temp_dir.path()).unwrap(), board);
+}
@@ -1,9 +1,19 @@
-use std::fs;
+use std::fs::{self, File};
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::domain::Board;
+use super::config::{BoardConfig, StorageBackend};
+use super::directory::DirectoryBoardRepository;
+use super::journal;
+use super::lock::BoardLock;
+use super::migration::{self, CURRENT_SCHEMA_VERSION};
+use super::snapshot;
+use super::sqlite::SqliteBoardRepository;
+use crate::domain::{Board, CardFilter, CardIdScheme, Journal};
 
 /// Errors that can occur during storage operations.
 #[derive(Error, Debug)]
@@ -13,47 +23,194 @@ pub enum StorageError {
     Io(#[from] std::io::Error),
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
+    #[error("Database error: {0}")]
+    Database(#[from] rusqlite::Error),
     #[error("Board not found at {0}")]
     BoardNotFound(PathBuf),
     #[error("Board already exists at {0}")]
     BoardAlreadyExists(PathBuf),
     #[error("Invalid board data: {0}")]
     InvalidData(String),
+    #[error(
+        "Board is locked by another process (waited {}s for {})",
+        timeout.as_secs_f64(),
+        path.display()
+    )]
+    LockTimeout { path: PathBuf, timeout: Duration },
+    #[error(
+        "Board file uses schema version {found}, but this clicky only supports up to version {supported}. Please upgrade clicky."
+    )]
+    UnsupportedSchemaVersion { found: u32, supported: u32 },
+    #[error("Snapshot not found: {0}. Run 'clicky backup list' to see the snapshots.")]
+    SnapshotNotFound(String),
+    #[error("Snapshot name '{0}' matches several snapshots; give more of the name")]
+    AmbiguousSnapshot(String),
+}
+
+impl StorageError {
+    /// Returns a stable, machine-readable code for this error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            StorageError::Io(_) => "io_error",
+            StorageError::Serialization(_) => "serialization_error",
+            StorageError::Database(_) => "database_error",
+            StorageError::BoardNotFound(_) => "board_not_found",
+            StorageError::BoardAlreadyExists(_) => "board_already_exists",
+            StorageError::InvalidData(_) => "invalid_board_data",
+            StorageError::LockTimeout { .. } => "lock_timeout",
+            StorageError::UnsupportedSchemaVersion { .. } => "unsupported_schema_version",
+            StorageError::SnapshotNotFound(_) => "snapshot_not_found",
+            StorageError::AmbiguousSnapshot(_) => "ambiguous_snapshot",
+        }
+    }
 }
 
 /// Trait for board persistence operations.
 ///
 /// This abstraction allows for different storage implementations
 /// (JSON files, database, etc.) without changing the application logic.
+/// Boards are addressed by the project directory containing them; each
+/// implementation decides where and how the board is actually stored.
 pub trait BoardRepository {
     /// Loads a board from storage.
     ///
     /// # Arguments
-    /// * `path` - Path to the board storage location
+    /// * `base_path` - Project directory containing the board
     ///
     /// # Errors
-    /// Returns `StorageError::BoardNotFound` if no board exists at the path.
-    fn load(&self, path: &Path) -> Result<Board, StorageError>;
+    /// Returns `StorageError::BoardNotFound` if no board exists there.
+    fn load(&self, base_path: &Path) -> Result<Board, StorageError>;
+
+    /// Loads a board holding only the cards that match `filter`.
+    ///
+    /// The result is a read-only view for listing cards and may leave out
+    /// the activity log; saving it would drop the other cards. Backends
+    /// that can query cards directly override the default, which loads the
+    /// whole board and filters it in memory.
+    fn load_matching(&self, base_path: &Path, filter: &CardFilter) -> Result<Board, StorageError> {
+        let mut board = self.load(base_path)?;
+        board.cards.retain(|card| filter.matches(card));
+        Ok(board)
+    }
 
     /// Saves a board to storage.
     ///
     /// # Arguments
     /// * `board` - The board to save
-    /// * `path` - Path to the board storage location
-    fn save(&self, board: &Board, path: &Path) -> Result<(), StorageError>;
+    /// * `base_path` - Project directory containing the board
+    fn save(&self, board: &Board, base_path: &Path) -> Result<(), StorageError>;
+
+    /// Reads the revision of the stored board.
+    ///
+    /// Saves compare it against the revision a change was based on, so
+    /// backends override the default, which loads the whole board, with a
+    /// read of just the revision.
+    ///
+    /// # Errors
+    /// Returns `StorageError::BoardNotFound` if no board exists there.
+    fn stored_revision(&self, base_path: &Path) -> Result<u64, StorageError> {
+        Ok(self.load(base_path)?.revision)
+    }
+
+    /// Keeps a copy of a board that a destructive operation is about to
+    /// change.
+    ///
+    /// Storage without snapshots, the default, ignores it.
+    fn snapshot(&self, board: &Board, base_path: &Path) -> Result<(), StorageError> {
+        let _ = (board, base_path);
+        Ok(())
+    }
 
-    /// Checks if a board exists at the given path.
-    fn exists(&self, path: &Path) -> bool;
+    /// Checks if a board exists in the given project directory.
+    fn exists(&self, base_path: &Path) -> bool;
 
     /// Deletes a board from storage.
     #[allow(dead_code)]
-    fn delete(&self, path: &Path) -> Result<(), StorageError>;
+    fn delete(&self, base_path: &Path) -> Result<(), StorageError>;
+
+    /// Upgrades stored data to the current schema version.
+    ///
+    /// With `dry_run`, reports the steps that would be applied without
+    /// changing anything.
+    fn migrate(&self, base_path: &Path, dry_run: bool) -> Result<MigrationReport, StorageError>;
+
+    /// Locks the board against modification by other processes.
+    ///
+    /// Returns `None` for storage that is not shared between processes.
+    ///
+    /// # Errors
+    /// Returns `StorageError::LockTimeout` if the lock is still held by
+    /// another process after `timeout`.
+    fn lock(&self, base_path: &Path, timeout: Duration) -> Result<Option<BoardLock>, StorageError>;
+
+    /// Loads the journal of undoable card operations.
+    ///
+    /// The journal is local to the checkout whatever the backend, so the
+    /// default reads `.clicky/journal.json`. Returns an empty journal if
+    /// none was saved.
+    fn load_journal(&self, base_path: &Path) -> Result<Journal, StorageError> {
+        journal::load(base_path)
+    }
+
+    /// Saves the journal of undoable card operations.
+    fn save_journal(&self, journal: &Journal, base_path: &Path) -> Result<(), StorageError> {
+        journal::save(journal, base_path)
+    }
+}
+
+/// Outcome of upgrading a stored board to the current schema version.
+#[derive(Debug, Serialize)]
+pub struct MigrationReport {
+    /// Schema version found in storage
+    pub from_version: u32,
+    /// Schema version after migrating
+    pub to_version: u32,
+    /// Descriptions of the applied (or, in a dry run, pending) steps
+    pub steps: Vec<String>,
+    /// Copy of the original data, if it was backed up
+    pub backup: Option<PathBuf>,
+    /// Whether nothing was written
+    pub dry_run: bool,
+}
+
+/// Outcome of moving a board to another storage backend.
+#[derive(Debug, Serialize)]
+pub struct StorageConversion {
+    /// Backend the board was stored in
+    pub from: StorageBackend,
+    /// Backend the board is stored in now
+    pub to: StorageBackend,
+    /// Number of cards carried over
+    pub cards: usize,
+}
+
+/// A board as written to `board.json`, tagged with its schema version.
+#[derive(Serialize)]
+struct StoredBoard<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    board: &'a Board,
+}
+
+/// The fields of a stored board document needed before reading all of it.
+///
+/// Other fields are skipped without being parsed into values.
+#[derive(Deserialize)]
+pub(super) struct StoredHeader {
+    pub schema_version: Option<u32>,
+    #[serde(default)]
+    pub revision: u64,
 }
 
 /// JSON file-based implementation of BoardRepository.
 ///
 /// Stores boards as JSON files in the filesystem.
-/// Each board is stored in a separate file named `board.json`.
+/// Each board is stored in a separate file named `board.json`, which is
+/// replaced atomically so readers never see a partially written board.
+///
+/// Files written by older versions are upgraded on load. Before such a file
+/// is first overwritten, the original is copied to `backups/board.v<N>.json`
+/// next to it.
 pub struct JsonBoardRepository;
 
 impl JsonBoardRepository {
@@ -62,6 +219,70 @@ impl JsonBoardRepository {
         Self
     }
 
+    /// Reads the stored document and its schema version.
+    fn read_document(&self, path: &Path) -> Result<(serde_json::Value, u32), StorageError> {
+        if !path.exists() {
+            return Err(StorageError::BoardNotFound(path.to_path_buf()));
+        }
+
+        let content = fs::read_to_string(path)?;
+        let document: serde_json::Value = serde_json::from_str(&content)?;
+        let version = migration::schema_version(&document)?;
+        Ok((document, version))
+    }
+
+    /// Reads the header of the stored document.
+    fn read_header(&self, path: &Path) -> Result<StoredHeader, StorageError> {
+        if !path.exists() {
+            return Err(StorageError::BoardNotFound(path.to_path_buf()));
+        }
+
+        Ok(serde_json::from_reader(std::io::BufReader::new(
+            File::open(path)?,
+        ))?)
+    }
+
+    /// Loads a board from a board file at any path, upgrading it to the
+    /// current schema version.
+    pub fn load_file(&self, path: &Path) -> Result<Board, StorageError> {
+        let (mut document, _) = self.read_document(path)?;
+        migration::upgrade(&mut document)?;
+        Ok(serde_json::from_value(document)?)
+    }
+
+    /// Writes a board file at any path, such as a version git is merging.
+    pub fn save_file(&self, board: &Board, path: &Path) -> Result<(), StorageError> {
+        self.write(board, path)
+    }
+
+    /// Writes a board at the current schema version.
+    fn write(&self, board: &Board, path: &Path) -> Result<(), StorageError> {
+        let stored = StoredBoard {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            board,
+        };
+        let content = serde_json::to_string_pretty(&stored)?;
+        write_atomic(path, content.as_bytes())
+    }
+
+    /// Copies an outdated board file into the backups directory.
+    ///
+    /// An existing backup of the same version is kept, so the backup always
+    /// holds the file as that version of clicky wrote it.
+    fn backup_original(&self, path: &Path, version: u32) -> Result<PathBuf, StorageError> {
+        let backup_dir = path
+            .parent()
+            .map(|p| p.join(BoardStorage::BACKUP_DIR))
+            .unwrap_or_else(|| PathBuf::from(BoardStorage::BACKUP_DIR));
+        fs::create_dir_all(&backup_dir)?;
+
+        let backup_path = backup_dir.join(format!("board.v{}.json", version));
+        if !backup_path.exists() {
+            fs::copy(path, &backup_path)?;
+        }
+        Ok(backup_path)
+    }
+
     /// Ensures the parent directory exists.
     fn ensure_directory(&self, path: &Path) -> Result<(), StorageError> {
         if let Some(parent) = path.parent() {
@@ -78,29 +299,50 @@ impl Default for JsonBoardRepository {
 }
 
 impl BoardRepository for JsonBoardRepository {
-    fn load(&self, path: &Path) -> Result<Board, StorageError> {
-        if !path.exists() {
-            return Err(StorageError::BoardNotFound(path.to_path_buf()));
-        }
-
-        let content = fs::read_to_string(path)?;
-        let board: Board = serde_json::from_str(&content)?;
-        Ok(board)
+    fn load(&self, base_path: &Path) -> Result<Board, StorageError> {
+        self.load_file(&BoardStorage::board_path(base_path))
     }
 
-    fn save(&self, board: &Board, path: &Path) -> Result<(), StorageError> {
+    fn save(&self, board: &Board, base_path: &Path) -> Result<(), StorageError> {
+        let path = &BoardStorage::board_path(base_path);
         self.ensure_directory(path)?;
+        BoardStorage::write_gitignore(base_path)?;
 
-        let content = serde_json::to_string_pretty(board)?;
-        fs::write(path, content)?;
-        Ok(())
+        // Unreadable files carry no version information and are replaced.
+        if let Ok(header) = self.read_header(path) {
+            let version = header.schema_version.unwrap_or(migration::UNVERSIONED);
+            if version > CURRENT_SCHEMA_VERSION {
+                return Err(StorageError::UnsupportedSchemaVersion {
+                    found: version,
+                    supported: CURRENT_SCHEMA_VERSION,
+                });
+            }
+            if version < CURRENT_SCHEMA_VERSION {
+                self.backup_original(path, version)?;
+            }
+        }
+
+        self.write(board, path)
+    }
+
+    fn stored_revision(&self, base_path: &Path) -> Result<u64, StorageError> {
+        let header = self.read_header(&BoardStorage::board_path(base_path))?;
+        let version = header.schema_version.unwrap_or(migration::UNVERSIONED);
+        if version > CURRENT_SCHEMA_VERSION {
+            return Err(StorageError::UnsupportedSchemaVersion {
+                found: version,
+                supported: CURRENT_SCHEMA_VERSION,
+            });
+        }
+        Ok(header.revision)
     }
 
-    fn exists(&self, path: &Path) -> bool {
-        path.exists()
+    fn exists(&self, base_path: &Path) -> bool {
+        BoardStorage::board_path(base_path).exists()
     }
 
-    fn delete(&self, path: &Path) -> Result<(), StorageError> {
+    fn delete(&self, base_path: &Path) -> Result<(), StorageError> {
+        let path = &BoardStorage::board_path(base_path);
         if !path.exists() {
             return Err(StorageError::BoardNotFound(path.to_path_buf()));
         }
@@ -108,6 +350,187 @@ impl BoardRepository for JsonBoardRepository {
         fs::remove_file(path)?;
         Ok(())
     }
+
+    fn migrate(&self, base_path: &Path, dry_run: bool) -> Result<MigrationReport, StorageError> {
+        let path = &BoardStorage::board_path(base_path);
+        let (mut document, from_version) = self.read_document(path)?;
+        let steps: Vec<String> = migration::upgrade(&mut document)?
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+
+        let mut report = MigrationReport {
+            from_version,
+            to_version: CURRENT_SCHEMA_VERSION,
+            steps,
+            backup: None,
+            dry_run,
+        };
+        if dry_run || report.steps.is_empty() {
+            return Ok(report);
+        }
+
+        let board: Board = serde_json::from_value(document)?;
+        report.backup = Some(self.backup_original(path, from_version)?);
+        self.write(&board, path)?;
+        Ok(report)
+    }
+
+    fn lock(&self, base_path: &Path, timeout: Duration) -> Result<Option<BoardLock>, StorageError> {
+        BoardLock::acquire(&BoardStorage::lock_path(base_path), timeout).map(Some)
+    }
+}
+
+/// Repository that stores each board with the backend named in its config.
+///
+/// Reads `.clicky/config.json` of the addressed project directory on every
+/// call, so one repository serves boards with different backends. Boards
+/// without a config use JSON storage.
+pub struct ConfiguredBoardRepository;
+
+impl ConfiguredBoardRepository {
+    /// Creates a new configured board repository.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Gets the repository for the backend configured for a board.
+    fn backend(&self, base_path: &Path) -> Result<Box<dyn BoardRepository>, StorageError> {
+        let config = BoardConfig::load(base_path)?;
+        Ok(Self::repository(config.storage))
+    }
+
+    /// Gets the repository implementing a backend.
+    fn repository(backend: StorageBackend) -> Box<dyn BoardRepository> {
+        match backend {
+            StorageBackend::Json => Box::new(JsonBoardRepository::new()),
+            StorageBackend::Sqlite => Box::new(SqliteBoardRepository::new()),
+            StorageBackend::Directory => Box::new(DirectoryBoardRepository::new()),
+        }
+    }
+
+    /// Moves the board in `base_path` to another backend.
+    ///
+    /// The board is written to the new backend and read back; only if it
+    /// comes back unchanged is the config switched over and the old data
+    /// removed. Converting to the current backend changes nothing. Boards
+    /// moved to a backend that needs random card IDs switch to them; existing
+    /// IDs are kept. The caller must hold the board lock.
+    ///
+    /// # Errors
+    /// Returns `StorageError::InvalidData` if the converted board differs
+    /// from the original; the original is left in place.
+    pub fn convert(
+        &self,
+        base_path: &Path,
+        target: StorageBackend,
+    ) -> Result<StorageConversion, StorageError> {
+        let mut config = BoardConfig::load(base_path)?;
+        let source = Self::repository(config.storage);
+        let board = source.load(base_path)?;
+        let conversion = StorageConversion {
+            from: config.storage,
+            to: target,
+            cards: board.cards.len(),
+        };
+        if config.storage == target {
+            return Ok(conversion);
+        }
+
+        let mut board = board;
+        if target.card_id_scheme() == CardIdScheme::Random {
+            board.card_id_scheme = CardIdScheme::Random;
+        }
+        let destination = Self::repository(target);
+        destination.save(&board, base_path)?;
+        if destination.load(base_path)? != board {
+            let _ = destination.delete(base_path);
+            return Err(StorageError::InvalidData(format!(
+                "Board did not survive conversion to {} storage; it was left unchanged",
+                target
+            )));
+        }
+
+        config.storage = target;
+        config.save(base_path)?;
+        source.delete(base_path)?;
+        Ok(conversion)
+    }
+}
+
+impl Default for ConfiguredBoardRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BoardRepository for ConfiguredBoardRepository {
+    fn load(&self, base_path: &Path) -> Result<Board, StorageError> {
+        self.backend(base_path)?.load(base_path)
+    }
+
+    fn load_matching(&self, base_path: &Path, filter: &CardFilter) -> Result<Board, StorageError> {
+        self.backend(base_path)?.load_matching(base_path, filter)
+    }
+
+    /// Saves the board and takes a snapshot of it if the newest one is
+    /// older than the configured interval, keeping as many snapshots as the
+    /// config allows.
+    fn save(&self, board: &Board, base_path: &Path) -> Result<(), StorageError> {
+        let config = BoardConfig::load(base_path)?;
+        Self::repository(config.storage).save(board, base_path)?;
+        let interval = Duration::from_secs(config.snapshot_interval_minutes.saturating_mul(60));
+        snapshot::take_if_due(board, base_path, config.snapshot_retention, interval)
+    }
+
+    fn snapshot(&self, board: &Board, base_path: &Path) -> Result<(), StorageError> {
+        let config = BoardConfig::load(base_path)?;
+        snapshot::take(board, base_path, config.snapshot_retention)
+    }
+
+    fn stored_revision(&self, base_path: &Path) -> Result<u64, StorageError> {
+        self.backend(base_path)?.stored_revision(base_path)
+    }
+
+    fn exists(&self, base_path: &Path) -> bool {
+        // A board with an unreadable config still exists; loading it
+        // reports what is wrong with the config.
+        self.backend(base_path)
+            .map(|backend| backend.exists(base_path))
+            .unwrap_or_else(|_| BoardStorage::config_path(base_path).exists())
+    }
+
+    fn delete(&self, base_path: &Path) -> Result<(), StorageError> {
+        self.backend(base_path)?.delete(base_path)
+    }
+
+    fn migrate(&self, base_path: &Path, dry_run: bool) -> Result<MigrationReport, StorageError> {
+        self.backend(base_path)?.migrate(base_path, dry_run)
+    }
+
+    fn lock(&self, base_path: &Path, timeout: Duration) -> Result<Option<BoardLock>, StorageError> {
+        self.backend(base_path)?.lock(base_path, timeout)
+    }
+}
+
+/// Writes a file by writing a temporary sibling and renaming it into place.
+pub(super) fn write_atomic(path: &Path, content: &[u8]) -> Result<(), StorageError> {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let temp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, std::process::id()));
+
+    let result = File::create(&temp_path)
+        .and_then(|mut file| {
+            file.write_all(content)?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&temp_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    Ok(result?)
 }
 
 /// Utility functions for managing board storage paths.
@@ -118,6 +541,33 @@ impl BoardStorage {
     pub const CLICKY_DIR: &'static str = ".clicky";
     /// Default filename for board data.
     pub const BOARD_FILE: &'static str = "board.json";
+    /// Filename of the board database used by SQLite storage.
+    pub const DATABASE_FILE: &'static str = "board.db";
+    /// Filename of the board settings used by directory storage.
+    pub const META_FILE: &'static str = "meta.json";
+    /// Directory holding one file per card in directory storage.
+    pub const CARDS_DIR: &'static str = "cards";
+    /// Directory holding the activity of each card in directory storage.
+    pub const ACTIVITY_DIR: &'static str = "activity";
+    /// Filename of the local, untracked board state in directory storage.
+    pub const STATE_FILE: &'static str = "state.json";
+    /// Filename of the lock guarding board modifications.
+    pub const LOCK_FILE: &'static str = "board.lock";
+    /// Directory inside the clicky directory holding backups.
+    pub const BACKUP_DIR: &'static str = "backups";
+    /// Filename of the local, untracked journal of undoable operations.
+    pub const JOURNAL_FILE: &'static str = "journal.json";
+    /// Filename of the board configuration.
+    pub const CONFIG_FILE: &'static str = "config.json";
+    /// Entries of the `.gitignore` written into the clicky directory.
+    const GITIGNORE: [&'static str; 6] = [
+        "board.lock",
+        "*.tmp",
+        "board.db-journal",
+        "state.json",
+        "backups/",
+        "journal.json",
+    ];
 
     /// Gets the path to the board file in the given directory.
     ///
@@ -126,23 +576,88 @@ impl BoardStorage {
         base_path.join(Self::CLICKY_DIR).join(Self::BOARD_FILE)
     }
 
+    /// Gets the path to the board database in the given directory.
+    ///
+    /// Returns: `<base_path>/.clicky/board.db`
+    pub fn database_path(base_path: &Path) -> PathBuf {
+        base_path.join(Self::CLICKY_DIR).join(Self::DATABASE_FILE)
+    }
+
+    /// Gets the path to the board settings of directory storage.
+    ///
+    /// Returns: `<base_path>/.clicky/meta.json`
+    pub fn meta_path(base_path: &Path) -> PathBuf {
+        base_path.join(Self::CLICKY_DIR).join(Self::META_FILE)
+    }
+
+    /// Gets the path to the board configuration in the given directory.
+    ///
+    /// Returns: `<base_path>/.clicky/config.json`
+    pub fn config_path(base_path: &Path) -> PathBuf {
+        base_path.join(Self::CLICKY_DIR).join(Self::CONFIG_FILE)
+    }
+
+    /// Gets the path to the lock file in the given directory.
+    ///
+    /// Returns: `<base_path>/.clicky/board.lock`
+    pub fn lock_path(base_path: &Path) -> PathBuf {
+        base_path.join(Self::CLICKY_DIR).join(Self::LOCK_FILE)
+    }
+
+    /// Gets the path to the journal of undoable operations.
+    ///
+    /// Returns: `<base_path>/.clicky/journal.json`
+    pub fn journal_path(base_path: &Path) -> PathBuf {
+        base_path.join(Self::CLICKY_DIR).join(Self::JOURNAL_FILE)
+    }
+
+    /// Keeps the lock file, temporary files and local state out of version
+    /// control.
+    ///
+    /// Adds the entries missing from `<base_path>/.clicky/.gitignore`,
+    /// keeping any the user added.
+    pub fn write_gitignore(base_path: &Path) -> Result<(), StorageError> {
+        let path = base_path.join(Self::CLICKY_DIR).join(".gitignore");
+        let mut content = fs::read_to_string(&path).unwrap_or_default();
+        let missing: Vec<&str> = Self::GITIGNORE
+            .into_iter()
+            .filter(|entry| !content.lines().any(|line| line.trim() == *entry))
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        for entry in missing {
+            content.push_str(entry);
+            content.push('\n');
+        }
+        fs::write(path, content)?;
+        Ok(())
+    }
+
     /// Gets the path to the clicky directory in the given directory.
-    #[allow(dead_code)]
     pub fn clicky_dir(base_path: &Path) -> PathBuf {
         base_path.join(Self::CLICKY_DIR)
     }
 
-    /// Finds the board path by searching upward from the current directory.
+    /// Finds the project directory of a board by searching upward from the
+    /// current directory.
     ///
     /// Starts at `start_path` and goes up the directory tree until it finds
-    /// a `.clicky/board.json` file or reaches the filesystem root.
-    pub fn find_board_path(start_path: &Path) -> Option<PathBuf> {
-        let mut current = start_path.to_path_buf();
+    /// a board, stored with whichever backend its config names, or reaches
+    /// the filesystem root.
+    pub fn find_base_path(start_path: &Path) -> Option<PathBuf> {
+        let repository = ConfiguredBoardRepository::new();
+        // Relative paths run out of parents before reaching the root.
+        let mut current =
+            std::path::absolute(start_path).unwrap_or_else(|_| start_path.to_path_buf());
 
         loop {
-            let board_path = Self::board_path(&current);
-            if board_path.exists() {
-                return Some(board_path);
+            if repository.exists(&current) {
+                return Some(current);
             }
 
             match current.parent() {
@@ -6,13 +6,12 @@ use tempfile::TempDir;
 fn test_json_repository_save_and_load() {
     // Arrange
     let temp_dir = TempDir::new().unwrap();
-    let board_path = temp_dir.path().join("board.json");
     let repo = JsonBoardRepository::new();
     let board = Board::new("test".to_string(), "Test Board".to_string());
 
     // Act
-    repo.save(&board, &board_path).unwrap();
-    let loaded = repo.load(&board_path).unwrap();
+    repo.save(&board, temp_dir.path()).unwrap();
+    let loaded = repo.load(temp_dir.path()).unwrap();
 
     // Assert
     assert_eq!(board.id, loaded.id);
@@ -24,28 +23,28 @@ fn test_json_repository_save_and_load() {
 fn test_json_repository_exists() {
     // Arrange
     let temp_dir = TempDir::new().unwrap();
-    let board_path = temp_dir.path().join("board.json");
+    let board_path = BoardStorage::board_path(temp_dir.path());
+    fs::create_dir_all(board_path.parent().unwrap()).unwrap();
     let repo = JsonBoardRepository::new();
 
     // Act & Assert
-    assert!(!repo.exists(&board_path));
+    assert!(!repo.exists(temp_dir.path()));
 
     // Create the file
     let mut file = fs::File::create(&board_path).unwrap();
     file.write_all(b"{}").unwrap();
 
-    assert!(repo.exists(&board_path));
+    assert!(repo.exists(temp_dir.path()));
 }
 
 #[test]
 fn test_json_repository_load_not_found() {
     // Arrange
     let temp_dir = TempDir::new().unwrap();
-    let board_path = temp_dir.path().join("nonexistent.json");
     let repo = JsonBoardRepository::new();
 
     // Act
-    let result = repo.load(&board_path);
+    let result = repo.load(temp_dir.path());
 
     // Assert
     assert!(matches!(result, Err(StorageError::BoardNotFound(_))));
@@ -66,35 +65,355 @@ fn test_board_storage_paths() {
 }
 
 #[test]
-fn test_find_board_path() {
+fn test_find_base_path() {
     // Arrange
     let temp_dir = TempDir::new().unwrap();
     let clicky_dir = temp_dir.path().join(".clicky");
     fs::create_dir(&clicky_dir).unwrap();
-
-    let board_path = clicky_dir.join("board.json");
-    fs::write(&board_path, "{}").unwrap();
+    fs::write(clicky_dir.join("board.json"), "{}").unwrap();
 
     let subdir = temp_dir.path().join("src").join("components");
     fs::create_dir_all(&subdir).unwrap();
 
     // Act
-    let found = BoardStorage::find_board_path(&subdir);
+    let found = BoardStorage::find_base_path(&subdir);
 
     // Assert
-    assert_eq!(found, Some(board_path));
+    assert_eq!(found.as_deref(), Some(temp_dir.path()));
 }
 
 #[test]
-fn test_find_board_path_not_found() {
+fn test_find_base_path_of_configured_backend() {
     // Arrange
     let temp_dir = TempDir::new().unwrap();
+    let repo = ConfiguredBoardRepository::new();
+    repo.save(
+        &Board::new("test".to_string(), "Test Board".to_string()),
+        temp_dir.path(),
+    )
+    .unwrap();
+    repo.convert(temp_dir.path(), StorageBackend::Sqlite)
+        .unwrap();
     let subdir = temp_dir.path().join("src");
     fs::create_dir(&subdir).unwrap();
 
     // Act
-    let found = BoardStorage::find_board_path(&subdir);
+    let found = BoardStorage::find_base_path(&subdir);
+
+    // Assert
+    assert!(!BoardStorage::board_path(temp_dir.path()).exists());
+    assert_eq!(found.as_deref(), Some(temp_dir.path()));
+}
+
+#[test]
+fn test_find_base_path_not_found() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let subdir = temp_dir.path().join("src");
+    fs::create_dir(&subdir).unwrap();
+
+    // Act
+    let found = BoardStorage::find_base_path(&subdir);
 
     // Assert
     assert!(found.is_none());
 }
+
+#[test]
+fn test_json_repository_save_replaces_atomically() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let board_path = BoardStorage::board_path(temp_dir.path());
+    fs::create_dir_all(board_path.parent().unwrap()).unwrap();
+    let repo = JsonBoardRepository::new();
+    let board = Board::new("test".to_string(), "Test Board".to_string());
+    fs::write(&board_path, "stale").unwrap();
+
+    // Act
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Assert
+    let mut entries: Vec<_> = fs::read_dir(board_path.parent().unwrap())
+        .unwrap()
+        .map(|e| e.unwrap().file_name())
+        .collect();
+    entries.sort();
+    assert_eq!(entries, vec![".gitignore", "board.json"]);
+    assert_eq!(repo.load(temp_dir.path()).unwrap().id, "test");
+}
+
+const LEGACY_BOARD: &str = r#"{
+  "id": "legacy",
+  "name": "Legacy",
+  "card_id_prefix": "LEG",
+  "next_card_number": 1,
+  "columns": [{ "id": "todo", "name": "To Do", "order": 0, "cards": [] }],
+  "cards": [],
+  "created_at": "2024-01-15T10:00:00Z",
+  "updated_at": "2024-01-15T10:00:00Z"
+}"#;
+
+#[test]
+fn test_json_repository_writes_schema_version() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let board_path = BoardStorage::board_path(temp_dir.path());
+    fs::create_dir_all(board_path.parent().unwrap()).unwrap();
+    let repo = JsonBoardRepository::new();
+    let board = Board::new("test".to_string(), "Test Board".to_string());
+
+    // Act
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Assert
+    let document: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&board_path).unwrap()).unwrap();
+    assert_eq!(document["schema_version"], CURRENT_SCHEMA_VERSION);
+}
+
+#[test]
+fn test_json_repository_loads_legacy_board() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let board_path = BoardStorage::board_path(temp_dir.path());
+    fs::create_dir_all(board_path.parent().unwrap()).unwrap();
+    fs::write(&board_path, LEGACY_BOARD).unwrap();
+    let repo = JsonBoardRepository::new();
+
+    // Act
+    let board = repo.load(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(board.id, "legacy");
+    assert_eq!(fs::read_to_string(&board_path).unwrap(), LEGACY_BOARD);
+}
+
+#[test]
+fn test_json_repository_stored_revision() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = JsonBoardRepository::new();
+    let mut board = Board::new("test".to_string(), "Test Board".to_string());
+    board.revision = 4;
+    repo.save(&board, temp_dir.path()).unwrap();
+    let legacy_dir = TempDir::new().unwrap();
+    let legacy_path = BoardStorage::board_path(legacy_dir.path());
+    fs::create_dir_all(legacy_path.parent().unwrap()).unwrap();
+    fs::write(&legacy_path, LEGACY_BOARD).unwrap();
+
+    // Act
+    let revision = repo.stored_revision(temp_dir.path()).unwrap();
+    let legacy_revision = repo.stored_revision(legacy_dir.path()).unwrap();
+    let missing = repo.stored_revision(TempDir::new().unwrap().path());
+
+    // Assert
+    assert_eq!(revision, 4);
+    assert_eq!(legacy_revision, 0);
+    assert!(matches!(missing, Err(StorageError::BoardNotFound(_))));
+}
+
+#[test]
+fn test_json_repository_backs_up_legacy_board_on_save() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let board_path = BoardStorage::board_path(temp_dir.path());
+    fs::create_dir_all(board_path.parent().unwrap()).unwrap();
+    fs::write(&board_path, LEGACY_BOARD).unwrap();
+    let repo = JsonBoardRepository::new();
+    let board = repo.load(temp_dir.path()).unwrap();
+
+    // Act
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Assert
+    let backup = board_path
+        .parent()
+        .unwrap()
+        .join("backups")
+        .join("board.v1.json");
+    assert_eq!(fs::read_to_string(backup).unwrap(), LEGACY_BOARD);
+}
+
+#[test]
+fn test_json_repository_rejects_newer_board() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let board_path = BoardStorage::board_path(temp_dir.path());
+    fs::create_dir_all(board_path.parent().unwrap()).unwrap();
+    let newer = format!(r#"{{"schema_version": {}}}"#, CURRENT_SCHEMA_VERSION + 1);
+    fs::write(&board_path, &newer).unwrap();
+    let repo = JsonBoardRepository::new();
+    let board = Board::new("test".to_string(), "Test Board".to_string());
+
+    // Act
+    let loaded = repo.load(temp_dir.path());
+    let revision = repo.stored_revision(temp_dir.path());
+    let saved = repo.save(&board, temp_dir.path());
+
+    // Assert
+    assert!(matches!(
+        loaded,
+        Err(StorageError::UnsupportedSchemaVersion { .. })
+    ));
+    assert!(matches!(
+        revision,
+        Err(StorageError::UnsupportedSchemaVersion { .. })
+    ));
+    assert!(matches!(
+        saved,
+        Err(StorageError::UnsupportedSchemaVersion { .. })
+    ));
+    assert_eq!(fs::read_to_string(&board_path).unwrap(), newer);
+}
+
+#[test]
+fn test_json_repository_migrate() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let board_path = BoardStorage::board_path(temp_dir.path());
+    fs::create_dir_all(board_path.parent().unwrap()).unwrap();
+    fs::write(&board_path, LEGACY_BOARD).unwrap();
+    let repo = JsonBoardRepository::new();
+
+    // Act
+    let dry_run = repo.migrate(temp_dir.path(), true).unwrap();
+    let unchanged = fs::read_to_string(&board_path).unwrap();
+    let report = repo.migrate(temp_dir.path(), false).unwrap();
+    let again = repo.migrate(temp_dir.path(), false).unwrap();
+
+    // Assert
+    assert_eq!(dry_run.from_version, 1);
+    assert_eq!(dry_run.steps.len(), CURRENT_SCHEMA_VERSION as usize - 1);
+    assert!(dry_run.backup.is_none());
+    assert_eq!(unchanged, LEGACY_BOARD);
+
+    assert_eq!(report.to_version, CURRENT_SCHEMA_VERSION);
+    let backup = report.backup.unwrap();
+    assert_eq!(fs::read_to_string(backup).unwrap(), LEGACY_BOARD);
+    assert_eq!(repo.load(temp_dir.path()).unwrap().id, "legacy");
+
+    assert!(again.steps.is_empty());
+    assert!(again.backup.is_none());
+}
+
+#[test]
+fn test_configured_repository_uses_json_by_default() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = ConfiguredBoardRepository::new();
+    let board = Board::new("test".to_string(), "Test Board".to_string());
+
+    // Act
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Assert
+    assert!(BoardStorage::board_path(temp_dir.path()).exists());
+    assert_eq!(repo.load(temp_dir.path()).unwrap().id, "test");
+}
+
+#[test]
+fn test_configured_repository_with_invalid_config() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = ConfiguredBoardRepository::new();
+    let board = Board::new("test".to_string(), "Test Board".to_string());
+    repo.save(&board, temp_dir.path()).unwrap();
+    fs::write(BoardStorage::config_path(temp_dir.path()), "not json").unwrap();
+
+    // Act & Assert
+    assert!(repo.exists(temp_dir.path()));
+    assert!(matches!(
+        repo.load(temp_dir.path()),
+        Err(StorageError::Serialization(_))
+    ));
+}
+
+#[test]
+fn test_configured_repository_convert_round_trip() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = ConfiguredBoardRepository::new();
+    let mut board = Board::new("test".to_string(), "Test Board".to_string());
+    let card_id = board.create_card("Task".to_string(), None, None, None);
+    board
+        .get_card_mut(&card_id)
+        .unwrap()
+        .add_label("bug".to_string());
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Act
+    let to_sqlite = repo
+        .convert(temp_dir.path(), StorageBackend::Sqlite)
+        .unwrap();
+    let in_sqlite = repo.load(temp_dir.path()).unwrap();
+    let to_json = repo.convert(temp_dir.path(), StorageBackend::Json).unwrap();
+
+    // Assert
+    assert_eq!(to_sqlite.from, StorageBackend::Json);
+    assert_eq!(to_sqlite.cards, 1);
+    assert_eq!(in_sqlite, board);
+    assert_eq!(to_json.to, StorageBackend::Json);
+    assert_eq!(repo.load(temp_dir.path()).unwrap(), board);
+    assert!(!BoardStorage::database_path(temp_dir.path()).exists());
+    assert_eq!(
+        BoardConfig::load(temp_dir.path()).unwrap().storage,
+        StorageBackend::Json
+    );
+}
+
+#[test]
+fn test_configured_repository_convert_to_current_backend() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = ConfiguredBoardRepository::new();
+    let board = Board::new("test".to_string(), "Test Board".to_string());
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Act
+    let conversion = repo.convert(temp_dir.path(), StorageBackend::Json).unwrap();
+
+    // Assert
+    assert_eq!(conversion.from, conversion.to);
+    assert!(BoardStorage::board_path(temp_dir.path()).exists());
+    assert!(!BoardStorage::config_path(temp_dir.path()).exists());
+}
+
+#[test]
+fn test_convert_to_directory_switches_to_random_card_ids() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repo = ConfiguredBoardRepository::new();
+    let mut board = Board::new("test".to_string(), "Test Board".to_string());
+    board.create_card("Task".to_string(), None, None, None);
+    repo.save(&board, temp_dir.path()).unwrap();
+
+    // Act
+    repo.convert(temp_dir.path(), StorageBackend::Directory)
+        .unwrap();
+    let converted = repo.load(temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(converted.card_id_scheme, CardIdScheme::Random);
+    assert_eq!(converted.cards, board.cards);
+    assert!(!BoardStorage::board_path(temp_dir.path()).exists());
+}
+
+#[test]
+fn test_write_gitignore_keeps_existing_entries() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let clicky_dir = temp_dir.path().join(".clicky");
+    fs::create_dir(&clicky_dir).unwrap();
+    fs::write(clicky_dir.join(".gitignore"), "board.lock\nnotes.md").unwrap();
+
+    // Act
+    BoardStorage::write_gitignore(temp_dir.path()).unwrap();
+    BoardStorage::write_gitignore(temp_dir.path()).unwrap();
+
+    // Assert
+    let content = fs::read_to_string(clicky_dir.join(".gitignore")).unwrap();
+    assert_eq!(
+        content,
+        "board.lock\nnotes.md\n*.tmp\nboard.db-journal\nstate.json\nbackups/\njournal.json\n"
+    );
+}
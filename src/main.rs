@@ -2,6 +2,8 @@ use std::env;
 use std::path::{Path, PathBuf};
 use std::process;
 
+use chrono::{DateTime, Duration, Local, Utc};
+use clap::error::ErrorKind;
 use clap::Parser;
 
 mod application;
@@ -9,23 +11,33 @@ mod cli;
 mod domain;
 mod infrastructure;
 
-use application::{BoardService, CardService};
+use application::actor::current_actor;
+use application::{
+    BoardService, CardService, CardUpdate, ChecklistEdit, ColumnService, LabelService, NewCard,
+};
+use cli::exit_code;
 #[cfg(feature = "interactive")]
 use cli::interactive;
-use cli::{Cli, Commands};
+use cli::output::{self, BoardSummary, CliError, ColumnSummary, LabelSummary, OutputFormat};
+use cli::{
+    BackupCommands, CardPositionArgs, ChecklistCommands, Cli, ColumnCommands, Commands,
+    GitCommands, LabelCommands, StorageCommands,
+};
+use domain::{
+    Activity, ActivityFilter, Board, Card, CardFilter, CardPosition, ChildPolicy, Column,
+    LabelMatch, WipPolicy,
+};
+use infrastructure::config::StorageBackend;
 use infrastructure::storage::BoardStorage;
 
-/// Exit codes for the application
-#[allow(dead_code)]
-mod exit_code {
-    pub const SUCCESS: i32 = 0;
-    pub const GENERAL_ERROR: i32 = 1;
-    pub const BOARD_NOT_FOUND: i32 = 2;
-    pub const INVALID_INPUT: i32 = 3;
-}
-
 fn main() {
-    let cli = Cli::parse();
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(e) => exit_with_usage_error(e),
+    };
+    let output = cli.output;
+    let if_revision = cli.if_revision;
+    let command_name = cli.command.name();
 
     // Determine the base path
     let base_path = cli.path.unwrap_or_else(|| {
@@ -35,7 +47,11 @@ fn main() {
 
     // Execute the command
     let result = match cli.command {
-        Commands::Init { name, interactive } => {
+        Commands::Init {
+            name,
+            storage,
+            interactive,
+        } => {
             if interactive {
                 #[cfg(feature = "interactive")]
                 {
@@ -47,7 +63,7 @@ fn main() {
                     Err("Interactive mode is not enabled. Build with --features interactive to use this feature.".into())
                 }
             } else {
-                cmd_init(&base_path, name)
+                cmd_init(&base_path, name, storage, output)
             }
         }
         Commands::Create {
@@ -55,6 +71,11 @@ fn main() {
             description,
             assignee,
             column,
+            priority,
+            labels,
+            due,
+            parent,
+            force,
             interactive,
         } => {
             if interactive {
@@ -68,19 +89,25 @@ fn main() {
                     Err("Interactive mode is not enabled. Build with --features interactive to use this feature.".into())
                 }
             } else {
-                cmd_create(
-                    &base_path,
-                    // SAFETY: Clap enforces this via required_unless_present = "interactive"
-                    title.expect("Title is required unless using interactive mode"),
+                let new_card = NewCard {
                     description,
                     assignee,
-                    column,
-                )
+                    column_id: column,
+                    priority,
+                    labels,
+                    due,
+                    parent,
+                    // SAFETY: Clap enforces this via required_unless_present = "interactive"
+                    ..NewCard::new(title.expect("Title is required unless using interactive mode"))
+                };
+                cmd_create(&base_path, new_card, force, if_revision, output)
             }
         }
         Commands::Move {
             card_id,
             column,
+            position,
+            force,
             interactive,
         } => {
             if interactive {
@@ -100,6 +127,10 @@ fn main() {
                     &card_id.expect("Card ID is required unless using interactive mode"),
                     // SAFETY: Clap enforces this via required_unless_present = "interactive"
                     &column.expect("Column is required unless using interactive mode"),
+                    position.to_position(),
+                    force,
+                    if_revision,
+                    output,
                 )
             }
         }
@@ -122,12 +153,21 @@ fn main() {
                     &base_path,
                     // SAFETY: Clap enforces this via required_unless_present = "interactive"
                     &card_id.expect("Card ID is required unless using interactive mode"),
+                    output,
                 )
             }
         }
         Commands::List {
             column,
             assignee,
+            priority,
+            labels,
+            any_label,
+            overdue,
+            due_before,
+            archived,
+            epic,
+            tree,
             interactive,
         } => {
             if interactive {
@@ -141,7 +181,21 @@ fn main() {
                     Err("Interactive mode is not enabled. Build with --features interactive to use this feature.".into())
                 }
             } else {
-                cmd_list(&base_path, column, assignee)
+                let filter = CardFilter {
+                    column,
+                    assignee,
+                    priority,
+                    labels,
+                    label_match: if any_label {
+                        LabelMatch::Any
+                    } else {
+                        LabelMatch::All
+                    },
+                    due_before,
+                    overdue_on: overdue.then(|| Local::now().date_naive()),
+                    archived,
+                };
+                cmd_list(&base_path, &filter, epic.as_deref(), tree, output)
             }
         }
         Commands::Update {
@@ -151,6 +205,17 @@ fn main() {
             clear_description,
             assignee,
             clear_assignee,
+            priority,
+            clear_priority,
+            labels,
+            add_labels,
+            remove_labels,
+            clear_labels,
+            due,
+            clear_due,
+            parent,
+            clear_parent,
+            force,
             interactive,
         } => {
             if interactive {
@@ -164,21 +229,39 @@ fn main() {
                     Err("Interactive mode is not enabled. Build with --features interactive to use this feature.".into())
                 }
             } else {
+                let changes = CardUpdate {
+                    title,
+                    description: optional_update(description, clear_description),
+                    assignee: optional_update(assignee, clear_assignee),
+                    priority: optional_update(priority, clear_priority),
+                    due: optional_update(due, clear_due),
+                    labels: if clear_labels {
+                        Some(Vec::new())
+                    } else if labels.is_empty() {
+                        None
+                    } else {
+                        Some(labels)
+                    },
+                    add_labels,
+                    remove_labels,
+                    parent: optional_update(parent, clear_parent),
+                };
                 cmd_update(
                     &base_path,
                     // SAFETY: Clap enforces this via required_unless_present = "interactive"
                     &card_id.expect("Card ID is required unless using interactive mode"),
-                    title,
-                    description,
-                    clear_description,
-                    assignee,
-                    clear_assignee,
+                    changes,
+                    force,
+                    if_revision,
+                    output,
                 )
             }
         }
         Commands::Delete {
             card_id,
             force,
+            children,
+            ignore_claim,
             interactive,
         } => {
             if interactive {
@@ -196,30 +279,186 @@ fn main() {
                     &base_path,
                     &card_id.expect("Card ID is required unless using interactive mode"),
                     force,
+                    children,
+                    ignore_claim,
+                    if_revision,
+                    output,
                 )
             }
         }
-        Commands::Info => cmd_info(&base_path),
+        Commands::Rank { card_id, position } => {
+            cmd_rank(&base_path, &card_id, &position, if_revision, output)
+        }
+        Commands::Link {
+            card_id,
+            relation,
+            other_id,
+            force,
+        } => {
+            let (blocker, blocked) = relation.order(&card_id, &other_id);
+            cmd_link(
+                &base_path,
+                blocker,
+                blocked,
+                true,
+                force,
+                if_revision,
+                output,
+            )
+        }
+        Commands::Unlink {
+            card_id,
+            relation,
+            other_id,
+            force,
+        } => {
+            let (blocker, blocked) = relation.order(&card_id, &other_id);
+            cmd_link(
+                &base_path,
+                blocker,
+                blocked,
+                false,
+                force,
+                if_revision,
+                output,
+            )
+        }
+        Commands::Ready => cmd_ready(&base_path, output),
+        Commands::Next {
+            column,
+            assignee,
+            labels,
+            claim,
+            ttl,
+            force,
+        } => {
+            let filter = CardFilter {
+                column,
+                assignee,
+                labels,
+                ..Default::default()
+            };
+            let claim = claim.then_some(ttl);
+            cmd_next(&base_path, &filter, claim, force, if_revision, output)
+        }
+        Commands::Claim {
+            card_id,
+            ttl,
+            force,
+        } => cmd_claim(&base_path, &card_id, ttl, force, if_revision, output),
+        Commands::Heartbeat { card_id } => cmd_heartbeat(&base_path, &card_id, if_revision, output),
+        Commands::Release { card_id, force } => {
+            cmd_release(&base_path, &card_id, force, if_revision, output)
+        }
+        Commands::Checklist { command, force } => {
+            cmd_checklist(&base_path, command, force, if_revision, output)
+        }
+        Commands::Archive { card_id, force } => {
+            cmd_archive(&base_path, &card_id, true, force, if_revision, output)
+        }
+        Commands::Unarchive { card_id, force } => {
+            cmd_archive(&base_path, &card_id, false, force, if_revision, output)
+        }
+        Commands::Comment {
+            card_id,
+            text,
+            author,
+            force,
+        } => cmd_comment(
+            &base_path,
+            &card_id,
+            &text,
+            author,
+            force,
+            if_revision,
+            output,
+        ),
+        Commands::Undo { steps, force } => {
+            cmd_undo(&base_path, steps.get(), true, force, if_revision, output)
+        }
+        Commands::Redo { steps, force } => {
+            cmd_undo(&base_path, steps.get(), false, force, if_revision, output)
+        }
+        Commands::History { card_id } => cmd_history(&base_path, &card_id, output),
+        Commands::Log { since, actor } => {
+            let filter = ActivityFilter {
+                actor,
+                since,
+                ..Default::default()
+            };
+            cmd_log(&base_path, &filter, output)
+        }
+        Commands::Info => cmd_info(&base_path, output),
+        Commands::Check { repair } => cmd_check(&base_path, repair, output),
+        Commands::Migrate { dry_run } => cmd_migrate(&base_path, dry_run, output),
+        Commands::Storage { command } => cmd_storage(&base_path, command, output),
+        Commands::Backup { command } => cmd_backup(&base_path, command, if_revision, output),
+        Commands::MergeDriver { base, ours, theirs } => {
+            cmd_merge_driver(&base, &ours, &theirs, output)
+        }
+        Commands::Git { command } => cmd_git(&base_path, command, output),
+        Commands::Column { command } => cmd_column(&base_path, command, if_revision, output),
+        Commands::Labels { command } => {
+            let command = command.unwrap_or(LabelCommands::List);
+            cmd_labels(&base_path, command, if_revision, output)
+        }
         #[cfg(feature = "tui")]
         Commands::Tui => cmd_tui(&base_path),
     };
 
     if let Err(e) = result {
-        eprintln!("Error: {}", e);
-        process::exit(exit_code::GENERAL_ERROR);
+        if output.is_text() {
+            eprintln!("Error: {}", e);
+        } else {
+            output::print_error(output, command_name, e.as_ref());
+        }
+        process::exit(exit_code::from_error_code(output::error_code(e.as_ref())));
+    }
+}
+
+/// Reports invalid command-line arguments and exits with `USAGE_ERROR`.
+///
+/// `--help` and `--version` are not errors and exit as clap does.
+fn exit_with_usage_error(e: clap::Error) -> ! {
+    if matches!(e.kind(), ErrorKind::DisplayHelp | ErrorKind::DisplayVersion) {
+        e.exit();
+    }
+
+    let output = OutputFormat::requested(env::args());
+    if output.is_text() {
+        // Best effort: nothing is left to report a failed write to.
+        let _ = e.print();
+    } else {
+        let rendered = e.render().to_string();
+        let message = rendered.lines().next().unwrap_or_default();
+        let message = message.strip_prefix("error: ").unwrap_or(message);
+        output::print_error(output, "clicky", &CliError::new("usage_error", message));
     }
+    process::exit(exit_code::USAGE_ERROR);
 }
 
-fn cmd_init(base_path: &Path, name: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
+fn cmd_init(
+    base_path: &Path,
+    name: Option<String>,
+    storage: StorageBackend,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
     let service = BoardService::new();
 
     if service.exists(base_path) {
-        return Err(
-            "Board already initialized in this directory. Use 'clicky info' to view it.".into(),
-        );
+        return Err(CliError::new(
+            "already_initialized",
+            "Board already initialized in this directory. Use 'clicky info' to view it.",
+        )
+        .into());
     }
 
-    let board = service.initialize(base_path, name)?;
+    let board = service.initialize_with_storage(base_path, name, storage)?;
+
+    if !output.is_text() {
+        output::print_data(output, "init", &BoardSummary::from(&board))?;
+        return Ok(());
+    }
 
     println!(
         "✓ Initialized board '{}' in {}",
@@ -229,27 +468,44 @@ fn cmd_init(base_path: &Path, name: Option<String>) -> Result<(), Box<dyn std::e
     println!("  Card ID prefix: {}", board.card_id_prefix);
     let column_names: Vec<&str> = board.columns.iter().map(|c| c.name.as_str()).collect();
     println!("  Columns: {}", column_names.join(", "));
+    println!("  Storage: {}", storage);
 
     Ok(())
 }
 
+/// Builds the update for an optional card field from a value and a clear flag.
+fn optional_update<T>(value: Option<T>, clear: bool) -> Option<Option<T>> {
+    if clear {
+        Some(None)
+    } else {
+        value.map(Some)
+    }
+}
+
 fn cmd_create(
     base_path: &Path,
-    title: String,
-    description: Option<String>,
-    assignee: Option<String>,
-    column: Option<String>,
+    new_card: NewCard,
+    force: bool,
+    if_revision: Option<u64>,
+    output: OutputFormat,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let service = CardService::new();
+    let service = CardService::new().if_revision(if_revision).force(force);
 
-    let result = service.create(base_path, title, description, assignee, column)?;
+    let result = service.create(base_path, new_card)?;
 
-    println!("✓ Created card {}", result.card_id);
     let card = result
         .board
         .get_card(&result.card_id)
         .ok_or_else(|| format!("Card {} not found after creation", result.card_id))?;
+
+    if !output.is_text() {
+        output::print_data(output, "create", card)?;
+        return Ok(());
+    }
+
+    println!("✓ Created card {}", result.card_id);
     println!("  Title: {}", card.title);
+    print_wip_warning(&result.board, &card.column_id);
 
     Ok(())
 }
@@ -258,13 +514,23 @@ fn cmd_move(
     base_path: &Path,
     card_id: &str,
     column: &str,
+    position: Option<CardPosition>,
+    force: bool,
+    if_revision: Option<u64>,
+    output: OutputFormat,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let service = CardService::new();
+    let service = CardService::new().if_revision(if_revision).force(force);
 
-    let board = service.move_to(base_path, card_id, column)?;
+    let board = service.move_to_position(base_path, card_id, column, position.as_ref())?;
     let card = board
         .get_card(card_id)
         .ok_or_else(|| format!("Card {} not found after move", card_id))?;
+
+    if !output.is_text() {
+        output::print_data(output, "move", card)?;
+        return Ok(());
+    }
+
     let column_name = board
         .columns
         .iter()
@@ -274,32 +540,146 @@ fn cmd_move(
 
     println!("✓ Moved {} to {}", card_id, column_name);
     println!("  Title: {}", card.title);
+    print_wip_warning(&board, column);
+    if !board.is_backlog_column(column) && !board.is_done_column(column) {
+        print_blocked_warning(&board, card);
+    }
+
+    Ok(())
+}
+
+/// Describes the number of cards in a column against its WIP limit,
+/// e.g. "3 cards" or "5/4 cards, over WIP limit".
+fn describe_card_count(board: &Board, column: &Column) -> String {
+    let count = board.get_cards_in_column(&column.id).len();
+    match column.wip_limit {
+        Some(limit) if count > limit as usize => {
+            format!("{}/{} cards, over WIP limit", count, limit)
+        }
+        Some(limit) => format!("{}/{} cards", count, limit),
+        None => format!("{} cards", count),
+    }
+}
+
+/// Warns when a column is over its WIP limit after a change.
+fn print_wip_warning(board: &Board, column_id: &str) {
+    if let Err(exceeded) = board.check_wip_limit(column_id) {
+        println!("⚠ {}", exceeded);
+    }
+}
+
+/// Warns on stderr when a board read for display fails the structural
+/// checks; it is still shown so it can be inspected before `clicky check
+/// --repair`.
+fn print_consistency_warning(board: &Board) {
+    if let Err(e) = BoardService::check_consistency(board) {
+        eprintln!("Warning: {}", e);
+    }
+}
+
+/// Warns when a card is worked on before its blockers are done.
+fn print_blocked_warning(board: &Board, card: &Card) {
+    let blockers: Vec<&str> = board
+        .unfinished_blockers(card)
+        .iter()
+        .map(|b| b.id.as_str())
+        .collect();
+    if !blockers.is_empty() {
+        println!("⚠ {} is blocked by {}", card.id, blockers.join(", "));
+    }
+}
+
+fn cmd_rank(
+    base_path: &Path,
+    card_id: &str,
+    position: &CardPositionArgs,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let position = position.to_position().ok_or_else(|| {
+        CliError::new(
+            "invalid_position",
+            "Use one of --top, --bottom, --before, --after or --position",
+        )
+    })?;
+    let service = CardService::new().if_revision(if_revision);
+
+    let board = service.rank(base_path, card_id, &position)?;
+    let card = board
+        .get_card(card_id)
+        .ok_or_else(|| format!("Card {} not found after ranking", card_id))?;
+    let cards = board.get_cards_in_column(&card.column_id);
+
+    if !output.is_text() {
+        output::print_items(output, "rank", &cards)?;
+        return Ok(());
+    }
+
+    let index = cards.iter().position(|c| c.id == card_id).unwrap_or(0);
+    let column_name = board
+        .columns
+        .iter()
+        .find(|c| c.id == card.column_id)
+        .map_or(card.column_id.as_str(), |c| c.name.as_str());
+    println!(
+        "✓ Ranked {} at position {} in {}",
+        card_id, index, column_name
+    );
 
     Ok(())
 }
 
-fn cmd_show(base_path: &Path, card_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+fn cmd_show(
+    base_path: &Path,
+    card_id: &str,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
     let service = CardService::new();
 
     let board = service.get(base_path, card_id)?;
+    print_consistency_warning(&board);
     let card = board
         .get_card(card_id)
         .ok_or_else(|| format!("Card {} not found", card_id))?;
-    let column = board
+
+    if !output.is_text() {
+        output::print_data(output, "show", card)?;
+        return Ok(());
+    }
+
+    let column_name = board
         .columns
         .iter()
         .find(|c| c.id == card.column_id)
-        .unwrap();
+        .map_or("unknown column", |c| c.name.as_str());
 
     println!("Card: {}", card.id);
     println!("  Title:       {}", card.title);
     if let Some(ref desc) = card.description {
         println!("  Description: {}", desc);
     }
-    println!("  Column:      {} ({})", column.name, card.column_id);
+    println!("  Column:      {} ({})", column_name, card.column_id);
+    if let Some(parent) = board.parent_of(card) {
+        println!("  Parent:      {}: {}", parent.id, parent.title);
+    }
     if let Some(ref assignee) = card.assignee {
         println!("  Assignee:    {}", assignee);
     }
+    if let Some(priority) = card.priority {
+        println!("  Priority:    {}", priority);
+    }
+    if !card.labels.is_empty() {
+        let labels: Vec<&str> = card.labels.iter().map(String::as_str).collect();
+        println!("  Labels:      {}", labels.join(", "));
+    }
+    if let Some(due) = card.due {
+        let overdue = if board.is_card_overdue(card, Local::now().date_naive()) {
+            " (overdue)"
+        } else {
+            ""
+        };
+        println!("  Due:         {}{}", due, overdue);
+    }
     println!(
         "  Created:     {}",
         card.created_at.format("%Y-%m-%d %H:%M")
@@ -308,41 +688,128 @@ fn cmd_show(base_path: &Path, card_id: &str) -> Result<(), Box<dyn std::error::E
         "  Updated:     {}",
         card.updated_at.format("%Y-%m-%d %H:%M")
     );
+    if let Some(archived_at) = card.archived_at {
+        println!("  Archived:    {}", archived_at.format("%Y-%m-%d %H:%M"));
+    }
+    if let Some(claim) = &card.claim {
+        let expired = if claim.is_expired(Utc::now()) {
+            " (expired)"
+        } else {
+            ""
+        };
+        println!(
+            "  Claimed:     by {} until {}{}",
+            claim.claimant,
+            format_local(claim.expires_at),
+            expired
+        );
+    }
+
+    print_checklist(card);
+
+    if let Some((done, total)) = board.child_progress(&card.id) {
+        println!("\nChildren ({}/{} done):", done, total);
+        let cards: Vec<&Card> = board.cards.iter().collect();
+        print_tree(&board, &cards, &card.id, "  ");
+    }
+
+    let blockers = board.blockers(card);
+    if !blockers.is_empty() {
+        println!("\nBlocked by:");
+        for blocker in blockers {
+            let mark = if board.is_done_column(&blocker.column_id) {
+                "✓"
+            } else {
+                "✗"
+            };
+            println!(
+                "  {} {}: {} ({})",
+                mark, blocker.id, blocker.title, blocker.column_id
+            );
+        }
+    }
+    let blocked = board.blocked_cards(&card.id);
+    if !blocked.is_empty() {
+        println!("\nBlocks:");
+        for other in blocked {
+            println!("  {}: {} ({})", other.id, other.title, other.column_id);
+        }
+    }
+
+    if !card.comments.is_empty() {
+        println!("\nComments ({}):", card.comments.len());
+        for comment in &card.comments {
+            println!(
+                "  [{}] {}: {}",
+                comment.created_at.format("%Y-%m-%d %H:%M"),
+                comment.author,
+                comment.body
+            );
+        }
+    }
 
     Ok(())
 }
 
 fn cmd_list(
     base_path: &Path,
-    column_filter: Option<String>,
-    assignee_filter: Option<String>,
+    filter: &CardFilter,
+    epic: Option<&str>,
+    tree: bool,
+    output: OutputFormat,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let service = CardService::new();
 
-    let board = service.list(base_path)?;
+    let board = match epic {
+        Some(epic) => service.list_under(base_path, epic, filter)?,
+        None => service.list_matching(base_path, filter)?,
+    };
+
+    let columns: Vec<(&Column, Vec<&Card>)> = board
+        .columns
+        .iter()
+        // Skip if column filter is specified and doesn't match
+        .filter(|column| filter.column.as_ref().is_none_or(|f| column.id == *f))
+        .map(|column| {
+            (
+                column,
+                board.get_matching_cards_in_column(&column.id, filter),
+            )
+        })
+        .collect();
+
+    if !output.is_text() {
+        let cards: Vec<&Card> = columns.into_iter().flat_map(|(_, cards)| cards).collect();
+        output::print_items(output, "list", &cards)?;
+        return Ok(());
+    }
 
+    let total: usize = columns.iter().map(|(_, cards)| cards.len()).sum();
     println!("Board: {} ({})", board.name, board.id);
-    println!("Total cards: {}\n", board.cards.len());
+    if filter.archived {
+        println!("Archived cards: {}\n", total);
+    } else {
+        println!("Total cards: {}\n", total);
+    }
 
-    for column in &board.columns {
-        // Skip if column filter is specified and doesn't match
-        if let Some(ref filter) = column_filter {
-            if column.id != *filter {
-                continue;
-            }
+    if tree {
+        let cards: Vec<&Card> = columns.into_iter().flat_map(|(_, cards)| cards).collect();
+        let full = service.list(base_path)?;
+        print_consistency_warning(&full);
+        for card in cards.iter().filter(|c| {
+            !c.parent
+                .as_ref()
+                .is_some_and(|p| cards.iter().any(|o| o.id == *p))
+        }) {
+            println!("{}", tree_line(&full, card));
+            print_tree(&full, &cards, &card.id, "");
         }
+        return Ok(());
+    }
 
-        let cards_in_column: Vec<_> = board
-            .cards
-            .iter()
-            .filter(|c| {
-                c.column_id == column.id
-                    && assignee_filter
-                        .as_ref()
-                        .is_none_or(|a| c.assignee.as_ref() == Some(a))
-            })
-            .collect();
+    let today = Local::now().date_naive();
 
+    for (column, cards_in_column) in columns {
         println!("\n{} ({})", column.name, column.id);
         println!("{}", "─".repeat(column.name.len() + column.id.len() + 3));
 
@@ -350,12 +817,37 @@ fn cmd_list(
             println!("  (no cards)");
         } else {
             for card in cards_in_column {
+                let priority_str = card
+                    .priority
+                    .map(|p| format!(" ({})", p))
+                    .unwrap_or_default();
                 let assignee_str = card
                     .assignee
                     .as_ref()
                     .map(|a| format!(" [@{}]", a))
                     .unwrap_or_default();
-                println!("  {}: {}{}", card.id, card.title, assignee_str);
+                let labels_str: String = card.labels.iter().map(|l| format!(" #{}", l)).collect();
+                let due_str = match card.due {
+                    Some(due) if board.is_card_overdue(card, today) => {
+                        format!(" due {} (overdue)", due)
+                    }
+                    Some(due) => format!(" due {}", due),
+                    None => String::new(),
+                };
+                let checklist_str = card
+                    .checklist_progress()
+                    .map(|(done, total)| format!(" [{}/{}]", done, total))
+                    .unwrap_or_default();
+                println!(
+                    "  {}: {}{}{}{}{}{}",
+                    card.id,
+                    card.title,
+                    checklist_str,
+                    priority_str,
+                    assignee_str,
+                    labels_str,
+                    due_str
+                );
             }
         }
     }
@@ -363,34 +855,58 @@ fn cmd_list(
     Ok(())
 }
 
+/// Describes a card in a tree, with the progress of its children.
+fn tree_line(board: &Board, card: &Card) -> String {
+    let progress = board
+        .child_progress(&card.id)
+        .map(|(done, total)| format!(" ({}/{} done)", done, total))
+        .unwrap_or_default();
+    format!(
+        "{}: {} ({}){}",
+        card.id, card.title, card.column_id, progress
+    )
+}
+
+/// Prints the cards among `cards` that are below `parent` as a tree, each
+/// line starting with `prefix`.
+fn print_tree(board: &Board, cards: &[&Card], parent: &str, prefix: &str) {
+    let children: Vec<&Card> = cards
+        .iter()
+        .filter(|c| c.parent.as_deref() == Some(parent))
+        .copied()
+        .collect();
+    for (i, child) in children.iter().enumerate() {
+        let last = i + 1 == children.len();
+        let (branch, indent) = if last {
+            ("└─ ", "   ")
+        } else {
+            ("├─ ", "│  ")
+        };
+        println!("{}{}{}", prefix, branch, tree_line(board, child));
+        print_tree(board, cards, &child.id, &format!("{}{}", prefix, indent));
+    }
+}
+
 fn cmd_update(
     base_path: &Path,
     card_id: &str,
-    title: Option<String>,
-    description: Option<String>,
-    clear_description: bool,
-    assignee: Option<String>,
-    clear_assignee: bool,
+    changes: CardUpdate,
+    force: bool,
+    if_revision: Option<u64>,
+    output: OutputFormat,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let service = CardService::new();
-
-    let desc_update = if clear_description {
-        Some(None)
-    } else {
-        description.map(Some)
-    };
-
-    let assignee_update = if clear_assignee {
-        Some(None)
-    } else {
-        assignee.map(Some)
-    };
+    let service = CardService::new().if_revision(if_revision).force(force);
 
-    let board = service.update(base_path, card_id, title, desc_update, assignee_update)?;
+    let board = service.update(base_path, card_id, changes)?;
     let card = board
         .get_card(card_id)
         .ok_or_else(|| format!("Card {} not found after update", card_id))?;
 
+    if !output.is_text() {
+        output::print_data(output, "update", card)?;
+        return Ok(());
+    }
+
     println!("✓ Updated {}", card_id);
     println!("  Title: {}", card.title);
 
@@ -401,8 +917,20 @@ fn cmd_delete(
     base_path: &Path,
     card_id: &str,
     force: bool,
+    children: Option<ChildPolicy>,
+    ignore_claim: bool,
+    if_revision: Option<u64>,
+    output: OutputFormat,
 ) -> Result<(), Box<dyn std::error::Error>> {
     if !force {
+        if !output.is_text() {
+            return Err(CliError::new(
+                "confirmation_required",
+                "Deleting a card with machine-readable output requires --force",
+            )
+            .into());
+        }
+
         print!("Are you sure you want to delete {}? [y/N] ", card_id);
         use std::io::Write;
         std::io::stdout().flush()?;
@@ -416,49 +944,1021 @@ fn cmd_delete(
         }
     }
 
-    let service = CardService::new();
-    service.delete(base_path, card_id)?;
+    let service = CardService::new()
+        .if_revision(if_revision)
+        .force(ignore_claim);
+    let before = service.get(base_path, card_id)?;
+    let board = service.delete_with_children(base_path, card_id, children)?;
+    let deleted: Vec<&str> = before
+        .cards
+        .iter()
+        .filter(|c| c.id != card_id && board.get_card(&c.id).is_none())
+        .map(|c| c.id.as_str())
+        .collect();
+
+    if !output.is_text() {
+        let data = serde_json::json!({ "card_id": card_id, "deleted_children": deleted });
+        output::print_data(output, "delete", &data)?;
+        return Ok(());
+    }
 
     println!("✓ Deleted {}", card_id);
+    if !deleted.is_empty() {
+        println!("  Also deleted: {}", deleted.join(", "));
+    }
 
     Ok(())
 }
 
-fn cmd_info(base_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
-    let board_service = BoardService::new();
+fn cmd_archive(
+    base_path: &Path,
+    card_id: &str,
+    archive: bool,
+    force: bool,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = CardService::new().if_revision(if_revision).force(force);
+    let board = if archive {
+        service.archive(base_path, card_id)?
+    } else {
+        service.unarchive(base_path, card_id)?
+    };
+    let card = board
+        .get_card(card_id)
+        .ok_or_else(|| format!("Card {} not found after saving", card_id))?;
 
-    if !board_service.exists(base_path) {
-        // Try to find a board in parent directories
-        match BoardStorage::find_board_path(base_path) {
-            Some(path) => {
-                let found_base = path.parent().and_then(|p| p.parent()).unwrap_or(base_path);
-                println!("Board found in parent directory: {}", found_base.display());
-                println!(
-                    "Run 'clicky --path {} info' to view it.",
-                    found_base.display()
-                );
-                return Ok(());
-            }
-            None => {
-                return Err("No board found. Run 'clicky init' to create one.".into());
-            }
-        }
+    if !output.is_text() {
+        let name = if archive { "archive" } else { "unarchive" };
+        output::print_data(output, name, card)?;
+        return Ok(());
     }
 
-    let board = board_service.load(base_path)?;
+    if archive {
+        println!("✓ Archived {}", card_id);
+        println!("  Restore it with: clicky unarchive {}", card_id);
+    } else {
+        println!("✓ Returned {} to {}", card_id, card.column_id);
+        print_wip_warning(&board, &card.column_id);
+    }
 
-    println!("Board: {}", board.name);
-    println!("ID: {}", board.id);
-    println!("Card ID prefix: {}", board.card_id_prefix);
-    println!("Created: {}", board.created_at.format("%Y-%m-%d %H:%M"));
-    println!("\nColumns:");
+    Ok(())
+}
 
-    for column in &board.columns {
-        let card_count = board.get_cards_in_column(&column.id).len();
-        println!("  {} ({}): {} cards", column.name, column.id, card_count);
+fn cmd_link(
+    base_path: &Path,
+    blocker: &str,
+    blocked: &str,
+    link: bool,
+    force: bool,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = CardService::new().if_revision(if_revision).force(force);
+    let board = if link {
+        service.link(base_path, blocker, blocked)?
+    } else {
+        service.unlink(base_path, blocker, blocked)?
+    };
+    let card = board
+        .get_card(blocked)
+        .ok_or_else(|| format!("Card {} not found after saving", blocked))?;
+
+    if !output.is_text() {
+        let name = if link { "link" } else { "unlink" };
+        output::print_data(output, name, card)?;
+        return Ok(());
     }
 
-    println!("\nTotal cards: {}", board.cards.len());
+    if link {
+        println!("✓ {} now blocks {}", blocker, blocked);
+    } else {
+        println!("✓ {} no longer blocks {}", blocker, blocked);
+    }
+    if board.is_ready(card) {
+        println!("  {} is ready", blocked);
+    } else {
+        print_blocked_warning(&board, card);
+    }
+
+    Ok(())
+}
+
+fn cmd_ready(base_path: &Path, output: OutputFormat) -> Result<(), Box<dyn std::error::Error>> {
+    let service = CardService::new();
+    let board = service.list(base_path)?;
+    print_consistency_warning(&board);
+    let cards = board.ready_cards();
+
+    if !output.is_text() {
+        output::print_items(output, "ready", &cards)?;
+        return Ok(());
+    }
+
+    if cards.is_empty() {
+        println!("No cards are ready.");
+        return Ok(());
+    }
+    println!("Ready cards: {}\n", cards.len());
+    for card in cards {
+        println!("  {}: {} ({})", card.id, card.title, card.column_id);
+    }
+
+    Ok(())
+}
+
+fn cmd_next(
+    base_path: &Path,
+    filter: &CardFilter,
+    claim: Option<Duration>,
+    force: bool,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = CardService::new().if_revision(if_revision).force(force);
+    let picked = service.next(base_path, filter, claim)?;
+    let board = &picked.board;
+    let card = board
+        .get_card(&picked.card_id)
+        .ok_or_else(|| format!("Card {} not found", picked.card_id))?;
+
+    if !output.is_text() {
+        output::print_data(output, "next", card)?;
+        return Ok(());
+    }
+
+    let priority_str = card
+        .priority
+        .map(|p| format!(" ({})", p))
+        .unwrap_or_default();
+    println!("→ {}: {}{}", card.id, card.title, priority_str);
+    let column_name = board
+        .columns
+        .iter()
+        .find(|c| c.id == card.column_id)
+        .map_or(card.column_id.as_str(), |c| c.name.as_str());
+    if let Some(claim) = card.claim.as_ref().filter(|_| picked.claimed) {
+        println!(
+            "✓ Claimed for {} in {} until {}",
+            claim.claimant,
+            column_name,
+            format_local(claim.expires_at)
+        );
+        print_wip_warning(board, &card.column_id);
+    } else {
+        println!("  Column: {}", column_name);
+    }
+
+    Ok(())
+}
+
+fn cmd_claim(
+    base_path: &Path,
+    card_id: &str,
+    ttl: Duration,
+    force: bool,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = CardService::new().if_revision(if_revision).force(force);
+    let board = service.claim(base_path, card_id, ttl)?;
+    let card = board
+        .get_card(card_id)
+        .ok_or_else(|| format!("Card {} not found after saving", card_id))?;
+
+    if !output.is_text() {
+        output::print_data(output, "claim", card)?;
+        return Ok(());
+    }
+
+    if let Some(claim) = &card.claim {
+        println!(
+            "✓ Claimed {} for {} until {}",
+            card_id,
+            claim.claimant,
+            format_local(claim.expires_at)
+        );
+        println!("  Renew it with: clicky heartbeat {}", card_id);
+    }
+
+    Ok(())
+}
+
+fn cmd_heartbeat(
+    base_path: &Path,
+    card_id: &str,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = CardService::new().if_revision(if_revision);
+    let board = service.heartbeat(base_path, card_id)?;
+    let card = board
+        .get_card(card_id)
+        .ok_or_else(|| format!("Card {} not found after saving", card_id))?;
+
+    if !output.is_text() {
+        output::print_data(output, "heartbeat", card)?;
+        return Ok(());
+    }
+
+    if let Some(claim) = &card.claim {
+        println!(
+            "✓ Claim on {} extended until {}",
+            card_id,
+            format_local(claim.expires_at)
+        );
+    }
+
+    Ok(())
+}
+
+fn cmd_release(
+    base_path: &Path,
+    card_id: &str,
+    force: bool,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = CardService::new().if_revision(if_revision).force(force);
+    let board = service.release(base_path, card_id)?;
+    let card = board
+        .get_card(card_id)
+        .ok_or_else(|| format!("Card {} not found after saving", card_id))?;
+
+    if !output.is_text() {
+        output::print_data(output, "release", card)?;
+        return Ok(());
+    }
+
+    println!("✓ Released {}", card_id);
+    println!("  Column: {}", card.column_id);
+
+    Ok(())
+}
+
+fn cmd_checklist(
+    base_path: &Path,
+    command: ChecklistCommands,
+    force: bool,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = CardService::new().if_revision(if_revision).force(force);
+    let command_name = command.name();
+    let (card_id, edit) = match command {
+        ChecklistCommands::Add { card_id, text } => (card_id, ChecklistEdit::Add(text)),
+        ChecklistCommands::Check { card_id, item } => (card_id, ChecklistEdit::Check(item.get())),
+        ChecklistCommands::Uncheck { card_id, item } => {
+            (card_id, ChecklistEdit::Uncheck(item.get()))
+        }
+        ChecklistCommands::Move { card_id, item, to } => (
+            card_id,
+            ChecklistEdit::Move {
+                item: item.get(),
+                to: to.get(),
+            },
+        ),
+        ChecklistCommands::Remove { card_id, item } => (card_id, ChecklistEdit::Remove(item.get())),
+    };
+    let board = service.edit_checklist(base_path, &card_id, &edit)?;
+    let card = board
+        .get_card(&card_id)
+        .ok_or_else(|| format!("Card {} not found after saving", card_id))?;
+
+    if !output.is_text() {
+        output::print_data(output, command_name, card)?;
+        return Ok(());
+    }
+
+    match edit {
+        ChecklistEdit::Add(_) => {
+            println!("✓ Added item {} to {}", card.checklist.len(), card_id)
+        }
+        ChecklistEdit::Check(item) => println!("✓ Checked item {} of {}", item, card_id),
+        ChecklistEdit::Uncheck(item) => println!("✓ Unchecked item {} of {}", item, card_id),
+        ChecklistEdit::Move { item, .. } => println!("✓ Moved item {} of {}", item, card_id),
+        ChecklistEdit::Remove(item) => println!("✓ Removed item {} of {}", item, card_id),
+    }
+    print_checklist(card);
+
+    Ok(())
+}
+
+/// Prints the numbered checklist of a card.
+fn print_checklist(card: &Card) {
+    let Some((done, total)) = card.checklist_progress() else {
+        return;
+    };
+    println!("\nChecklist [{}/{}]:", done, total);
+    for (number, item) in card.checklist.iter().enumerate() {
+        println!("  {}. {}", number + 1, item);
+    }
+}
+
+/// Formats a timestamp in local time, e.g. "2024-01-15 14:20".
+fn format_local(timestamp: DateTime<Utc>) -> String {
+    timestamp
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
+
+fn cmd_comment(
+    base_path: &Path,
+    card_id: &str,
+    text: &str,
+    author: Option<String>,
+    force: bool,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = CardService::new().if_revision(if_revision).force(force);
+    let author = author.unwrap_or_else(current_actor);
+
+    let board = service.add_comment(base_path, card_id, &author, text)?;
+    let comment = board
+        .get_card(card_id)
+        .and_then(|c| c.comments.last())
+        .ok_or_else(|| format!("Comment on {} not found after saving", card_id))?;
+
+    if !output.is_text() {
+        let data = serde_json::json!({ "card_id": card_id, "comment": comment });
+        output::print_data(output, "comment", &data)?;
+        return Ok(());
+    }
+
+    println!("✓ Added comment to {}", card_id);
+    println!("  Author: {}", comment.author);
+
+    Ok(())
+}
+
+fn cmd_undo(
+    base_path: &Path,
+    steps: usize,
+    undo: bool,
+    force: bool,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = CardService::new().if_revision(if_revision).force(force);
+    let replayed = if undo {
+        service.undo(base_path, steps)?
+    } else {
+        service.redo(base_path, steps)?
+    };
+
+    if !output.is_text() {
+        let name = if undo { "undo" } else { "redo" };
+        let data = serde_json::json!({
+            "operations": replayed.operations,
+            "revision": replayed.board.revision,
+        });
+        output::print_data(output, name, &data)?;
+        return Ok(());
+    }
+
+    let verb = if undo { "Undid" } else { "Redid" };
+    for operation in &replayed.operations {
+        println!(
+            "✓ {} {} ({}, {})",
+            verb,
+            operation.description,
+            operation.actor,
+            operation
+                .timestamp
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+        );
+    }
+    if replayed.operations.len() < steps {
+        println!(
+            "  Only {} change(s) to {}",
+            replayed.operations.len(),
+            if undo { "undo" } else { "redo" }
+        );
+    }
+
+    Ok(())
+}
+
+fn cmd_history(
+    base_path: &Path,
+    card_id: &str,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = CardService::new();
+    let history = service.history(base_path, card_id)?;
+
+    if !output.is_text() {
+        output::print_items(output, "history", &history)?;
+        return Ok(());
+    }
+
+    println!("History of {} ({} entries):", card_id, history.len());
+    for activity in &history {
+        println!("  {}", format_activity(activity, false));
+    }
+
+    Ok(())
+}
+
+fn cmd_log(
+    base_path: &Path,
+    filter: &ActivityFilter,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = CardService::new();
+    let activity = service.log(base_path, filter)?;
+
+    if !output.is_text() {
+        output::print_items(output, "log", &activity)?;
+        return Ok(());
+    }
+
+    if activity.is_empty() {
+        println!("No activity found.");
+        return Ok(());
+    }
+
+    for entry in &activity {
+        println!("{}", format_activity(entry, true));
+    }
+
+    Ok(())
+}
+
+/// Formats an activity as a single line, in local time.
+fn format_activity(activity: &Activity, with_card: bool) -> String {
+    let timestamp = activity
+        .timestamp
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M");
+    if with_card {
+        format!(
+            "{}  {}  {}  {}",
+            timestamp, activity.card_id, activity.actor, activity.kind
+        )
+    } else {
+        format!("{}  {}  {}", timestamp, activity.actor, activity.kind)
+    }
+}
+
+fn cmd_info(base_path: &Path, output: OutputFormat) -> Result<(), Box<dyn std::error::Error>> {
+    let board_service = BoardService::new();
+
+    if !board_service.exists(base_path) {
+        // Try to find a board in parent directories
+        match BoardStorage::find_base_path(base_path) {
+            Some(found_base) => {
+                if !output.is_text() {
+                    return Err(CliError::new(
+                        "board_not_found",
+                        format!(
+                            "No board in this directory; board found in parent directory: {}",
+                            found_base.display()
+                        ),
+                    )
+                    .into());
+                }
+                println!("Board found in parent directory: {}", found_base.display());
+                println!(
+                    "Run 'clicky --path {} info' to view it.",
+                    found_base.display()
+                );
+                return Ok(());
+            }
+            None => {
+                return Err(CliError::new(
+                    "board_not_found",
+                    "No board found. Run 'clicky init' to create one.",
+                )
+                .into());
+            }
+        }
+    }
+
+    let board = board_service.load(base_path)?;
+    print_consistency_warning(&board);
+
+    if !output.is_text() {
+        output::print_data(output, "info", &BoardSummary::from(&board))?;
+        return Ok(());
+    }
+
+    println!("Board: {}", board.name);
+    println!("ID: {}", board.id);
+    println!("Card ID prefix: {}", board.card_id_prefix);
+    println!("Revision: {}", board.revision);
+    println!("Created: {}", board.created_at.format("%Y-%m-%d %H:%M"));
+    println!("\nColumns:");
+
+    for column in &board.columns {
+        println!(
+            "  {} ({}): {}",
+            column.name,
+            column.id,
+            describe_card_count(&board, column)
+        );
+    }
+
+    let archived = board.archived_cards().len();
+    println!("\nTotal cards: {}", board.cards.len() - archived);
+    if archived > 0 {
+        println!("Archived cards: {}", archived);
+    }
+
+    Ok(())
+}
+
+fn cmd_check(
+    base_path: &Path,
+    repair: bool,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = BoardService::new();
+
+    if repair {
+        let issues = service.repair(base_path)?;
+
+        if !output.is_text() {
+            output::print_items(output, "check", &issues)?;
+            return Ok(());
+        }
+
+        if issues.is_empty() {
+            println!("✓ Board is consistent");
+        } else {
+            println!("✓ Repaired {} issue(s):", issues.len());
+            for issue in &issues {
+                println!("  - {}: {}", issue.message, issue.repair);
+            }
+        }
+        return Ok(());
+    }
+
+    let issues = service.check(base_path)?;
+    if issues.is_empty() {
+        if !output.is_text() {
+            output::print_items(output, "check", &issues)?;
+        } else {
+            println!("✓ Board is consistent");
+        }
+        return Ok(());
+    }
+
+    let mut message = format!(
+        "{} issue(s) found. Run 'clicky check --repair' to fix them.",
+        issues.len()
+    );
+    if output.is_text() {
+        for issue in &issues {
+            println!("  - {} (repair: {})", issue.message, issue.repair);
+        }
+    } else {
+        let messages: Vec<String> = issues.iter().map(|i| i.to_string()).collect();
+        message = format!("{} {}", message, messages.join("; "));
+    }
+    Err(CliError::new("inconsistent_board", message).into())
+}
+
+fn cmd_migrate(
+    base_path: &Path,
+    dry_run: bool,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = BoardService::new();
+    let report = service.migrate(base_path, dry_run)?;
+
+    if !output.is_text() {
+        output::print_data(output, "migrate", &report)?;
+        return Ok(());
+    }
+
+    if report.steps.is_empty() {
+        println!("✓ Board is already at schema version {}", report.to_version);
+        return Ok(());
+    }
+
+    if report.dry_run {
+        println!(
+            "Would migrate board from schema version {} to {}:",
+            report.from_version, report.to_version
+        );
+    } else {
+        println!(
+            "✓ Migrated board from schema version {} to {}:",
+            report.from_version, report.to_version
+        );
+    }
+    for step in &report.steps {
+        println!("  - {}", step);
+    }
+    if let Some(backup) = &report.backup {
+        println!("  Backup: {}", backup.display());
+    }
+
+    Ok(())
+}
+
+fn cmd_storage(
+    base_path: &Path,
+    command: StorageCommands,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = BoardService::new();
+
+    match command {
+        StorageCommands::Convert { to } => {
+            let conversion = service.convert(base_path, to)?;
+
+            if !output.is_text() {
+                output::print_data(output, "storage convert", &conversion)?;
+                return Ok(());
+            }
+
+            if conversion.from == conversion.to {
+                println!("✓ Board already uses {} storage", conversion.to);
+            } else {
+                println!(
+                    "✓ Converted board from {} to {} storage ({} cards)",
+                    conversion.from, conversion.to, conversion.cards
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn cmd_backup(
+    base_path: &Path,
+    command: BackupCommands,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = BoardService::new().if_revision(if_revision);
+    let command_name = command.name();
+
+    match command {
+        BackupCommands::List => {
+            let snapshots = service.snapshots(base_path)?;
+
+            if !output.is_text() {
+                output::print_items(output, command_name, &snapshots)?;
+                return Ok(());
+            }
+
+            if snapshots.is_empty() {
+                println!("No snapshots yet");
+                return Ok(());
+            }
+            for snapshot in &snapshots {
+                println!(
+                    "{}  {}  revision {}",
+                    snapshot.name,
+                    snapshot
+                        .taken_at
+                        .with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M:%S"),
+                    snapshot.revision
+                );
+            }
+        }
+        BackupCommands::Restore { snapshot } => {
+            let (snapshot, board) = service.restore_snapshot(base_path, &snapshot)?;
+
+            if !output.is_text() {
+                output::print_data(output, command_name, &BoardSummary::from(&board))?;
+                return Ok(());
+            }
+
+            println!("✓ Restored snapshot {}", snapshot.name);
+            println!(
+                "  Board is now at revision {} with {} cards",
+                board.revision,
+                board.cards.len()
+            );
+        }
+        BackupCommands::Diff { snapshot } => {
+            let (snapshot, diff) = service.diff_snapshot(base_path, &snapshot)?;
+
+            if !output.is_text() {
+                output::print_data(output, command_name, &diff)?;
+                return Ok(());
+            }
+
+            if diff.is_empty() {
+                println!("No changes since snapshot {}", snapshot.name);
+                return Ok(());
+            }
+            println!("Changes since snapshot {}:", snapshot.name);
+            for card in &diff.added {
+                println!("  + {}: {}", card.id, card.title);
+            }
+            for card in &diff.removed {
+                println!("  - {}: {}", card.id, card.title);
+            }
+            for card in &diff.changed {
+                println!("  ~ {}: {}", card.card_id, card.title);
+                for change in &card.changes {
+                    println!("      {}", change);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn cmd_merge_driver(
+    base: &Path,
+    ours: &Path,
+    theirs: &Path,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = BoardService::new();
+    let outcome = service.merge_files(base, ours, theirs)?;
+
+    if !outcome.conflicts.is_empty() {
+        let conflicts: Vec<String> = outcome.conflicts.iter().map(|c| c.to_string()).collect();
+        let mut message = format!(
+            "{} board conflict(s) could not be merged; kept our version",
+            conflicts.len()
+        );
+        if output.is_text() {
+            for conflict in &conflicts {
+                eprintln!("  conflict: {}", conflict);
+            }
+        } else {
+            message = format!("{}: {}", message, conflicts.join("; "));
+        }
+        return Err(CliError::new("merge_conflict", message).into());
+    }
+
+    if !output.is_text() {
+        output::print_data(output, "merge-driver", &outcome)?;
+        return Ok(());
+    }
+
+    println!("✓ Merged board '{}'", outcome.board.name);
+    for renumbering in &outcome.renumbered {
+        println!("  Renumbered {} to {}", renumbering.from, renumbering.to);
+    }
+
+    Ok(())
+}
+
+fn cmd_git(
+    base_path: &Path,
+    command: GitCommands,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = BoardService::new();
+
+    match command {
+        GitCommands::InstallMergeDriver => {
+            let installation = service.install_merge_driver(base_path)?;
+
+            if !output.is_text() {
+                output::print_data(output, "git install-merge-driver", &installation)?;
+                return Ok(());
+            }
+
+            println!("✓ Installed merge driver '{}'", installation.driver);
+            println!("  Attributes: {}", installation.attributes.display());
+            println!("  Command: {}", installation.command);
+            println!("  Commit the attributes file; other clones run this command too.");
+        }
+    }
+
+    Ok(())
+}
+
+fn cmd_column(
+    base_path: &Path,
+    command: ColumnCommands,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = ColumnService::new().if_revision(if_revision);
+    let command_name = command.name();
+
+    match command {
+        ColumnCommands::Add { id, name, position } => {
+            let board = service.add(base_path, id.clone(), name, position)?;
+            let column = board
+                .columns
+                .iter()
+                .find(|c| c.id == id)
+                .ok_or_else(|| format!("Column {} not found after creation", id))?;
+
+            if !output.is_text() {
+                output::print_data(output, command_name, &ColumnSummary::new(&board, column))?;
+                return Ok(());
+            }
+
+            println!("✓ Added column '{}' ({})", column.name, column.id);
+            println!("  Position: {}", column.order);
+        }
+        ColumnCommands::Remove { id, move_to, force } => {
+            let (board, moved) = service
+                .force(force)
+                .remove(base_path, &id, move_to.as_deref())?;
+
+            if !output.is_text() {
+                let data = serde_json::json!({
+                    "column_id": id,
+                    "moved_to": move_to,
+                    "moved_cards": moved,
+                });
+                output::print_data(output, command_name, &data)?;
+                return Ok(());
+            }
+
+            println!("✓ Removed column {}", id);
+            if let Some(target) = move_to {
+                if moved > 0 {
+                    println!("  Moved {} card(s) to {}", moved, target);
+                    print_wip_warning(&board, &target);
+                }
+            }
+        }
+        ColumnCommands::Rename { id, name } => {
+            let board = service.rename(base_path, &id, name)?;
+            let column = board
+                .columns
+                .iter()
+                .find(|c| c.id == id)
+                .ok_or_else(|| format!("Column {} not found after rename", id))?;
+
+            if !output.is_text() {
+                output::print_data(output, command_name, &ColumnSummary::new(&board, column))?;
+                return Ok(());
+            }
+
+            println!("✓ Renamed column {} to '{}'", id, column.name);
+        }
+        ColumnCommands::Reorder { id, position } => {
+            let (board, position) = service.reorder(base_path, &id, position)?;
+
+            if !output.is_text() {
+                let columns: Vec<_> = board
+                    .columns
+                    .iter()
+                    .map(|c| ColumnSummary::new(&board, c))
+                    .collect();
+                output::print_items(output, command_name, &columns)?;
+                return Ok(());
+            }
+
+            println!("✓ Moved column {} to position {}", id, position);
+            let column_ids: Vec<&str> = board.columns.iter().map(|c| c.id.as_str()).collect();
+            println!("  Columns: {}", column_ids.join(", "));
+        }
+        ColumnCommands::Limit { id, limit, clear } => {
+            let limit = if clear { None } else { limit };
+            let board = service.set_wip_limit(base_path, &id, limit)?;
+            let column = board
+                .columns
+                .iter()
+                .find(|c| c.id == id)
+                .ok_or_else(|| format!("Column {} not found after update", id))?;
+
+            if !output.is_text() {
+                output::print_data(output, command_name, &ColumnSummary::new(&board, column))?;
+                return Ok(());
+            }
+
+            match limit {
+                Some(limit) => println!("✓ Set the WIP limit of {} to {}", column.name, limit),
+                None => println!("✓ Removed the WIP limit of {}", column.name),
+            }
+            print_wip_warning(&board, &id);
+        }
+        ColumnCommands::Policy { policy } => {
+            let board = service.set_wip_policy(base_path, policy)?;
+
+            if !output.is_text() {
+                let data = serde_json::json!({ "wip_policy": board.wip_policy });
+                output::print_data(output, command_name, &data)?;
+                return Ok(());
+            }
+
+            match policy {
+                WipPolicy::Warn => println!("✓ Moves over a WIP limit now print a warning"),
+                WipPolicy::Block => {
+                    println!("✓ Moves over a WIP limit are now blocked unless forced with --force")
+                }
+            }
+        }
+        ColumnCommands::List => {
+            let board = service.list(base_path)?;
+            print_consistency_warning(&board);
+
+            if !output.is_text() {
+                let columns: Vec<_> = board
+                    .columns
+                    .iter()
+                    .map(|c| ColumnSummary::new(&board, c))
+                    .collect();
+                output::print_items(output, command_name, &columns)?;
+                return Ok(());
+            }
+
+            for column in &board.columns {
+                println!(
+                    "  {}. {} ({}): {}",
+                    column.order,
+                    column.name,
+                    column.id,
+                    describe_card_count(&board, column)
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn cmd_labels(
+    base_path: &Path,
+    command: LabelCommands,
+    if_revision: Option<u64>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = LabelService::new().if_revision(if_revision);
+    let command_name = command.name();
+
+    match command {
+        LabelCommands::List => {
+            let board = service.list(base_path)?;
+            print_consistency_warning(&board);
+            let labels: Vec<LabelSummary> = board
+                .label_names()
+                .iter()
+                .map(|name| LabelSummary::new(&board, name))
+                .collect();
+
+            if !output.is_text() {
+                output::print_items(output, command_name, &labels)?;
+                return Ok(());
+            }
+
+            if labels.is_empty() {
+                println!("No labels");
+            }
+            for label in labels {
+                let color_str = label.color.map(|c| format!(" [{}]", c)).unwrap_or_default();
+                println!("  {}{}: {} cards", label.name, color_str, label.card_count);
+            }
+        }
+        LabelCommands::Rename { name, new_name } => {
+            let result = service.rename(base_path, &name, &new_name)?;
+            let new_name = new_name.trim();
+
+            if !output.is_text() {
+                output::print_data(
+                    output,
+                    command_name,
+                    &LabelSummary::new(&result.board, new_name),
+                )?;
+                return Ok(());
+            }
+
+            println!("✓ Renamed label {} to {}", name, new_name);
+            println!("  Cards updated: {}", result.cards_affected);
+        }
+        LabelCommands::Delete { name } => {
+            let result = service.delete(base_path, &name)?;
+
+            if !output.is_text() {
+                let data = serde_json::json!({
+                    "label": name,
+                    "cards_affected": result.cards_affected,
+                });
+                output::print_data(output, command_name, &data)?;
+                return Ok(());
+            }
+
+            println!("✓ Deleted label {}", name);
+            println!("  Cards updated: {}", result.cards_affected);
+        }
+        LabelCommands::Color { name, color } => {
+            let board = service.set_color(base_path, &name, color.as_deref())?;
+            let label = LabelSummary::new(&board, &name);
+
+            if !output.is_text() {
+                output::print_data(output, command_name, &label)?;
+                return Ok(());
+            }
+
+            match label.color {
+                Some(color) => println!("✓ Set color of label {} to {}", name, color),
+                None => println!("✓ Cleared color of label {}", name),
+            }
+        }
+    }
 
     Ok(())
 }